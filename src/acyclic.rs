@@ -0,0 +1,338 @@
+//! A directed graph that stays acyclic as edges are added.
+//!
+//! The graph keeps a topological order of its nodes up to date with the
+//! algorithm of Pearce and Kelly. An edge that agrees with the order is added
+//! right away. Otherwise only the nodes between its two ends in the order are
+//! searched, for a path that the edge would close into a cycle, and those
+//! reachable from or reaching the edge are moved to make room for it.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::{CycleError, DiGraph, GraphError, NodeIndex};
+
+#[derive(Debug, PartialEq)]
+pub enum AcyclicError {
+    Graph(GraphError),
+    /// The edge would close the cycle held by the error, which starts at the
+    /// target of the edge and ends at its source.
+    Cycle(CycleError),
+}
+
+impl Display for AcyclicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AcyclicError::Graph(err) => write!(f, "{}", err),
+            AcyclicError::Cycle(CycleError(cycle)) => {
+                write!(f, "edge would create a cycle through {} nodes", cycle.len())
+            }
+        }
+    }
+}
+
+impl core::error::Error for AcyclicError {}
+
+/// A directed graph that rejects every edge that would create a cycle,
+/// self-loops included.
+///
+/// The underlying [`DiGraph`] is available read-only through
+/// [`AcyclicGraph::graph`], so that it cannot be changed behind the order's
+/// back.
+#[derive(Debug, Clone)]
+pub struct AcyclicGraph<T, E = ()> {
+    graph: DiGraph<T, E>,
+    /// The position of every node in a topological order.
+    order: Vec<usize>,
+    /// Scratch space for the searches, all `false` between calls.
+    visited: Vec<bool>,
+}
+
+impl<T, E> Default for AcyclicGraph<T, E> {
+    fn default() -> Self {
+        Self {
+            graph: DiGraph::new().track_incoming(true),
+            order: Vec::new(),
+            visited: Vec::new(),
+        }
+    }
+}
+
+impl<T, E> TryFrom<DiGraph<T, E>> for AcyclicGraph<T, E> {
+    type Error = CycleError;
+
+    fn try_from(graph: DiGraph<T, E>) -> Result<Self, CycleError> {
+        let mut order = vec![0; graph.node_count()];
+        for (position, idx) in graph.topological_sort()?.into_iter().enumerate() {
+            order[idx.index()] = position;
+        }
+
+        Ok(Self {
+            visited: vec![false; order.len()],
+            graph: graph.track_incoming(true),
+            order,
+        })
+    }
+}
+
+impl<T, E> AcyclicGraph<T, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn graph(&self) -> &DiGraph<T, E> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> DiGraph<T, E> {
+        self.graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// The new node comes last in the topological order.
+    pub fn add_node(&mut self, node: T) -> NodeIndex {
+        self.order.push(self.order.len());
+        self.visited.push(false);
+        self.graph.add_node(node)
+    }
+
+    pub fn get_node_mut(&mut self, idx: NodeIndex) -> Option<&mut T> {
+        self.graph.get_node_mut(idx)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), AcyclicError> {
+        self.graph.check_node(from).map_err(AcyclicError::Graph)?;
+        self.graph.check_node(to).map_err(AcyclicError::Graph)?;
+        if from == to {
+            return Err(AcyclicError::Cycle(CycleError(vec![from])));
+        }
+
+        let (lower, upper) = (self.order[to.index()], self.order[from.index()]);
+        if lower < upper {
+            let forward = self.search_forward(to.index(), upper)?;
+            let backward = self.search_backward(from.index(), lower);
+            self.reorder(forward, backward);
+        }
+
+        self.graph
+            .add_edge(from, to, weight)
+            .map_err(AcyclicError::Graph)
+    }
+
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> Result<E, GraphError> {
+        self.graph.remove_edge(from, to)
+    }
+
+    /// Removes the node like [`DiGraph::remove_node`], shifting the index of
+    /// every node after it down by one.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove_node(&mut self, idx: NodeIndex) -> T {
+        let node = self.graph.remove_node(idx);
+        let removed = self.order.remove(idx.index());
+        for position in self.order.iter_mut() {
+            if *position > removed {
+                *position -= 1;
+            }
+        }
+        self.visited.pop();
+        node
+    }
+
+    /// Returns every node in an order where each edge points forward.
+    pub fn topological_order(&self) -> Vec<NodeIndex> {
+        let mut nodes = vec![NodeIndex::default(); self.order.len()];
+        for (idx, &position) in self.order.iter().enumerate() {
+            nodes[position] = NodeIndex::new(idx);
+        }
+        nodes
+    }
+
+    /// Collects the nodes reachable from `start` that come before position
+    /// `upper`, or returns the cycle if the node at `upper` is among them.
+    fn search_forward(&mut self, start: usize, upper: usize) -> Result<Vec<usize>, AcyclicError> {
+        let mut found = vec![start];
+        self.visited[start] = true;
+        // The path from `start` to the node on top, with the position of the
+        // next edge to follow from each node.
+        let mut path = vec![(start, 0)];
+        while let Some((node, next)) = path.last_mut() {
+            let Some(edge) = self.graph.edges[*node].get(*next) else {
+                path.pop();
+                continue;
+            };
+            *next += 1;
+
+            let target = edge.target.index();
+            if self.order[target] == upper {
+                let cycle = path
+                    .iter()
+                    .map(|&(node, _)| node)
+                    .chain([target])
+                    .map(NodeIndex::new)
+                    .collect();
+                self.unvisit(&found);
+                return Err(AcyclicError::Cycle(CycleError(cycle)));
+            }
+            if self.order[target] < upper && !self.visited[target] {
+                self.visited[target] = true;
+                found.push(target);
+                path.push((target, 0));
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// Collects the nodes reaching `start` that come after position `lower`.
+    fn search_backward(&mut self, start: usize, lower: usize) -> Vec<usize> {
+        let mut found = vec![start];
+        self.visited[start] = true;
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for (source, _) in self.graph.incoming_edges(node) {
+                if self.order[source] > lower && !self.visited[source] {
+                    self.visited[source] = true;
+                    found.push(source);
+                    stack.push(source);
+                }
+            }
+        }
+        found
+    }
+
+    /// Gives the positions of both sets back to their nodes, those reaching
+    /// the new edge first, keeping the relative order within each set.
+    fn reorder(&mut self, mut forward: Vec<usize>, mut backward: Vec<usize>) {
+        self.unvisit(&forward);
+        self.unvisit(&backward);
+        forward.sort_unstable_by_key(|&node| self.order[node]);
+        backward.sort_unstable_by_key(|&node| self.order[node]);
+
+        let mut positions: Vec<_> = backward
+            .iter()
+            .chain(forward.iter())
+            .map(|&node| self.order[node])
+            .collect();
+        positions.sort_unstable();
+        for (node, position) in backward.into_iter().chain(forward).zip(positions) {
+            self.order[node] = position;
+        }
+    }
+
+    fn unvisit(&mut self, nodes: &[usize]) {
+        for &node in nodes {
+            self.visited[node] = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AcyclicError, AcyclicGraph};
+    use crate::{CycleError, DiGraph, GraphError, NodeIndex};
+
+    fn is_topological<T, E>(graph: &AcyclicGraph<T, E>) -> bool {
+        let order = graph.topological_order();
+        let mut position = vec![0; order.len()];
+        for (i, idx) in order.iter().enumerate() {
+            position[idx.index()] = i;
+        }
+        graph
+            .graph()
+            .edges()
+            .all(|edge| position[edge.source().index()] < position[edge.target().index()])
+    }
+
+    #[test]
+    fn reorders_and_rejects_cycles() {
+        let mut graph = AcyclicGraph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[3], n[1], ()).unwrap();
+        graph.add_edge(n[4], n[3], ()).unwrap();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[2], n[4], ()).unwrap();
+        assert!(is_topological(&graph));
+        assert_eq!(
+            graph.topological_order(),
+            vec![n[2], n[4], n[3], n[1], n[0]]
+        );
+
+        assert_eq!(
+            graph.add_edge(n[0], n[4], ()),
+            Err(AcyclicError::Cycle(CycleError(vec![
+                n[4], n[3], n[1], n[0]
+            ])))
+        );
+        assert_eq!(
+            graph.add_edge(n[2], n[2], ()),
+            Err(AcyclicError::Cycle(CycleError(vec![n[2]])))
+        );
+        assert_eq!(
+            graph.add_edge(n[0], NodeIndex::new(9), ()),
+            Err(AcyclicError::Graph(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(9),
+                len: 5
+            }))
+        );
+        assert_eq!(graph.edge_count(), 4);
+        assert!(is_topological(&graph));
+
+        graph.remove_edge(n[3], n[1]).unwrap();
+        graph.add_edge(n[0], n[4], ()).unwrap();
+        assert!(is_topological(&graph));
+
+        assert_eq!(graph.remove_node(n[2]), 2);
+        assert!(is_topological(&graph));
+        let added = graph.add_node(5);
+        graph.add_edge(added, NodeIndex::new(0), ()).unwrap();
+        assert!(is_topological(&graph));
+    }
+
+    #[test]
+    fn matches_full_toposort() {
+        // Adds the edges of a dense graph in a scrambled order, keeping those
+        // that do not close a cycle, and checks every rejection against a
+        // graph built without the wrapper.
+        let mut graph = AcyclicGraph::new();
+        let mut plain = DiGraph::new();
+        for i in 0..12 {
+            graph.add_node(i);
+            plain.add_node(i);
+        }
+        for k in 0..144u64 {
+            let (from, to) = ((k * 37 % 144) / 12, (k * 37 % 144) % 12);
+            let (from, to) = (NodeIndex::new(from as usize), NodeIndex::new(to as usize));
+            let mut attempt = plain.clone().allow_self_loops(true);
+            attempt.add_edge(from, to, ()).unwrap();
+
+            match graph.add_edge(from, to, ()) {
+                Ok(()) => {
+                    assert!(attempt.topological_sort().is_ok());
+                    plain.add_edge(from, to, ()).unwrap();
+                }
+                Err(AcyclicError::Cycle(_)) => assert!(attempt.topological_sort().is_err()),
+                Err(err) => panic!("unexpected error: {}", err),
+            }
+            assert!(is_topological(&graph));
+        }
+
+        let from_plain = AcyclicGraph::try_from(plain).unwrap();
+        assert!(is_topological(&from_plain));
+        assert!(
+            AcyclicGraph::try_from(DiGraph::<u8>::from_edges([(0, 1), (1, 0)]).unwrap()).is_err()
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeRef, EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Returns the nodes whose removal would split their connected component,
+    /// ignoring edge direction, sorted by index.
+    pub fn articulation_points(&self) -> Vec<NodeIndex> {
+        let (articulation_points, _) = self.cut_points_and_bridges();
+        articulation_points
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, is_cut)| is_cut)
+            .map(|(node, _)| NodeIndex::new(node))
+            .collect()
+    }
+
+    /// Returns the edges whose removal would split their connected component,
+    /// ignoring edge direction, in [`EdgeIndex`](crate::EdgeIndex) order. An
+    /// edge with a parallel twin is never a bridge.
+    pub fn bridges(&self) -> Vec<EdgeRef<'_, E>> {
+        let (_, bridges) = self.cut_points_and_bridges();
+        self.edges()
+            .filter(|edge| bridges[edge.index().index()])
+            .collect()
+    }
+
+    /// Tarjan's lowpoint search over the undirected view of the graph, marking
+    /// articulation points by node and bridges by edge index.
+    fn cut_points_and_bridges(&self) -> (Vec<bool>, Vec<bool>) {
+        let len = self.nodes.len();
+        let mut adjacency = vec![Vec::new(); len];
+        for edge in self.edges() {
+            let (source, target) = (edge.source().index(), edge.target().index());
+            if source != target {
+                adjacency[source].push((target, edge.index().index()));
+                adjacency[target].push((source, edge.index().index()));
+            }
+        }
+
+        let mut articulation_points = vec![false; len];
+        let mut bridges = vec![false; self.edge_count];
+        let mut discovered: Vec<Option<usize>> = vec![None; len];
+        let mut low = vec![0; len];
+        let mut time = 0;
+        // Frames hold a node, the edge it was reached through, and how many
+        // of its neighbors have been looked at.
+        let mut stack: Vec<(usize, Option<usize>, usize)> = Vec::new();
+
+        for root in 0..len {
+            if discovered[root].is_some() {
+                continue;
+            }
+
+            discovered[root] = Some(time);
+            low[root] = time;
+            time += 1;
+            let mut root_children = 0;
+            stack.push((root, None, 0));
+            while let Some((node, parent_edge, position)) = stack.last_mut() {
+                let node = *node;
+                if let Some(&(next, edge)) = adjacency[node].get(*position) {
+                    *position += 1;
+                    if Some(edge) == *parent_edge {
+                        continue;
+                    }
+                    match discovered[next] {
+                        Some(next_time) => low[node] = low[node].min(next_time),
+                        None => {
+                            discovered[next] = Some(time);
+                            low[next] = time;
+                            time += 1;
+                            stack.push((next, Some(edge), 0));
+                        }
+                    }
+                    continue;
+                }
+
+                let parent_edge = *parent_edge;
+                stack.pop();
+                if let Some(&(parent, _, _)) = stack.last() {
+                    low[parent] = low[parent].min(low[node]);
+                    let parent_time = discovered[parent].unwrap();
+                    if low[node] > parent_time {
+                        bridges[parent_edge.unwrap()] = true;
+                    }
+                    if parent == root {
+                        root_children += 1;
+                    } else if low[node] >= parent_time {
+                        articulation_points[parent] = true;
+                    }
+                }
+            }
+            if root_children > 1 {
+                articulation_points[root] = true;
+            }
+        }
+
+        (articulation_points, bridges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn cut_points_and_bridges() {
+        // A triangle 0-1-2 hanging off 3 by the edge 2-3, with 3-4 beyond it.
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        assert_eq!(graph.articulation_points(), vec![n[2], n[3]]);
+        let bridges: Vec<_> = graph
+            .bridges()
+            .iter()
+            .map(|edge| (edge.source(), edge.target()))
+            .collect();
+        assert_eq!(bridges, vec![(n[2], n[3]), (n[3], n[4])]);
+
+        graph.add_edge(n[4], n[3], ()).unwrap();
+        assert_eq!(graph.bridges().len(), 1);
+        assert_eq!(graph.articulation_points(), vec![n[2], n[3]]);
+    }
+
+    #[test]
+    fn ignores_direction() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+
+        assert_eq!(graph.articulation_points(), vec![n[1]]);
+        assert_eq!(graph.bridges().len(), 2);
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{Measure, MinScored};
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds the cheapest path from `start` to `goal`, guided by `heuristic`.
+    ///
+    /// `edge_cost` is given the nodes an edge is traversed from and to along
+    /// with its weight. The result is only guaranteed to be optimal if
+    /// `heuristic` never overestimates the remaining cost to `goal`.
+    ///
+    /// Panics if `start` or `goal` is out of bounds.
+    pub fn astar<K: Measure>(
+        &self,
+        start: NodeIndex,
+        goal: NodeIndex,
+        mut heuristic: impl FnMut(NodeIndex) -> K,
+        mut edge_cost: impl FnMut(NodeIndex, NodeIndex, &E) -> K,
+    ) -> Option<(K, Vec<NodeIndex>)> {
+        for (name, idx) in [("start", start), ("goal", goal)] {
+            if idx.index() >= self.nodes.len() {
+                panic!(
+                    "index {} out of range: {} is {}, but len is {}",
+                    name,
+                    name,
+                    idx,
+                    self.nodes.len(),
+                );
+            }
+        }
+
+        let mut scores = vec![None; self.nodes.len()];
+        let mut expanded_estimates: Vec<Option<K>> = vec![None; self.nodes.len()];
+        let mut predecessors = vec![None; self.nodes.len()];
+        let mut heap = BinaryHeap::new();
+
+        scores[start.index()] = Some(K::default());
+        heap.push(MinScored(heuristic(start), start.index()));
+        while let Some(MinScored(estimate, node)) = heap.pop() {
+            if node == goal.index() {
+                let mut path = vec![goal];
+                let mut current = node;
+                while let Some(previous) = predecessors[current] {
+                    path.push(NodeIndex::new(previous));
+                    current = previous;
+                }
+                path.reverse();
+                return Some((scores[node].unwrap(), path));
+            }
+
+            // A node is queued again whenever a cheaper path to it is found,
+            // so skip entries that were superseded before they were popped.
+            if expanded_estimates[node].is_some_and(|expanded| expanded <= estimate) {
+                continue;
+            }
+            expanded_estimates[node] = Some(estimate);
+
+            let score = scores[node].unwrap();
+            for (next, weight) in self.weighted_successors(node) {
+                let next_score =
+                    score + edge_cost(NodeIndex::new(node), NodeIndex::new(next), weight);
+                if scores[next].is_some_and(|current| current <= next_score) {
+                    continue;
+                }
+
+                scores[next] = Some(next_score);
+                predecessors[next] = Some(node);
+                heap.push(MinScored(
+                    next_score + heuristic(NodeIndex::new(next)),
+                    next,
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NodeIndex, UnGraph};
+
+    fn get_grid(width: usize, height: usize, walls: &[(usize, usize)]) -> UnGraph<(usize, usize)> {
+        let mut graph = UnGraph::new_undirected();
+        for y in 0..height {
+            for x in 0..width {
+                graph.add_node((x, y));
+            }
+        }
+
+        let n = |x: usize, y: usize| NodeIndex::new(y * width + x);
+        for y in 0..height {
+            for x in 0..width {
+                if walls.contains(&(x, y)) {
+                    continue;
+                }
+                if x + 1 < width && !walls.contains(&(x + 1, y)) {
+                    graph.add_edge(n(x, y), n(x + 1, y), ()).unwrap();
+                }
+                if y + 1 < height && !walls.contains(&(x, y + 1)) {
+                    graph.add_edge(n(x, y), n(x, y + 1), ()).unwrap();
+                }
+            }
+        }
+
+        graph
+    }
+
+    #[test]
+    fn grid_pathfinding() {
+        // . . . .
+        // . # # .
+        // . . # .
+        let graph = get_grid(4, 3, &[(1, 1), (2, 1), (2, 2)]);
+        let goal = NodeIndex::new(2 * 4 + 1);
+        let manhattan = |idx: NodeIndex| {
+            let (x, y) = *graph.get_node(idx).unwrap();
+            x.abs_diff(1) + y.abs_diff(2)
+        };
+
+        let (cost, path) = graph
+            .astar(NodeIndex::new(3), goal, manhattan, |_, _, _| 1)
+            .unwrap();
+        assert_eq!(cost, 6);
+        let path: Vec<_> = path
+            .into_iter()
+            .map(|idx| *graph.get_node(idx).unwrap())
+            .collect();
+        assert_eq!(
+            path,
+            vec![(3, 0), (2, 0), (1, 0), (0, 0), (0, 1), (0, 2), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let graph = get_grid(3, 3, &[(1, 0), (1, 1), (1, 2)]);
+        let found = graph.astar(NodeIndex::new(0), NodeIndex::new(2), |_| 0, |_, _, _| 1);
+        assert_eq!(found, None);
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Measure;
+use crate::{EdgeType, Graph, NegativeCycleError, NodeIndex};
+
+/// The cheapest paths from a single source node, by node index.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ShortestPaths<K> {
+    pub distances: Vec<Option<K>>,
+    pub predecessors: Vec<Option<NodeIndex>>,
+}
+
+impl<K> ShortestPaths<K> {
+    /// Follows the predecessors back from `target`, returning the path from
+    /// the source to it, or `None` if it cannot be reached.
+    pub fn path_to(&self, target: NodeIndex) -> Option<Vec<NodeIndex>> {
+        self.distances.get(target.index())?.as_ref()?;
+
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(previous) = self.predecessors[node.index()] {
+            path.push(previous);
+            node = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T, E: Measure, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds the cheapest paths from `source` with the Bellman-Ford algorithm,
+    /// which unlike [`Graph::shortest_path_costs`] supports negative weights.
+    ///
+    /// Fails if a negative cycle can be reached from `source`. In undirected
+    /// graphs any negative edge is such a cycle, as it can be walked back and
+    /// forth.
+    ///
+    /// Panics if `source` is out of bounds.
+    pub fn bellman_ford(&self, source: NodeIndex) -> Result<ShortestPaths<E>, NegativeCycleError> {
+        let len = self.nodes.len();
+        if source.index() >= len {
+            panic!(
+                "index source out of range: source is {}, but len is {}",
+                source, len,
+            );
+        }
+
+        let mut distances = vec![None; len];
+        let mut predecessors = vec![None; len];
+        distances[source.index()] = Some(E::default());
+
+        // Tries to shorten the path to `to` by going through `from`.
+        let relax = |distances: &mut Vec<Option<E>>,
+                     predecessors: &mut Vec<Option<NodeIndex>>,
+                     from: usize,
+                     to: usize,
+                     weight: E| {
+            let Some(distance) = distances[from] else {
+                return false;
+            };
+            let distance = distance + weight;
+            if distances[to].is_some_and(|current| current <= distance) {
+                return false;
+            }
+            distances[to] = Some(distance);
+            predecessors[to] = Some(NodeIndex::new(from));
+            true
+        };
+        let relax_all = |distances: &mut Vec<Option<E>>,
+                         predecessors: &mut Vec<Option<NodeIndex>>| {
+            let mut changed = None;
+            for (from, edge_data) in self.edges.iter().enumerate() {
+                for edge in edge_data.iter() {
+                    let to = edge.target.index();
+                    if relax(distances, predecessors, from, to, edge.weight) {
+                        changed = Some(to);
+                    }
+                    if !Ty::is_directed() && relax(distances, predecessors, to, from, edge.weight) {
+                        changed = Some(from);
+                    }
+                }
+            }
+            changed
+        };
+
+        for _ in 1..len {
+            if relax_all(&mut distances, &mut predecessors).is_none() {
+                return Ok(ShortestPaths {
+                    distances,
+                    predecessors,
+                });
+            }
+        }
+
+        let Some(changed) = relax_all(&mut distances, &mut predecessors) else {
+            return Ok(ShortestPaths {
+                distances,
+                predecessors,
+            });
+        };
+
+        // Following predecessors from a node that could still be improved
+        // leads onto the negative cycle within `len` steps.
+        let mut node = NodeIndex::new(changed);
+        for _ in 0..len {
+            node = predecessors[node.index()].unwrap();
+        }
+        let mut cycle = vec![node];
+        let mut current = predecessors[node.index()].unwrap();
+        while current != node {
+            cycle.push(current);
+            current = predecessors[current.index()].unwrap();
+        }
+        cycle.reverse();
+        Err(NegativeCycleError(cycle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NegativeCycleError, UnGraph};
+
+    #[test]
+    fn negative_weights() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 4).unwrap();
+        graph.add_edge(n[0], n[2], 5).unwrap();
+        graph.add_edge(n[1], n[3], 3).unwrap();
+        graph.add_edge(n[2], n[1], -3).unwrap();
+        graph.add_edge(n[3], n[4], -1).unwrap();
+
+        let paths = graph.bellman_ford(n[0]).unwrap();
+        assert_eq!(
+            paths.distances,
+            vec![Some(0), Some(2), Some(5), Some(5), Some(4)]
+        );
+        assert_eq!(
+            paths.path_to(n[4]),
+            Some(vec![n[0], n[2], n[1], n[3], n[4]])
+        );
+        assert_eq!(graph.bellman_ford(n[3]).unwrap().path_to(n[0]), None);
+    }
+
+    #[test]
+    fn negative_cycles() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 1.0).unwrap();
+        graph.add_edge(n[1], n[2], 2.0).unwrap();
+        graph.add_edge(n[2], n[3], -1.0).unwrap();
+        graph.add_edge(n[3], n[1], -1.5).unwrap();
+
+        assert_eq!(
+            graph.bellman_ford(n[0]),
+            Err(NegativeCycleError(vec![n[1], n[2], n[3]]))
+        );
+        assert!(graph.bellman_ford(n[3]).is_err());
+
+        let mut undirected = UnGraph::new_undirected();
+        let a = undirected.add_node('a');
+        let b = undirected.add_node('b');
+        undirected.add_edge(a, b, -1).unwrap();
+        assert!(undirected.bellman_ford(a).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    /// Splits the nodes into two sets with every edge running between them,
+    /// ignoring edge direction, or returns `None` if that is impossible.
+    ///
+    /// The first set holds the smallest node of every connected component, and
+    /// both sets are sorted by index.
+    pub fn bipartition(&self) -> Option<(Vec<NodeIndex>, Vec<NodeIndex>)> {
+        let adjacency = self.undirected_adjacency();
+        let mut sides: Vec<Option<bool>> = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+
+        for root in 0..self.nodes.len() {
+            if sides[root].is_some() {
+                continue;
+            }
+
+            sides[root] = Some(false);
+            queue.push_back(root);
+            while let Some(node) = queue.pop_front() {
+                let side = sides[node].unwrap();
+                for &next in adjacency[node].iter() {
+                    match sides[next] {
+                        None => {
+                            sides[next] = Some(!side);
+                            queue.push_back(next);
+                        }
+                        Some(next_side) if next_side == side => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for (node, side) in sides.into_iter().enumerate() {
+            match side {
+                Some(false) => left.push(NodeIndex::new(node)),
+                _ => right.push(NodeIndex::new(node)),
+            }
+        }
+        Some((left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn two_coloring() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+        graph.add_edge(n[2], n[3], ()).unwrap();
+
+        assert_eq!(
+            graph.bipartition(),
+            Some((vec![n[0], n[2], n[4]], vec![n[1], n[3]]))
+        );
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        assert!(graph.is_bipartite());
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        assert!(!graph.is_bipartite());
+    }
+
+    #[test]
+    fn odd_cycles() {
+        let mut graph = UnGraph::new_undirected().allow_self_loops(true);
+        let a = graph.add_node('a');
+        assert_eq!(graph.bipartition(), Some((vec![a], vec![])));
+        graph.add_edge(a, a, ()).unwrap();
+        assert!(!graph.is_bipartite());
+    }
+}
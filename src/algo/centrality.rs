@@ -0,0 +1,169 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Counts, for every node, the shortest paths between other nodes that
+    /// pass through it, with paths that tie for shortest sharing the count.
+    /// Edges are unweighted, and in undirected graphs every pair of endpoints
+    /// is counted once.
+    pub fn betweenness_centrality(&self) -> Vec<f64> {
+        let sources: Vec<_> = (0..self.nodes.len()).map(NodeIndex::new).collect();
+        self.betweenness_centrality_sampled(&sources)
+    }
+
+    /// Approximates [`Graph::betweenness_centrality`] using only the shortest
+    /// paths starting from `sources`, scaled up as if every node had been a
+    /// source. Passing every node gives the exact result.
+    ///
+    /// Panics if any of the sources is out of bounds.
+    pub fn betweenness_centrality_sampled(&self, sources: &[NodeIndex]) -> Vec<f64> {
+        let len = self.nodes.len();
+        let mut centrality = vec![0.0; len];
+        if sources.is_empty() {
+            return centrality;
+        }
+
+        let mut order = Vec::with_capacity(len);
+        let mut predecessors = vec![Vec::new(); len];
+        let mut paths = vec![0.0; len];
+        let mut distances: Vec<Option<usize>> = vec![None; len];
+        let mut dependencies = vec![0.0; len];
+        let mut queue = VecDeque::new();
+        for &source in sources {
+            if source.index() >= len {
+                panic!(
+                    "index source out of range: source is {}, but len is {}",
+                    source, len,
+                );
+            }
+
+            order.clear();
+            for node in 0..len {
+                predecessors[node].clear();
+                paths[node] = 0.0;
+                distances[node] = None;
+                dependencies[node] = 0.0;
+            }
+
+            paths[source.index()] = 1.0;
+            distances[source.index()] = Some(0);
+            queue.push_back(source.index());
+            while let Some(node) = queue.pop_front() {
+                order.push(node);
+                let distance = distances[node].unwrap() + 1;
+                for next in self.successors(node) {
+                    if distances[next].is_none() {
+                        distances[next] = Some(distance);
+                        queue.push_back(next);
+                    }
+                    if distances[next] == Some(distance) {
+                        paths[next] += paths[node];
+                        predecessors[next].push(node);
+                    }
+                }
+            }
+
+            for &node in order.iter().rev() {
+                for &previous in predecessors[node].iter() {
+                    dependencies[previous] +=
+                        paths[previous] / paths[node] * (1.0 + dependencies[node]);
+                }
+                if node != source.index() {
+                    centrality[node] += dependencies[node];
+                }
+            }
+        }
+
+        let mut scale = len as f64 / sources.len() as f64;
+        if !Ty::is_directed() {
+            scale /= 2.0;
+        }
+        for value in centrality.iter_mut() {
+            *value *= scale;
+        }
+        centrality
+    }
+
+    /// Scores every node by how close it is to the nodes it can reach: the
+    /// number of reachable nodes divided by the sum of the distances to them,
+    /// following edges out of the node without weights. Nodes that reach
+    /// nothing score zero.
+    pub fn closeness_centrality(&self) -> Vec<f64> {
+        let len = self.nodes.len();
+        let mut distances: Vec<Option<usize>> = vec![None; len];
+        let mut queue = VecDeque::new();
+
+        (0..len)
+            .map(|source| {
+                distances.iter_mut().for_each(|distance| *distance = None);
+                distances[source] = Some(0);
+                queue.push_back(source);
+                let (mut reached, mut total) = (0, 0);
+                while let Some(node) = queue.pop_front() {
+                    let distance = distances[node].unwrap() + 1;
+                    for next in self.successors(node) {
+                        if distances[next].is_none() {
+                            distances[next] = Some(distance);
+                            reached += 1;
+                            total += distance;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+
+                if total == 0 {
+                    0.0
+                } else {
+                    reached as f64 / total as f64
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn betweenness() {
+        let mut path = UnGraph::new_undirected();
+        let n: Vec<_> = (0..4).map(|i| path.add_node(i)).collect();
+        for i in 0..3 {
+            path.add_edge(n[i], n[i + 1], ()).unwrap();
+        }
+        assert_eq!(path.betweenness_centrality(), vec![0.0, 2.0, 2.0, 0.0]);
+        assert_eq!(
+            path.betweenness_centrality_sampled(&n),
+            path.betweenness_centrality()
+        );
+
+        // Two shortest paths from 0 to 3, through 1 and through 2.
+        let mut diamond = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| diamond.add_node(i)).collect();
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            diamond.add_edge(n[from], n[to], ()).unwrap();
+        }
+        assert_eq!(diamond.betweenness_centrality(), vec![0.0, 0.5, 0.5, 0.0]);
+        assert_eq!(
+            diamond.betweenness_centrality_sampled(&[n[0]]),
+            vec![0.0, 2.0, 2.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn closeness() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+
+        assert_eq!(graph.closeness_centrality(), vec![1.0, 1.0, 0.0, 0.0]);
+        graph.add_edge(n[2], n[3], ()).unwrap();
+        assert_eq!(graph.closeness_centrality()[0], 3.0 / 4.0);
+    }
+}
@@ -0,0 +1,247 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Lists every clique, a set of nodes all connected to each other, that
+    /// no other node could join, with the Bron-Kerbosch algorithm. Every
+    /// clique is sorted by index.
+    ///
+    /// Edge direction, self-loops and parallel edges are ignored. There can
+    /// be exponentially many maximal cliques, so this is only practical for
+    /// small or sparse graphs.
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeIndex>> {
+        let adjacency = self.simple_adjacency();
+        let mut cliques = Cliques::new(&adjacency, false);
+        cliques.search();
+        cliques.found.into_iter().map(to_indices).collect()
+    }
+
+    /// Finds a clique with as many nodes as possible, sorted by index, or an
+    /// empty one for an empty graph.
+    ///
+    /// This is exact but takes exponential time in the worst case, even with
+    /// the search cut short wherever it cannot beat the best clique so far.
+    /// [`Graph::greedy_clique`] is fast but may fall short.
+    pub fn maximum_clique(&self) -> Vec<NodeIndex> {
+        let adjacency = self.simple_adjacency();
+        maximum_clique(&adjacency)
+    }
+
+    /// Builds a clique by taking nodes from the highest degree down, adding
+    /// each one that is connected to every node taken so far. Returns it
+    /// sorted by index.
+    pub fn greedy_clique(&self) -> Vec<NodeIndex> {
+        let adjacency = self.simple_adjacency();
+        let mut order: Vec<_> = (0..adjacency.len()).collect();
+        order.sort_by_key(|&node| core::cmp::Reverse(adjacency[node].len()));
+
+        let mut clique: Vec<usize> = Vec::new();
+        for node in order {
+            if clique
+                .iter()
+                .all(|member| adjacency[node].binary_search(member).is_ok())
+            {
+                clique.push(node);
+            }
+        }
+        clique.sort_unstable();
+        to_indices(clique)
+    }
+
+    /// Finds a set of nodes with no edges between them with as many nodes as
+    /// possible, sorted by index. This is a maximum clique of the complement
+    /// of the graph, with the same cost.
+    pub fn maximum_independent_set(&self) -> Vec<NodeIndex> {
+        let adjacency = self.simple_adjacency();
+        let complement: Vec<Vec<usize>> = adjacency
+            .iter()
+            .enumerate()
+            .map(|(node, neighbors)| {
+                (0..adjacency.len())
+                    .filter(|&other| other != node && neighbors.binary_search(&other).is_err())
+                    .collect()
+            })
+            .collect();
+        maximum_clique(&complement)
+    }
+
+    /// Builds an independent set by taking nodes from the lowest degree up,
+    /// adding each one without an edge to any node taken so far. Returns it
+    /// sorted by index.
+    pub fn greedy_independent_set(&self) -> Vec<NodeIndex> {
+        let adjacency = self.simple_adjacency();
+        let mut order: Vec<_> = (0..adjacency.len()).collect();
+        order.sort_by_key(|&node| adjacency[node].len());
+
+        let mut blocked = vec![false; adjacency.len()];
+        let mut set = Vec::new();
+        for node in order {
+            if !blocked[node] {
+                set.push(node);
+                for &neighbor in adjacency[node].iter() {
+                    blocked[neighbor] = true;
+                }
+            }
+        }
+        set.sort_unstable();
+        to_indices(set)
+    }
+}
+
+fn maximum_clique(adjacency: &[Vec<usize>]) -> Vec<NodeIndex> {
+    let mut cliques = Cliques::new(adjacency, true);
+    cliques.search();
+    cliques.found.pop().map(to_indices).unwrap_or_default()
+}
+
+fn to_indices(nodes: Vec<usize>) -> Vec<NodeIndex> {
+    nodes.into_iter().map(NodeIndex::new).collect()
+}
+
+/// Bron-Kerbosch with pivoting, over sorted adjacency lists.
+struct Cliques<'a> {
+    adjacency: &'a [Vec<usize>],
+    clique: Vec<usize>,
+    /// Whether to keep only cliques larger than every one found before,
+    /// skipping the branches that cannot lead to one.
+    only_larger: bool,
+    found: Vec<Vec<usize>>,
+}
+
+impl<'a> Cliques<'a> {
+    fn new(adjacency: &'a [Vec<usize>], only_larger: bool) -> Self {
+        Self {
+            adjacency,
+            clique: Vec::new(),
+            only_larger,
+            found: Vec::new(),
+        }
+    }
+
+    fn search(&mut self) {
+        if !self.adjacency.is_empty() {
+            self.expand((0..self.adjacency.len()).collect(), Vec::new());
+        }
+    }
+
+    /// Extends the clique with `candidates`, which are connected to every
+    /// node in it, never reporting a clique that `excluded`, the nodes
+    /// already tried, could grow.
+    fn expand(&mut self, mut candidates: Vec<usize>, mut excluded: Vec<usize>) {
+        if self.only_larger {
+            let best = self.found.last().map_or(0, |best| best.len());
+            if self.clique.len() + candidates.len() <= best {
+                return;
+            }
+        }
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                let mut clique = self.clique.clone();
+                clique.sort_unstable();
+                self.found.push(clique);
+            }
+            return;
+        }
+
+        // Every maximal clique holds the pivot or one of its non-neighbors,
+        // so only those need to be tried.
+        let adjacency = self.adjacency;
+        let pivot = candidates
+            .iter()
+            .chain(excluded.iter())
+            .copied()
+            .max_by_key(|&pivot| {
+                candidates
+                    .iter()
+                    .filter(|candidate| adjacency[pivot].binary_search(candidate).is_ok())
+                    .count()
+            })
+            .unwrap();
+        let tries: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|node| adjacency[pivot].binary_search(node).is_err())
+            .collect();
+
+        for node in tries {
+            let neighbors = &adjacency[node];
+            let within = |set: &[usize]| -> Vec<usize> {
+                set.iter()
+                    .copied()
+                    .filter(|other| neighbors.binary_search(other).is_ok())
+                    .collect()
+            };
+            let (next_candidates, next_excluded) = (within(&candidates), within(&excluded));
+            self.clique.push(node);
+            self.expand(next_candidates, next_excluded);
+            self.clique.pop();
+            candidates.retain(|&candidate| candidate != node);
+            excluded.push(node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    fn nodes(indices: &[usize]) -> Vec<NodeIndex> {
+        indices.iter().copied().map(NodeIndex::new).collect()
+    }
+
+    #[test]
+    fn cliques() {
+        // Two triangles sharing the edge 1-2, a third triangle 3-4-5 hanging
+        // off them, and the lone node 6.
+        let mut graph = UnGraph::<()>::from_edges([
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+        ])
+        .unwrap();
+        graph.add_node(());
+
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![
+                nodes(&[0, 1, 2]),
+                nodes(&[1, 2, 3]),
+                nodes(&[3, 4, 5]),
+                nodes(&[6])
+            ]
+        );
+        assert_eq!(graph.maximum_clique().len(), 3);
+
+        graph
+            .add_edge(NodeIndex::new(0), NodeIndex::new(3), ())
+            .unwrap();
+        assert_eq!(graph.maximum_clique(), nodes(&[0, 1, 2, 3]));
+        assert_eq!(graph.greedy_clique(), nodes(&[0, 1, 2, 3]));
+        assert!(DiGraph::<()>::default().maximum_clique().is_empty());
+    }
+
+    #[test]
+    fn independent_sets() {
+        // A path of five nodes, where greedy picks the ends first.
+        let mut graph = DiGraph::<()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 4)]).unwrap();
+        assert_eq!(graph.maximum_independent_set(), nodes(&[0, 2, 4]));
+        assert_eq!(graph.greedy_independent_set(), nodes(&[0, 2, 4]));
+
+        // A star, whose center greedy avoids.
+        graph = DiGraph::<()>::from_edges([(0, 1), (0, 2), (0, 3)]).unwrap();
+        assert_eq!(graph.maximum_independent_set(), nodes(&[1, 2, 3]));
+        assert_eq!(graph.greedy_independent_set(), nodes(&[1, 2, 3]));
+        assert!(DiGraph::<()>::default()
+            .maximum_independent_set()
+            .is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Counts the sets of three nodes that are all connected to each other.
+    ///
+    /// Like the clustering coefficients, this ignores edge direction,
+    /// self-loops and parallel edges.
+    pub fn triangle_count(&self) -> usize {
+        let adjacency = self.simple_adjacency();
+        let corners: usize = (0..adjacency.len())
+            .map(|node| triangles_at(&adjacency, node))
+            .sum();
+        corners / 3
+    }
+
+    /// The fraction of pairs of neighbors of `node` that are themselves
+    /// connected, or 0 if it has fewer than two neighbors.
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn local_clustering(&self, node: NodeIndex) -> f64 {
+        let len = self.nodes.len();
+        if node.index() >= len {
+            panic!(
+                "index node out of range: node is {}, but len is {}",
+                node, len
+            );
+        }
+
+        let adjacency = self.simple_adjacency();
+        local_clustering(&adjacency, node.index())
+    }
+
+    /// Iterates over the local clustering coefficient of every node, in
+    /// index order. The neighbors of every node are gathered up front, but
+    /// the coefficients are only computed as the iterator advances.
+    pub fn clustering_coefficients(&self) -> ClusteringCoefficients {
+        ClusteringCoefficients {
+            adjacency: self.simple_adjacency(),
+            next: 0,
+        }
+    }
+
+    /// The mean of the local clustering coefficients, or 0 for an empty
+    /// graph.
+    pub fn average_clustering(&self) -> f64 {
+        if self.nodes.is_empty() {
+            return 0.0;
+        }
+        self.clustering_coefficients().sum::<f64>() / self.nodes.len() as f64
+    }
+
+    /// The fraction of paths of two edges that are closed into a triangle,
+    /// also called the transitivity of the graph, or 0 if there are no such
+    /// paths.
+    pub fn global_clustering(&self) -> f64 {
+        let adjacency = self.simple_adjacency();
+        let (mut closed, mut paths) = (0, 0);
+        for (node, neighbors) in adjacency.iter().enumerate() {
+            let degree = neighbors.len();
+            closed += triangles_at(&adjacency, node);
+            paths += degree * degree.saturating_sub(1) / 2;
+        }
+
+        if paths == 0 {
+            0.0
+        } else {
+            closed as f64 / paths as f64
+        }
+    }
+}
+
+/// Iterator over the local clustering coefficients of the nodes of a graph,
+/// created by [`Graph::clustering_coefficients`].
+#[derive(Debug, Clone)]
+pub struct ClusteringCoefficients {
+    adjacency: Vec<Vec<usize>>,
+    next: usize,
+}
+
+impl Iterator for ClusteringCoefficients {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.next == self.adjacency.len() {
+            return None;
+        }
+        let coefficient = local_clustering(&self.adjacency, self.next);
+        self.next += 1;
+        Some(coefficient)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.adjacency.len() - self.next;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ClusteringCoefficients {}
+
+fn local_clustering(adjacency: &[Vec<usize>], node: usize) -> f64 {
+    let degree = adjacency[node].len();
+    if degree < 2 {
+        return 0.0;
+    }
+    let pairs = degree * (degree - 1) / 2;
+    triangles_at(adjacency, node) as f64 / pairs as f64
+}
+
+/// Counts the triangles `node` is part of, by intersecting its sorted
+/// neighbors with those of each neighbor.
+fn triangles_at(adjacency: &[Vec<usize>], node: usize) -> usize {
+    let neighbors = &adjacency[node];
+    let shared: usize = neighbors
+        .iter()
+        .map(|&neighbor| {
+            let mut mine = neighbors.iter().peekable();
+            adjacency[neighbor]
+                .iter()
+                .filter(|&theirs| {
+                    while mine.next_if(|&mine| mine < theirs).is_some() {}
+                    mine.next_if_eq(&theirs).is_some()
+                })
+                .count()
+        })
+        .sum();
+    // Every triangle is found once from each of the two other corners.
+    shared / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn house_with_a_roof() {
+        // A square 0-1-2-3 with a diagonal 0-2, and 4 hanging off 3.
+        let graph =
+            UnGraph::<()>::from_edges([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (3, 4)]).unwrap();
+
+        assert_eq!(graph.triangle_count(), 2);
+        assert_eq!(graph.local_clustering(NodeIndex::new(1)), 1.0);
+        assert_eq!(graph.local_clustering(NodeIndex::new(4)), 0.0);
+
+        let coefficients = graph.clustering_coefficients();
+        assert_eq!(coefficients.len(), 5);
+        assert_eq!(
+            coefficients.collect::<Vec<_>>(),
+            vec![2.0 / 3.0, 1.0, 2.0 / 3.0, 1.0 / 3.0, 0.0]
+        );
+        assert!((graph.average_clustering() - 8.0 / 15.0).abs() < 1e-12);
+        // Six closed paths out of the 3 + 1 + 3 + 3 paths of two edges.
+        assert_eq!(graph.global_clustering(), 0.6);
+    }
+
+    #[test]
+    fn ignores_direction_loops_and_parallel_edges() {
+        let mut graph = DiGraph::<()>::from_edges([(0, 1), (1, 2), (2, 0), (0, 2), (0, 1)])
+            .unwrap()
+            .allow_self_loops(true);
+        graph
+            .add_edge(NodeIndex::new(1), NodeIndex::new(1), ())
+            .unwrap();
+        assert_eq!(graph.triangle_count(), 1);
+        assert_eq!(graph.global_clustering(), 1.0);
+        assert_eq!(graph.average_clustering(), 1.0);
+
+        let empty = DiGraph::<()>::new();
+        assert_eq!(empty.triangle_count(), 0);
+        assert_eq!(empty.average_clustering(), 0.0);
+        assert_eq!(empty.global_clustering(), 0.0);
+    }
+}
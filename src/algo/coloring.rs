@@ -0,0 +1,74 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// The order in which [`Graph::greedy_coloring_with`] colors nodes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ColoringOrder {
+    /// By node index.
+    Index,
+    /// By descending degree, breaking ties by index. Tends to use fewer
+    /// colors than going by index.
+    LargestDegreeFirst,
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Colors the nodes by index. See [`Graph::greedy_coloring_with`].
+    pub fn greedy_coloring(&self) -> Vec<usize> {
+        self.greedy_coloring_with(ColoringOrder::Index)
+    }
+
+    /// Gives every node, in the given order, the smallest color not used by
+    /// any neighbor colored before it, ignoring edge direction and
+    /// self-loops. Colors are numbered from zero, by node index.
+    pub fn greedy_coloring_with(&self, order: ColoringOrder) -> Vec<usize> {
+        let adjacency = self.undirected_adjacency();
+        let mut nodes: Vec<usize> = (0..self.nodes.len()).collect();
+        if order == ColoringOrder::LargestDegreeFirst {
+            nodes.sort_by_key(|&node| core::cmp::Reverse(self.degree(NodeIndex::new(node))));
+        }
+
+        let mut colors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut taken = Vec::new();
+        for node in nodes {
+            taken.clear();
+            taken.resize(adjacency[node].len() + 1, false);
+            for &next in adjacency[node].iter() {
+                if let Some(color) = colors[next] {
+                    if color < taken.len() {
+                        taken[color] = true;
+                    }
+                }
+            }
+            colors[node] = taken.iter().position(|&taken| !taken);
+        }
+        colors.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColoringOrder;
+    use crate::UnGraph;
+
+    #[test]
+    fn coloring() {
+        // A crown graph, where going by index alternates between the two sides
+        // and needs a new color for every pair of nodes.
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 3), (0, 5), (2, 1), (2, 5), (4, 1), (4, 3)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+        assert_eq!(graph.greedy_coloring(), vec![0, 0, 1, 1, 2, 2]);
+
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        let colors = graph.greedy_coloring_with(ColoringOrder::LargestDegreeFirst);
+        for edge in graph.edges() {
+            assert_ne!(colors[edge.source().index()], colors[edge.target().index()]);
+        }
+        assert_eq!(colors[0], 0);
+        assert_eq!(colors[1], 1);
+    }
+}
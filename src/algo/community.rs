@@ -0,0 +1,279 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Splits the nodes into communities that are densely connected inside
+    /// and sparsely connected to each other, with the Louvain method. Returns
+    /// the community of every node, numbered from 0 in order of their first
+    /// node.
+    ///
+    /// Nodes are moved to whichever neighboring community raises the
+    /// [modularity](Graph::modularity) the most, and the communities found
+    /// are then merged into single nodes to be grouped again, until nothing
+    /// moves. Edge direction and weights are ignored, but every one of a set
+    /// of parallel edges counts. Nodes are visited in index order, so the
+    /// result is deterministic.
+    pub fn louvain_communities(&self) -> Vec<usize> {
+        let mut level = Level::new(self);
+        let mut membership: Vec<_> = (0..self.nodes.len()).collect();
+        while let Some(communities) = level.move_nodes() {
+            for community in membership.iter_mut() {
+                *community = communities[*community];
+            }
+            level = level.aggregate(&communities);
+        }
+        membership
+    }
+
+    /// Scores a split of the nodes into communities, given the community of
+    /// every node, by how many more edges fall inside communities than would
+    /// if the edges were rewired at random with every degree kept. Scores
+    /// range from -0.5 to 1, and a graph without edges scores 0.
+    ///
+    /// Like [`Graph::louvain_communities`], this ignores edge direction and
+    /// weights.
+    ///
+    /// Panics if `communities` does not have one entry per node.
+    pub fn modularity(&self, communities: &[usize]) -> f64 {
+        if communities.len() != self.nodes.len() {
+            panic!(
+                "communities has {} entries, but the graph has {} nodes",
+                communities.len(),
+                self.nodes.len()
+            );
+        }
+        if self.edge_count == 0 {
+            return 0.0;
+        }
+
+        let total = 2.0 * self.edge_count as f64;
+        let mut inside = 0.0;
+        let mut degrees = vec![0.0; communities.iter().max().map_or(0, |&max| max + 1)];
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                let (from, to) = (communities[source], communities[edge.target.index()]);
+                if from == to {
+                    inside += 2.0;
+                }
+                degrees[from] += 1.0;
+                degrees[to] += 1.0;
+            }
+        }
+
+        inside / total
+            - degrees
+                .iter()
+                .map(|degree| (degree / total) * (degree / total))
+                .sum::<f64>()
+    }
+}
+
+/// A graph whose nodes are the communities of the level before it, with the
+/// edges between and within them summed into weights.
+struct Level {
+    /// The neighbors of every node other than itself, each once.
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// The total weight of the edges from every node to itself.
+    loops: Vec<f64>,
+    degrees: Vec<f64>,
+    /// Twice the total weight of every edge.
+    total: f64,
+}
+
+impl Level {
+    fn new<T, E, Ty: EdgeType>(graph: &Graph<T, E, Ty>) -> Self {
+        let len = graph.nodes.len();
+        let mut adjacency = vec![Vec::new(); len];
+        let mut loops = vec![0.0; len];
+        for (source, edge_data) in graph.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                let target = edge.target.index();
+                if source == target {
+                    loops[source] += 1.0;
+                } else {
+                    adjacency[source].push((target, 1.0));
+                    adjacency[target].push((source, 1.0));
+                }
+            }
+        }
+        Self::from_parts(adjacency, loops)
+    }
+
+    fn from_parts(mut adjacency: Vec<Vec<(usize, f64)>>, loops: Vec<f64>) -> Self {
+        for neighbors in adjacency.iter_mut() {
+            merge_parallel(neighbors);
+        }
+        let degrees: Vec<f64> = adjacency
+            .iter()
+            .zip(&loops)
+            .map(|(neighbors, weight)| {
+                neighbors.iter().map(|(_, weight)| weight).sum::<f64>() + 2.0 * weight
+            })
+            .collect();
+        let total = degrees.iter().sum();
+        Self {
+            adjacency,
+            loops,
+            degrees,
+            total,
+        }
+    }
+
+    /// Moves every node to the neighboring community that gains the most
+    /// modularity until no move gains any, returning the community of every
+    /// node numbered from 0, or `None` if no node moved.
+    fn move_nodes(&self) -> Option<Vec<usize>> {
+        let len = self.adjacency.len();
+        if self.total == 0.0 {
+            return None;
+        }
+
+        let mut community: Vec<_> = (0..len).collect();
+        let mut degrees = self.degrees.clone();
+        let mut weight_to = vec![0.0; len];
+        let mut touched = Vec::new();
+        let mut moved_any = false;
+        loop {
+            let mut moved = false;
+            for node in 0..len {
+                let current = community[node];
+                for &(neighbor, weight) in self.adjacency[node].iter() {
+                    let neighbor = community[neighbor];
+                    if weight_to[neighbor] == 0.0 {
+                        touched.push(neighbor);
+                    }
+                    weight_to[neighbor] += weight;
+                }
+
+                // The gain of joining a community, leaving out the terms
+                // shared by every choice.
+                let degree = self.degrees[node];
+                degrees[current] -= degree;
+                let gain = |c: usize| weight_to[c] - degrees[c] * degree / self.total;
+                let mut best = (current, gain(current));
+                for &candidate in touched.iter() {
+                    let candidate_gain = gain(candidate);
+                    if candidate_gain > best.1 + 1e-12 {
+                        best = (candidate, candidate_gain);
+                    }
+                }
+                degrees[best.0] += degree;
+                community[node] = best.0;
+                moved |= best.0 != current;
+
+                for community in touched.drain(..) {
+                    weight_to[community] = 0.0;
+                }
+            }
+
+            if !moved {
+                break;
+            }
+            moved_any = true;
+        }
+
+        if !moved_any {
+            return None;
+        }
+        renumber(&mut community);
+        Some(community)
+    }
+
+    /// Merges the nodes of every community into a single node.
+    fn aggregate(&self, communities: &[usize]) -> Self {
+        let len = communities.iter().max().map_or(0, |&max| max + 1);
+        let mut adjacency = vec![Vec::new(); len];
+        let mut loops = vec![0.0; len];
+        for (node, neighbors) in self.adjacency.iter().enumerate() {
+            let from = communities[node];
+            loops[from] += self.loops[node];
+            for &(neighbor, weight) in neighbors.iter() {
+                let to = communities[neighbor];
+                if from == to {
+                    // Seen once from either end.
+                    loops[from] += weight / 2.0;
+                } else {
+                    adjacency[from].push((to, weight));
+                }
+            }
+        }
+        Self::from_parts(adjacency, loops)
+    }
+}
+
+/// Sorts `neighbors` and sums the weights of repeated neighbors.
+fn merge_parallel(neighbors: &mut Vec<(usize, f64)>) {
+    neighbors.sort_unstable_by_key(|&(neighbor, _)| neighbor);
+    neighbors.dedup_by(|(next, weight), (kept, total)| {
+        let same = next == kept;
+        if same {
+            *total += *weight;
+        }
+        same
+    });
+}
+
+/// Renumbers communities from 0 in order of their first node.
+fn renumber(communities: &mut [usize]) {
+    let mut ids = vec![None; communities.len()];
+    let mut next = 0;
+    for community in communities.iter_mut() {
+        *community = *ids[*community].get_or_insert_with(|| {
+            next += 1;
+            next - 1
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, UnGraph};
+
+    #[test]
+    fn two_cliques_joined_by_a_bridge() {
+        let mut edges = Vec::new();
+        for offset in [0, 4] {
+            for from in 0..4 {
+                for to in from + 1..4 {
+                    edges.push((offset + from, offset + to));
+                }
+            }
+        }
+        edges.push((3, 4));
+        let graph = UnGraph::<()>::from_edges(edges).unwrap();
+
+        let communities = graph.louvain_communities();
+        assert_eq!(communities, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+        assert!((graph.modularity(&communities) - 0.4230769230769231).abs() < 1e-12);
+        assert_eq!(graph.modularity(&[0; 8]), 0.0);
+    }
+
+    #[test]
+    fn merges_across_levels() {
+        // A ring of twelve triangles, each linked to the next by one edge. The
+        // first pass finds the triangles, and joining neighboring triangles
+        // scores higher still.
+        let mut edges = Vec::new();
+        for triangle in 0..12 {
+            let base = triangle * 3;
+            edges.extend([(base, base + 1), (base + 1, base + 2), (base + 2, base)]);
+            edges.push((base + 2, (base + 3) % 36));
+        }
+        let graph = DiGraph::<()>::from_edges(edges).unwrap();
+
+        let communities = graph.louvain_communities();
+        let count = communities.iter().max().unwrap() + 1;
+        assert!(count < 12);
+        let triangles = (0..36).map(|node| node / 3).collect::<Vec<_>>();
+        assert!(graph.modularity(&communities) > graph.modularity(&triangles));
+
+        assert_eq!(
+            DiGraph::<()>::new().louvain_communities(),
+            Vec::<usize>::new()
+        );
+        let lonely = DiGraph::<()>::from_edges([(0, 1)]).unwrap();
+        assert_eq!(lonely.louvain_communities(), vec![0, 0]);
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex, UnionFind};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Groups nodes that can reach each other when edge direction is ignored.
+    ///
+    /// Components are ordered by their smallest node index, and nodes within a
+    /// component are sorted by index.
+    pub fn connected_components(&self) -> Vec<Vec<NodeIndex>> {
+        let adjacency = self.undirected_adjacency();
+        let mut labels: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        let mut count = 0;
+        let mut queue = VecDeque::new();
+
+        for root in 0..self.nodes.len() {
+            if labels[root].is_some() {
+                continue;
+            }
+
+            labels[root] = Some(count);
+            queue.push_back(root);
+            while let Some(node) = queue.pop_front() {
+                for &next in adjacency[node].iter() {
+                    if labels[next].is_none() {
+                        labels[next] = Some(count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        let mut components = vec![Vec::new(); count];
+        for (node, label) in labels.into_iter().enumerate() {
+            components[label.unwrap()].push(NodeIndex::new(node));
+        }
+        components
+    }
+
+    /// Labels every node, by node index, with the component it belongs to
+    /// when edge direction is ignored. Unlike strongly connected components,
+    /// nodes only need a path between them in one direction to share a label.
+    ///
+    /// Labels count up from 0 in order of the smallest node index in each
+    /// component.
+    pub fn weakly_connected_components(&self) -> Vec<usize> {
+        let mut sets = UnionFind::new(self.nodes.len());
+        for edge in self.edges() {
+            sets.union(edge.source().index(), edge.target().index());
+        }
+
+        let mut roots = vec![None; self.nodes.len()];
+        let mut count = 0;
+        (0..self.nodes.len())
+            .map(|node| {
+                let root = sets.find_mut(node);
+                *roots[root].get_or_insert_with(|| {
+                    count += 1;
+                    count - 1
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn ignores_direction() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        graph.add_edge(n[1], n[4], ()).unwrap();
+        graph.add_edge(n[5], n[4], ()).unwrap();
+
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![n[0], n[3]], vec![n[1], n[4], n[5]], vec![n[2]]]
+        );
+    }
+
+    #[test]
+    fn weak_components() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[4], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[5], n[0], ()).unwrap();
+
+        assert_eq!(graph.weakly_connected_components(), vec![0, 1, 2, 1, 1, 0]);
+        assert_eq!(graph.strongly_connected_components().len(), 6);
+    }
+
+    #[test]
+    fn undirected_graph() {
+        let mut graph: UnGraph<i32> = UnGraph::new_undirected();
+        assert!(graph.connected_components().is_empty());
+
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        graph.add_edge(b, a, ()).unwrap();
+        assert_eq!(graph.connected_components(), vec![vec![a, b]]);
+    }
+}
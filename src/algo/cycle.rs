@@ -0,0 +1,110 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn is_cyclic(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Returns the nodes of a cycle in order, each one connected to the next
+    /// and the last one connected back to the first. In undirected graphs a
+    /// pair of parallel edges counts as a cycle of two nodes.
+    pub fn find_cycle(&self) -> Option<Vec<NodeIndex>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        let mut colors = vec![Color::Unvisited; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if colors[root] != Color::Unvisited {
+                continue;
+            }
+
+            // Each frame is a node on the current path, the node it was reached
+            // from, and its successors left to explore.
+            let mut path = vec![(root, None, self.successors(root))];
+            colors[root] = Color::InProgress;
+            while let Some((node, parent, successors)) = path.last_mut() {
+                let Some(next) = successors.next() else {
+                    colors[*node] = Color::Done;
+                    path.pop();
+                    continue;
+                };
+
+                // The edge an undirected node was reached through leads
+                // straight back, so skip it once.
+                if !Ty::is_directed() && *parent == Some(next) {
+                    *parent = None;
+                    continue;
+                }
+
+                let node = *node;
+                match colors[next] {
+                    Color::Unvisited => {
+                        colors[next] = Color::InProgress;
+                        path.push((next, Some(node), self.successors(next)));
+                    }
+                    Color::InProgress => {
+                        let start = path.iter().position(|(node, ..)| *node == next).unwrap();
+                        return Some(
+                            path[start..]
+                                .iter()
+                                .map(|(node, ..)| NodeIndex::new(*node))
+                                .collect(),
+                        );
+                    }
+                    Color::Done => {}
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_cycles() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        assert!(!graph.is_cyclic());
+        assert_eq!(graph.find_cycle(), None);
+
+        graph.add_edge(n[2], n[3], ()).unwrap();
+        graph.add_edge(n[3], n[1], ()).unwrap();
+        assert!(graph.is_cyclic());
+        assert_eq!(graph.find_cycle(), Some(vec![n[1], n[2], n[3]]));
+    }
+
+    #[test]
+    fn undirected_cycles() {
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        assert!(!graph.is_cyclic());
+
+        graph.add_edge(n[3], n[2], ()).unwrap();
+        assert_eq!(graph.find_cycle(), Some(vec![n[1], n[3], n[2]]));
+
+        let mut parallel = UnGraph::new_undirected();
+        let a = parallel.add_node(0);
+        let b = parallel.add_node(1);
+        parallel.add_edge(a, b, ()).unwrap();
+        assert!(!parallel.is_cyclic());
+        parallel.add_edge(b, a, ()).unwrap();
+        assert_eq!(parallel.find_cycle(), Some(vec![a, b]));
+    }
+}
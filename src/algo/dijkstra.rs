@@ -0,0 +1,150 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{Measure, MinScored};
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E: Measure, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Returns the cost of the cheapest path from `source` to every node, by
+    /// node index, or `None` for nodes that cannot be reached.
+    pub fn shortest_path_costs(&self, source: NodeIndex) -> Vec<Option<E>> {
+        self.dijkstra(source.index(), None).0
+    }
+
+    pub fn shortest_path(
+        &self,
+        source: NodeIndex,
+        target: NodeIndex,
+    ) -> Option<(E, Vec<NodeIndex>)> {
+        let (source, target) = (source.index(), target.index());
+        if target >= self.nodes.len() {
+            panic!(
+                "index target out of range: target is {}, but len is {}",
+                target,
+                self.nodes.len(),
+            );
+        }
+
+        let (costs, predecessors) = self.dijkstra(source, Some(target));
+        let cost = costs[target]?;
+
+        let mut path = vec![NodeIndex::new(target)];
+        let mut node = target;
+        while let Some(previous) = predecessors[node] {
+            path.push(NodeIndex::new(previous));
+            node = previous;
+        }
+        path.reverse();
+        Some((cost, path))
+    }
+
+    fn dijkstra(
+        &self,
+        source: usize,
+        target: Option<usize>,
+    ) -> (Vec<Option<E>>, Vec<Option<usize>>) {
+        if source >= self.nodes.len() {
+            panic!(
+                "index source out of range: source is {}, but len is {}",
+                source,
+                self.nodes.len(),
+            );
+        }
+
+        let mut costs = vec![None; self.nodes.len()];
+        let mut predecessors = vec![None; self.nodes.len()];
+        let mut visited = vec![false; self.nodes.len()];
+        let mut heap = BinaryHeap::new();
+
+        costs[source] = Some(E::default());
+        heap.push(MinScored(E::default(), source));
+        while let Some(MinScored(cost, node)) = heap.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            if Some(node) == target {
+                break;
+            }
+
+            for (next, &weight) in self.weighted_successors(node) {
+                if visited[next] {
+                    continue;
+                }
+
+                let next_cost = cost + weight;
+                if costs[next].is_none_or(|current| next_cost < current) {
+                    costs[next] = Some(next_cost);
+                    predecessors[next] = Some(node);
+                    heap.push(MinScored(next_cost, next));
+                }
+            }
+        }
+
+        (costs, predecessors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    fn get_test_graph() -> Graph<char, u32> {
+        let mut graph = Graph::new();
+        for node in ['a', 'b', 'c', 'd', 'e'] {
+            graph.add_node(node);
+        }
+        graph.add_edge(n(0), n(1), 7).unwrap();
+        graph.add_edge(n(0), n(2), 2).unwrap();
+        graph.add_edge(n(2), n(1), 3).unwrap();
+        graph.add_edge(n(1), n(3), 1).unwrap();
+        graph.add_edge(n(2), n(3), 8).unwrap();
+
+        graph
+    }
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    #[test]
+    fn shortest_path_costs() {
+        let graph = get_test_graph();
+
+        assert_eq!(
+            graph.shortest_path_costs(n(0)),
+            vec![Some(0), Some(5), Some(2), Some(6), None]
+        );
+        assert_eq!(
+            graph.shortest_path_costs(n(3)),
+            vec![None, None, None, Some(0), None]
+        );
+    }
+
+    #[test]
+    fn shortest_path() {
+        let graph = get_test_graph();
+
+        assert_eq!(
+            graph.shortest_path(n(0), n(3)),
+            Some((6, vec![n(0), n(2), n(1), n(3)]))
+        );
+        assert_eq!(graph.shortest_path(n(0), n(0)), Some((0, vec![n(0)])));
+        assert_eq!(graph.shortest_path(n(3), n(0)), None);
+        assert_eq!(graph.shortest_path(n(0), n(4)), None);
+    }
+
+    #[test]
+    fn shortest_path_undirected_floats() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        let c = graph.add_node(2);
+        graph.add_edge(b, a, 0.5).unwrap();
+        graph.add_edge(c, b, 0.25).unwrap();
+        graph.add_edge(a, c, 1.0).unwrap();
+
+        assert_eq!(graph.shortest_path(a, c), Some((0.75, vec![a, b, c])));
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Directed, Graph, NodeIndex};
+
+/// The immediate dominator of every node reachable from a root, by node
+/// index. The root and unreachable nodes have none.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Dominators {
+    pub root: NodeIndex,
+    pub immediate: Vec<Option<NodeIndex>>,
+}
+
+impl Dominators {
+    pub fn immediate_dominator(&self, node: NodeIndex) -> Option<NodeIndex> {
+        self.immediate.get(node.index()).copied().flatten()
+    }
+
+    /// Returns every dominator of `node`, from the node itself up to the root,
+    /// or `None` if it cannot be reached from the root.
+    pub fn dominators(&self, node: NodeIndex) -> Option<Vec<NodeIndex>> {
+        if node != self.root {
+            self.immediate_dominator(node)?;
+        }
+
+        let mut dominators = vec![node];
+        let mut node = node;
+        while let Some(dominator) = self.immediate_dominator(node) {
+            dominators.push(dominator);
+            node = dominator;
+        }
+        Some(dominators)
+    }
+}
+
+impl<T, E> Graph<T, E, Directed> {
+    /// Finds the immediate dominators of every node reachable from `root`: the
+    /// closest node that every path from `root` to it has to pass through.
+    /// Uses the iterative algorithm of Cooper, Harvey and Kennedy.
+    ///
+    /// Panics if `root` is out of bounds.
+    pub fn dominators(&self, root: NodeIndex) -> Dominators {
+        let len = self.nodes.len();
+        if root.index() >= len {
+            panic!(
+                "index root out of range: root is {}, but len is {}",
+                root, len,
+            );
+        }
+
+        // Number the reachable nodes in postorder.
+        let mut postorder = Vec::with_capacity(len);
+        let mut numbers: Vec<Option<usize>> = vec![None; len];
+        let mut discovered = vec![false; len];
+        discovered[root.index()] = true;
+        let mut stack = vec![(root.index(), self.successors(root.index()))];
+        while let Some((node, successors)) = stack.last_mut() {
+            let node = *node;
+            match successors.find(|&next| !discovered[next]) {
+                Some(next) => {
+                    discovered[next] = true;
+                    stack.push((next, self.successors(next)));
+                }
+                None => {
+                    numbers[node] = Some(postorder.len());
+                    postorder.push(node);
+                    stack.pop();
+                }
+            }
+        }
+
+        let mut predecessors = vec![Vec::new(); len];
+        for &node in postorder.iter() {
+            for next in self.successors(node) {
+                predecessors[next].push(node);
+            }
+        }
+
+        let intersect = |immediate: &[Option<usize>], mut a: usize, mut b: usize| {
+            while a != b {
+                while numbers[a] < numbers[b] {
+                    a = immediate[a].unwrap();
+                }
+                while numbers[b] < numbers[a] {
+                    b = immediate[b].unwrap();
+                }
+            }
+            a
+        };
+
+        let mut immediate: Vec<Option<usize>> = vec![None; len];
+        immediate[root.index()] = Some(root.index());
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in postorder.iter().rev().skip(1) {
+                let mut processed = predecessors[node]
+                    .iter()
+                    .copied()
+                    .filter(|&previous| immediate[previous].is_some());
+                let first = processed.next().unwrap();
+                let dominator = processed.fold(first, |dominator, previous| {
+                    intersect(&immediate, dominator, previous)
+                });
+                if immediate[node] != Some(dominator) {
+                    immediate[node] = Some(dominator);
+                    changed = true;
+                }
+            }
+        }
+
+        immediate[root.index()] = None;
+        Dominators {
+            root,
+            immediate: immediate
+                .into_iter()
+                .map(|dominator| dominator.map(NodeIndex::new))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    #[test]
+    fn control_flow_graph() {
+        // 0 branches to 1 and 2, which join at 3. 3 loops back to 1 and
+        // exits to 4. 5 is unreachable.
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 1), (3, 4), (5, 4)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        let dominators = graph.dominators(n[0]);
+        assert_eq!(
+            dominators.immediate,
+            vec![None, Some(n[0]), Some(n[0]), Some(n[0]), Some(n[3]), None]
+        );
+        assert_eq!(dominators.dominators(n[4]), Some(vec![n[4], n[3], n[0]]));
+        assert_eq!(dominators.dominators(n[0]), Some(vec![n[0]]));
+        assert_eq!(dominators.dominators(n[5]), None);
+
+        let dominators = graph.dominators(n[1]);
+        assert_eq!(dominators.immediate_dominator(n[4]), Some(n[3]));
+        assert_eq!(dominators.immediate_dominator(n[3]), Some(n[1]));
+        assert_eq!(dominators.immediate_dominator(n[2]), None);
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn has_eulerian_path(&self) -> bool {
+        self.eulerian_path().is_some()
+    }
+
+    /// Whether there is a path using every edge once that ends where it
+    /// started.
+    pub fn has_eulerian_circuit(&self) -> bool {
+        let balanced = (0..self.nodes.len()).map(NodeIndex::new).all(|node| {
+            if Ty::is_directed() {
+                self.in_degree(node) == self.out_degree(node)
+            } else {
+                self.degree(node).is_multiple_of(2)
+            }
+        });
+        balanced && self.has_eulerian_path()
+    }
+
+    /// Returns a path that follows every edge exactly once, as the nodes it
+    /// visits, using Hierholzer's algorithm. The path is a circuit whenever the
+    /// graph has one. A graph without edges has an empty path.
+    pub fn eulerian_path(&self) -> Option<Vec<NodeIndex>> {
+        let start = self.eulerian_start()?;
+        let start = match start {
+            Some(start) => start,
+            None => return Some(Vec::new()),
+        };
+
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges() {
+            let (source, target) = (edge.source().index(), edge.target().index());
+            adjacency[source].push((target, edge.index().index()));
+            if !Ty::is_directed() {
+                adjacency[target].push((source, edge.index().index()));
+            }
+        }
+
+        let mut used = vec![false; self.edge_count];
+        let mut positions = vec![0; self.nodes.len()];
+        let mut stack = vec![start];
+        let mut path = Vec::with_capacity(self.edge_count + 1);
+        while let Some(&node) = stack.last() {
+            let unused = adjacency[node][positions[node]..]
+                .iter()
+                .position(|&(_, edge)| !used[edge]);
+            match unused {
+                Some(offset) => {
+                    positions[node] += offset + 1;
+                    let (next, edge) = adjacency[node][positions[node] - 1];
+                    used[edge] = true;
+                    stack.push(next);
+                }
+                None => {
+                    positions[node] = adjacency[node].len();
+                    path.push(NodeIndex::new(node));
+                    stack.pop();
+                }
+            }
+        }
+
+        // Edges out of reach of the start were never walked.
+        if path.len() != self.edge_count + 1 {
+            return None;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Picks where an Eulerian path has to start based on node degrees, or
+    /// returns `None` if the degrees rule one out. Returns `Some(None)` if
+    /// there are no edges to walk.
+    fn eulerian_start(&self) -> Option<Option<usize>> {
+        let mut start = None;
+        let mut any = None;
+        let (mut starts, mut ends) = (0, 0);
+        for node in (0..self.nodes.len()).map(NodeIndex::new) {
+            if self.degree(node) > 0 && any.is_none() {
+                any = Some(node.index());
+            }
+
+            let odd = if Ty::is_directed() {
+                let (out, inc) = (self.out_degree(node), self.in_degree(node));
+                if out == inc + 1 {
+                    starts += 1;
+                    true
+                } else if inc == out + 1 {
+                    ends += 1;
+                    false
+                } else if out != inc {
+                    return None;
+                } else {
+                    false
+                }
+            } else if !self.degree(node).is_multiple_of(2) {
+                starts += 1;
+                true
+            } else {
+                false
+            };
+            if odd && start.is_none() {
+                start = Some(node.index());
+            }
+        }
+
+        let valid = if Ty::is_directed() {
+            starts == ends && starts <= 1
+        } else {
+            starts == 0 || starts == 2
+        };
+        if !valid {
+            return None;
+        }
+        Some(start.or(any))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn directed_paths() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (0, 3)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        let path: Vec<_> = graph
+            .eulerian_path()
+            .unwrap()
+            .into_iter()
+            .map(NodeIndex::index)
+            .collect();
+        assert_eq!(path, vec![0, 1, 2, 0, 3]);
+        assert!(!graph.has_eulerian_circuit());
+
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        assert!(graph.has_eulerian_circuit());
+        let path = graph.eulerian_path().unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.first(), path.last());
+
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        assert!(!graph.has_eulerian_path());
+    }
+
+    #[test]
+    fn undirected_paths() {
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        let path: Vec<_> = graph
+            .eulerian_path()
+            .unwrap()
+            .into_iter()
+            .map(NodeIndex::index)
+            .collect();
+        assert_eq!(path, vec![2, 1, 0, 2, 3]);
+        assert!(!graph.has_eulerian_circuit());
+        assert_eq!(
+            UnGraph::<i32>::new_undirected().eulerian_path(),
+            Some(vec![])
+        );
+
+        // Two separate triangles have balanced degrees but no single path.
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+        assert!(!graph.has_eulerian_circuit());
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Measure;
+use crate::{EdgeType, Graph, NegativeCycleError, NodeIndex};
+
+/// The cheapest path between every pair of nodes.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AllPairsShortestPaths<K> {
+    /// `distances[from][to]` is the cost of the cheapest path, or `None` if
+    /// `to` cannot be reached from `from`.
+    pub distances: Vec<Vec<Option<K>>>,
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<K: Copy> AllPairsShortestPaths<K> {
+    pub fn distance(&self, from: NodeIndex, to: NodeIndex) -> Option<K> {
+        *self.distances.get(from.index())?.get(to.index())?
+    }
+
+    pub fn path(&self, from: NodeIndex, to: NodeIndex) -> Option<Vec<NodeIndex>> {
+        self.distance(from, to)?;
+
+        let mut path = vec![from];
+        let mut node = from.index();
+        while node != to.index() {
+            node = self.next[node][to.index()].unwrap();
+            path.push(NodeIndex::new(node));
+        }
+        Some(path)
+    }
+}
+
+impl<T, E: Measure, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds the cheapest path between every pair of nodes with the
+    /// Floyd-Warshall algorithm in O(V³) time and O(V²) memory.
+    ///
+    /// Negative weights are supported, but fails if there is a negative cycle.
+    pub fn all_pairs_shortest_paths(&self) -> Result<AllPairsShortestPaths<E>, NegativeCycleError> {
+        let len = self.nodes.len();
+        let mut distances = vec![vec![None; len]; len];
+        let mut next = vec![vec![None; len]; len];
+        for node in 0..len {
+            distances[node][node] = Some(E::default());
+            next[node][node] = Some(node);
+        }
+
+        let mut connect = |from: usize, to: usize, weight: E| {
+            if distances[from][to].is_none_or(|current| weight < current) {
+                distances[from][to] = Some(weight);
+                next[from][to] = Some(to);
+            }
+        };
+        for (from, edge_data) in self.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                connect(from, edge.target.index(), edge.weight);
+                if !Ty::is_directed() {
+                    connect(edge.target.index(), from, edge.weight);
+                }
+            }
+        }
+
+        for via in 0..len {
+            for from in 0..len {
+                let Some(first) = distances[from][via] else {
+                    continue;
+                };
+                for to in 0..len {
+                    let Some(second) = distances[via][to] else {
+                        continue;
+                    };
+                    let distance = first + second;
+                    if distances[from][to].is_none_or(|current| distance < current) {
+                        distances[from][to] = Some(distance);
+                        next[from][to] = next[from][via];
+                    }
+                }
+            }
+        }
+
+        // Bellman-Ford reports the negative cycle a node lies on in order.
+        for (node, row) in distances.iter().enumerate() {
+            if row[node].is_some_and(|distance| distance < E::default()) {
+                self.bellman_ford(NodeIndex::new(node))?;
+            }
+        }
+
+        Ok(AllPairsShortestPaths { distances, next })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn all_pairs() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[2], -2).unwrap();
+        graph.add_edge(n[2], n[3], 2).unwrap();
+        graph.add_edge(n[3], n[1], -1).unwrap();
+        graph.add_edge(n[1], n[0], 4).unwrap();
+        graph.add_edge(n[1], n[2], 3).unwrap();
+
+        let paths = graph.all_pairs_shortest_paths().unwrap();
+        assert_eq!(
+            paths.distances,
+            vec![
+                vec![Some(0), Some(-1), Some(-2), Some(0)],
+                vec![Some(4), Some(0), Some(2), Some(4)],
+                vec![Some(5), Some(1), Some(0), Some(2)],
+                vec![Some(3), Some(-1), Some(1), Some(0)],
+            ]
+        );
+        assert_eq!(paths.path(n[0], n[1]), Some(vec![n[0], n[2], n[3], n[1]]));
+        assert_eq!(paths.path(n[2], n[2]), Some(vec![n[2]]));
+    }
+
+    #[test]
+    fn unreachable_and_negative_cycles() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 1.5).unwrap();
+
+        let paths = graph.all_pairs_shortest_paths().unwrap();
+        assert_eq!(paths.distance(b, a), Some(1.5));
+        assert_eq!(paths.distance(a, c), None);
+        assert_eq!(paths.path(a, c), None);
+
+        graph.add_edge(b, c, -0.5).unwrap();
+        assert!(graph.all_pairs_shortest_paths().is_err());
+    }
+}
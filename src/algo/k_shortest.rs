@@ -0,0 +1,204 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::{Measure, MinScored};
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E: Measure, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds up to `k` of the cheapest paths from `source` to `target` that
+    /// visit no node twice, with Yen's algorithm. Paths come with their cost,
+    /// cheapest first.
+    ///
+    /// Paths are told apart by their nodes, so parallel edges only count
+    /// once, with the cheapest of them. Weights must not be negative.
+    ///
+    /// Panics if `source` or `target` is out of bounds.
+    pub fn k_shortest_paths(
+        &self,
+        source: NodeIndex,
+        target: NodeIndex,
+        k: usize,
+    ) -> Vec<(E, Vec<NodeIndex>)> {
+        let len = self.nodes.len();
+        for (name, idx) in [("source", source), ("target", target)] {
+            if idx.index() >= len {
+                panic!(
+                    "index {} out of range: {} is {}, but len is {}",
+                    name, name, idx, len,
+                );
+            }
+        }
+
+        let mut found: Vec<(E, Vec<usize>)> = Vec::new();
+        let first = self.spur_path(source.index(), target.index(), &vec![false; len], &[]);
+        found.extend(first);
+
+        let mut candidates: Vec<(E, Vec<usize>)> = Vec::new();
+        while found.len() < k {
+            let Some((_, previous)) = found.last() else {
+                break;
+            };
+
+            for i in 0..previous.len() - 1 {
+                let root = &previous[..=i];
+                let spur = previous[i];
+
+                // Edges that would lead back onto a path already found.
+                let blocked_edges: Vec<_> = found
+                    .iter()
+                    .filter(|(_, path)| path.len() > i + 1 && path[..=i] == *root)
+                    .map(|(_, path)| (path[i], path[i + 1]))
+                    .collect();
+                let mut blocked_nodes = vec![false; len];
+                for &node in &root[..i] {
+                    blocked_nodes[node] = true;
+                }
+
+                let Some((_, spur_path)) =
+                    self.spur_path(spur, target.index(), &blocked_nodes, &blocked_edges)
+                else {
+                    continue;
+                };
+                let path: Vec<_> = root.iter().chain(&spur_path[1..]).copied().collect();
+                if candidates.iter().all(|(_, candidate)| *candidate != path) {
+                    candidates.push((self.path_cost(&path), path));
+                }
+            }
+
+            let cheapest = candidates
+                .iter()
+                .enumerate()
+                .min_by(|(_, (a, _)), (_, (b, _))| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                .map(|(position, _)| position);
+            match cheapest {
+                Some(position) => found.push(candidates.remove(position)),
+                None => break,
+            }
+        }
+
+        found
+            .into_iter()
+            .map(|(cost, path)| (cost, path.into_iter().map(NodeIndex::new).collect()))
+            .collect()
+    }
+
+    /// The cost of following `path`, taking the cheapest edge between each
+    /// pair of nodes.
+    fn path_cost(&self, path: &[usize]) -> E {
+        path.windows(2).fold(E::default(), |cost, pair| {
+            let cheapest = self
+                .weighted_successors(pair[0])
+                .filter(|&(next, _)| next == pair[1])
+                .map(|(_, &weight)| weight)
+                .reduce(|a, b| if b < a { b } else { a })
+                .expect("consecutive path nodes are connected");
+            cost + cheapest
+        })
+    }
+
+    /// Dijkstra's algorithm, avoiding the blocked nodes and edges.
+    fn spur_path(
+        &self,
+        source: usize,
+        target: usize,
+        blocked_nodes: &[bool],
+        blocked_edges: &[(usize, usize)],
+    ) -> Option<(E, Vec<usize>)> {
+        let len = self.nodes.len();
+        let mut costs = vec![None; len];
+        let mut predecessors = vec![None; len];
+        let mut visited = blocked_nodes.to_vec();
+        let mut heap = BinaryHeap::new();
+
+        costs[source] = Some(E::default());
+        heap.push(MinScored(E::default(), source));
+        while let Some(MinScored(cost, node)) = heap.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            if node == target {
+                break;
+            }
+
+            for (next, &weight) in self.weighted_successors(node) {
+                if visited[next] || blocked_edges.contains(&(node, next)) {
+                    continue;
+                }
+
+                let next_cost = cost + weight;
+                if costs[next].is_none_or(|current| next_cost < current) {
+                    costs[next] = Some(next_cost);
+                    predecessors[next] = Some(node);
+                    heap.push(MinScored(next_cost, next));
+                }
+            }
+        }
+
+        let cost = costs[target]?;
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(previous) = predecessors[node] {
+            path.push(previous);
+            node = previous;
+        }
+        path.reverse();
+        Some((cost, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    #[test]
+    fn yen_example() {
+        // The example from the Wikipedia article on Yen's algorithm, with
+        // C, D, E, F, G, H as 0 to 5.
+        let mut graph: Graph<char, u32> = "CDEFGH".chars().collect();
+        for (from, to, weight) in [
+            (0, 1, 3),
+            (0, 2, 2),
+            (1, 3, 4),
+            (2, 1, 1),
+            (2, 3, 2),
+            (2, 4, 3),
+            (3, 4, 2),
+            (3, 5, 1),
+            (4, 5, 2),
+        ] {
+            graph.add_edge(n(from), n(to), weight).unwrap();
+        }
+
+        assert_eq!(
+            graph.k_shortest_paths(n(0), n(5), 3),
+            vec![
+                (5, vec![n(0), n(2), n(3), n(5)]),
+                (7, vec![n(0), n(2), n(4), n(5)]),
+                (8, vec![n(0), n(1), n(3), n(5)]),
+            ]
+        );
+        assert_eq!(graph.k_shortest_paths(n(0), n(5), 10).len(), 7);
+        assert_eq!(graph.k_shortest_paths(n(5), n(0), 3), vec![]);
+        assert_eq!(graph.k_shortest_paths(n(0), n(0), 3), vec![(0, vec![n(0)])]);
+    }
+
+    #[test]
+    fn undirected_with_parallel_edges() {
+        let mut graph = UnGraph::<(), u32>::from_edges([(0, 1), (1, 2)]).unwrap();
+        graph.edge_weights_mut().for_each(|weight| *weight = 1);
+        graph.add_edge(n(2), n(1), 0).unwrap();
+        graph.add_edge(n(0), n(2), 5).unwrap();
+
+        assert_eq!(
+            graph.k_shortest_paths(n(0), n(2), 5),
+            vec![(1, vec![n(0), n(1), n(2)]), (5, vec![n(0), n(2)])]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Measure;
+use crate::{CycleError, Directed, Graph, NodeIndex};
+
+impl<T, E: Measure> Graph<T, E, Directed> {
+    /// Finds the path whose edge weights add up to the most, the critical
+    /// path when edges are tasks and their weights durations. Returns the
+    /// total along with the nodes of the path, or a single node with a cost
+    /// of zero if no path is more than that.
+    ///
+    /// Fails if the graph has a cycle, as paths could then grow forever.
+    pub fn longest_path(&self) -> Result<(E, Vec<NodeIndex>), CycleError> {
+        let order = self.topological_sort()?;
+        let Some(&first) = order.first() else {
+            return Ok((E::default(), Vec::new()));
+        };
+
+        // The most costly path ending at every node, by way of its
+        // predecessor on that path. A node on its own costs nothing, but an
+        // edge of weight zero into it still makes the path longer.
+        let mut costs = vec![E::default(); self.nodes.len()];
+        let mut predecessors = vec![None; self.nodes.len()];
+        let mut end = first.index();
+        for node in order.into_iter().map(|idx| idx.index()) {
+            for edge in self.edges[node].iter() {
+                let target = edge.target.index();
+                let cost = costs[node] + edge.weight;
+                let tied = cost == costs[target] && predecessors[target].is_none();
+                if cost > costs[target] || tied {
+                    costs[target] = cost;
+                    predecessors[target] = Some(node);
+                }
+            }
+            if costs[node] > costs[end] {
+                end = node;
+            }
+        }
+
+        let mut path = vec![NodeIndex::new(end)];
+        let mut node = end;
+        while let Some(previous) = predecessors[node] {
+            path.push(NodeIndex::new(previous));
+            node = previous;
+        }
+        path.reverse();
+        Ok((costs[end], path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CycleError, DiGraph, NodeIndex};
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    #[test]
+    fn critical_path() {
+        let mut tasks: DiGraph<&str, u32> = ["start", "design", "build", "docs", "ship"]
+            .into_iter()
+            .collect();
+        tasks.add_edge(n(0), n(1), 0).unwrap();
+        tasks.add_edge(n(1), n(2), 5).unwrap();
+        tasks.add_edge(n(1), n(3), 5).unwrap();
+        tasks.add_edge(n(2), n(4), 10).unwrap();
+        tasks.add_edge(n(3), n(4), 2).unwrap();
+        tasks.add_edge(n(0), n(4), 12).unwrap();
+
+        assert_eq!(tasks.longest_path(), Ok((15, vec![n(0), n(1), n(2), n(4)])));
+
+        tasks.add_edge(n(4), n(0), 1).unwrap();
+        assert!(matches!(tasks.longest_path(), Err(CycleError(_))));
+    }
+
+    #[test]
+    fn negative_and_missing_edges() {
+        let empty = DiGraph::<(), i32>::new();
+        assert_eq!(empty.longest_path(), Ok((0, vec![])));
+
+        let mut graph = DiGraph::<(), i32>::from_edges([(0, 1), (1, 2)]).unwrap();
+        *graph.edge_weight_mut(n(0), n(1)).unwrap() = -3;
+        *graph.edge_weight_mut(n(1), n(2)).unwrap() = 2;
+        assert_eq!(graph.longest_path(), Ok((2, vec![n(1), n(2)])));
+    }
+}
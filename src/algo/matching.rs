@@ -0,0 +1,133 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds a largest set of edges of a bipartite graph with no node in
+    /// common, using the Hopcroft–Karp algorithm, or returns `None` if the
+    /// graph is not bipartite. Edge direction is ignored.
+    ///
+    /// Every pair starts with its node from the first set of
+    /// [`Graph::bipartition`], and pairs are sorted by that node.
+    pub fn maximum_matching(&self) -> Option<Vec<(NodeIndex, NodeIndex)>> {
+        let (left, _) = self.bipartition()?;
+        let adjacency = self.undirected_adjacency();
+        let len = self.nodes.len();
+        let mut pair_left: Vec<Option<usize>> = vec![None; len];
+        let mut pair_right: Vec<Option<usize>> = vec![None; len];
+        let mut layers = vec![usize::MAX; len];
+        let mut positions = vec![0; len];
+        let mut queue = VecDeque::new();
+        let mut stack = Vec::new();
+
+        loop {
+            // Layer the left nodes by the length of the shortest alternating
+            // path from a free one.
+            let mut found = false;
+            for node in left.iter().map(|node| node.index()) {
+                layers[node] = if pair_left[node].is_none() {
+                    queue.push_back(node);
+                    0
+                } else {
+                    usize::MAX
+                };
+            }
+            while let Some(node) = queue.pop_front() {
+                for &next in adjacency[node].iter() {
+                    match pair_right[next] {
+                        None => found = true,
+                        Some(partner) if layers[partner] == usize::MAX => {
+                            layers[partner] = layers[node] + 1;
+                            queue.push_back(partner);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+
+            // Augment along node-disjoint shortest paths, following the layers.
+            for node in left.iter().map(|node| node.index()) {
+                positions[node] = 0;
+            }
+            for root in left.iter().map(|node| node.index()) {
+                if pair_left[root].is_some() {
+                    continue;
+                }
+
+                stack.clear();
+                stack.push(root);
+                while let Some(&node) = stack.last() {
+                    if positions[node] == adjacency[node].len() {
+                        layers[node] = usize::MAX;
+                        stack.pop();
+                        continue;
+                    }
+
+                    let next = adjacency[node][positions[node]];
+                    positions[node] += 1;
+                    match pair_right[next] {
+                        None => {
+                            for &node in stack.iter() {
+                                let next = adjacency[node][positions[node] - 1];
+                                pair_left[node] = Some(next);
+                                pair_right[next] = Some(node);
+                            }
+                            break;
+                        }
+                        Some(partner) if layers[partner] == layers[node] + 1 => {
+                            stack.push(partner);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        Some(
+            left.into_iter()
+                .filter_map(|node| Some((node, NodeIndex::new(pair_left[node.index()]?))))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn assignment() {
+        // Workers 0 to 2 and jobs 3 to 5. A greedy choice of 0 -> 3 leaves
+        // worker 1 without a job.
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        for (worker, job) in [(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)] {
+            graph.add_edge(n[worker], n[job], ()).unwrap();
+        }
+
+        let matching = graph.maximum_matching().unwrap();
+        assert_eq!(matching.len(), 3);
+        assert_eq!(matching[0].0, n[0]);
+        assert!(matching.contains(&(n[1], n[3])));
+        let mut jobs: Vec<_> = matching.iter().map(|&(_, job)| job).collect();
+        jobs.sort();
+        assert_eq!(jobs, vec![n[3], n[4], n[5]]);
+    }
+
+    #[test]
+    fn unmatched_and_not_bipartite() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[2], n[0], ()).unwrap();
+        assert_eq!(graph.maximum_matching().unwrap().len(), 1);
+
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        assert_eq!(graph.maximum_matching(), None);
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Sub;
+
+use super::Measure;
+use crate::{Directed, EdgeIndex, Graph, NodeIndex};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MaxFlow<K> {
+    pub value: K,
+    /// The flow through every edge, by [`EdgeIndex`].
+    pub edge_flows: Vec<K>,
+}
+
+impl<K: Copy> MaxFlow<K> {
+    pub fn edge_flow(&self, edge: EdgeIndex) -> Option<K> {
+        self.edge_flows.get(edge.index()).copied()
+    }
+}
+
+impl<T, E: Measure + Sub<Output = E>> Graph<T, E, Directed> {
+    /// Finds the maximum flow from `source` to `sink` with the Edmonds-Karp
+    /// algorithm, treating edge weights as capacities.
+    ///
+    /// Panics if `source` or `sink` is out of bounds.
+    pub fn max_flow(&self, source: NodeIndex, sink: NodeIndex) -> MaxFlow<E> {
+        for (name, idx) in [("source", source), ("sink", sink)] {
+            if idx.index() >= self.nodes.len() {
+                panic!(
+                    "index {} out of range: {} is {}, but len is {}",
+                    name,
+                    name,
+                    idx,
+                    self.nodes.len(),
+                );
+            }
+        }
+
+        let zero = E::default();
+
+        // Every edge becomes a pair of arcs in the residual graph: arc `2 * i`
+        // carries the remaining capacity of edge `i`, and arc `2 * i + 1` the
+        // flow that can be pushed back.
+        let mut heads = Vec::new();
+        let mut residuals = Vec::new();
+        let mut arcs = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges() {
+            let arc = heads.len();
+            heads.extend([edge.target().index(), edge.source().index()]);
+            residuals.extend([*edge.weight(), zero]);
+            arcs[edge.source().index()].push(arc);
+            arcs[edge.target().index()].push(arc + 1);
+        }
+
+        let mut value = zero;
+        let mut incoming_arcs = vec![None; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        loop {
+            incoming_arcs.fill(None);
+            queue.clear();
+            queue.push_back(source.index());
+            while let Some(node) = queue.pop_front() {
+                for &arc in arcs[node].iter() {
+                    let head = heads[arc];
+                    if head != source.index()
+                        && incoming_arcs[head].is_none()
+                        && residuals[arc] > zero
+                    {
+                        incoming_arcs[head] = Some(arc);
+                        queue.push_back(head);
+                    }
+                }
+            }
+
+            if incoming_arcs[sink.index()].is_none() {
+                break;
+            }
+
+            let mut bottleneck = None;
+            let mut node = sink.index();
+            while let Some(arc) = incoming_arcs[node] {
+                if bottleneck.is_none_or(|bottleneck| residuals[arc] < bottleneck) {
+                    bottleneck = Some(residuals[arc]);
+                }
+                node = heads[arc ^ 1];
+            }
+
+            let bottleneck = bottleneck.unwrap();
+            let mut node = sink.index();
+            while let Some(arc) = incoming_arcs[node] {
+                residuals[arc] = residuals[arc] - bottleneck;
+                residuals[arc ^ 1] = residuals[arc ^ 1] + bottleneck;
+                node = heads[arc ^ 1];
+            }
+            value = value + bottleneck;
+        }
+
+        MaxFlow {
+            value,
+            edge_flows: residuals.into_iter().skip(1).step_by(2).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EdgeIndex, Graph};
+
+    #[test]
+    fn max_flow() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 16).unwrap();
+        graph.add_edge(n[0], n[2], 13).unwrap();
+        graph.add_edge(n[1], n[3], 12).unwrap();
+        graph.add_edge(n[2], n[1], 4).unwrap();
+        graph.add_edge(n[2], n[4], 14).unwrap();
+        graph.add_edge(n[3], n[2], 9).unwrap();
+        graph.add_edge(n[3], n[5], 20).unwrap();
+        graph.add_edge(n[4], n[3], 7).unwrap();
+        graph.add_edge(n[4], n[5], 4).unwrap();
+
+        let flow = graph.max_flow(n[0], n[5]);
+        assert_eq!(flow.value, 23);
+        for edge in graph.edges() {
+            assert!(flow.edge_flows[edge.index().index()] <= *edge.weight());
+        }
+        for node in &n[1..5] {
+            let inflow: i32 = graph
+                .edges()
+                .filter(|edge| edge.target() == *node)
+                .map(|edge| flow.edge_flows[edge.index().index()])
+                .sum();
+            let outflow: i32 = graph
+                .edges()
+                .filter(|edge| edge.source() == *node)
+                .map(|edge| flow.edge_flows[edge.index().index()])
+                .sum();
+            assert_eq!(inflow, outflow);
+        }
+        assert_eq!(flow.edge_flow(EdgeIndex::new(9)), None);
+    }
+
+    #[test]
+    fn disconnected_sink() {
+        let mut graph = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 2.5).unwrap();
+
+        let flow = graph.max_flow(a, c);
+        assert_eq!(flow.value, 0.0);
+        assert_eq!(flow.edge_flows, vec![0.0]);
+        assert_eq!(graph.max_flow(a, b).value, 2.5);
+    }
+}
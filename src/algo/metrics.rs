@@ -0,0 +1,179 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// The number of edges as a fraction of the most a simple graph with as
+    /// many nodes could have. Self-loops and parallel edges are counted too,
+    /// so the density of a multigraph can go above 1. Graphs with fewer than
+    /// two nodes have a density of 0.
+    pub fn density(&self) -> f64 {
+        let len = self.nodes.len() as f64;
+        if len < 2.0 {
+            return 0.0;
+        }
+
+        let pairs = len * (len - 1.0);
+        if Ty::is_directed() {
+            self.edge_count as f64 / pairs
+        } else {
+            2.0 * self.edge_count as f64 / pairs
+        }
+    }
+
+    /// The number of edges on the way from `node` to the node furthest from
+    /// it, with edges unweighted, or `None` if some node cannot be reached.
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn eccentricity(&self, node: NodeIndex) -> Option<usize> {
+        let len = self.nodes.len();
+        if node.index() >= len {
+            panic!(
+                "index node out of range: node is {}, but len is {}",
+                node, len
+            );
+        }
+
+        let mut distances = vec![None; len];
+        let mut queue = VecDeque::new();
+        self.farthest_distance(node.index(), &mut distances, &mut queue)
+    }
+
+    /// The eccentricity of every node, found with a breadth-first search from
+    /// each of them.
+    pub fn eccentricities(&self) -> Vec<Option<usize>> {
+        let sources: Vec<_> = (0..self.nodes.len()).map(NodeIndex::new).collect();
+        self.eccentricities_of(&sources)
+    }
+
+    /// The largest eccentricity of any node, or `None` if the graph is empty
+    /// or some node cannot be reached from another.
+    pub fn diameter(&self) -> Option<usize> {
+        let sources: Vec<_> = (0..self.nodes.len()).map(NodeIndex::new).collect();
+        self.diameter_sampled(&sources)
+    }
+
+    /// The smallest eccentricity of any node, or `None` if the graph is empty
+    /// or no node reaches every other.
+    pub fn radius(&self) -> Option<usize> {
+        let sources: Vec<_> = (0..self.nodes.len()).map(NodeIndex::new).collect();
+        self.radius_sampled(&sources)
+    }
+
+    /// Approximates [`Graph::diameter`] using only the eccentricities of
+    /// `sources`, which can only fall short of the true diameter. Passing
+    /// every node gives the exact result.
+    ///
+    /// Panics if any of the sources is out of bounds.
+    pub fn diameter_sampled(&self, sources: &[NodeIndex]) -> Option<usize> {
+        if sources.is_empty() {
+            return None;
+        }
+
+        self.eccentricities_of(sources)
+            .into_iter()
+            .try_fold(0, |diameter, eccentricity| {
+                eccentricity.map(|eccentricity| diameter.max(eccentricity))
+            })
+    }
+
+    /// Approximates [`Graph::radius`] using only the eccentricities of
+    /// `sources`, which can only overshoot the true radius. Passing every
+    /// node gives the exact result.
+    ///
+    /// Panics if any of the sources is out of bounds.
+    pub fn radius_sampled(&self, sources: &[NodeIndex]) -> Option<usize> {
+        self.eccentricities_of(sources).into_iter().flatten().min()
+    }
+
+    fn eccentricities_of(&self, sources: &[NodeIndex]) -> Vec<Option<usize>> {
+        let len = self.nodes.len();
+        let mut distances = vec![None; len];
+        let mut queue = VecDeque::new();
+        sources
+            .iter()
+            .map(|&source| {
+                if source.index() >= len {
+                    panic!(
+                        "index source out of range: source is {}, but len is {}",
+                        source, len,
+                    );
+                }
+                distances.fill(None);
+                self.farthest_distance(source.index(), &mut distances, &mut queue)
+            })
+            .collect()
+    }
+
+    /// Breadth-first search from `source`, returning the distance to the
+    /// last node found if every node was found.
+    fn farthest_distance(
+        &self,
+        source: usize,
+        distances: &mut [Option<usize>],
+        queue: &mut VecDeque<usize>,
+    ) -> Option<usize> {
+        distances[source] = Some(0);
+        queue.push_back(source);
+        let mut found = 0;
+        let mut farthest = 0;
+        while let Some(node) = queue.pop_front() {
+            found += 1;
+            farthest = distances[node].unwrap();
+            for next in self.successors(node) {
+                if distances[next].is_none() {
+                    distances[next] = Some(farthest + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        (found == self.nodes.len()).then_some(farthest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn path_metrics() {
+        let path = UnGraph::<u32>::path_graph(5);
+        assert_eq!(path.density(), 0.4);
+        assert_eq!(
+            path.eccentricities(),
+            vec![Some(4), Some(3), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(path.diameter(), Some(4));
+        assert_eq!(path.radius(), Some(2));
+
+        let middle = [NodeIndex::new(2)];
+        assert_eq!(path.diameter_sampled(&middle), Some(2));
+        assert_eq!(path.radius_sampled(&middle), Some(2));
+        assert_eq!(path.diameter_sampled(&[]), None);
+    }
+
+    #[test]
+    fn directed_and_disconnected() {
+        let mut graph = Graph::<u32>::path_graph(3);
+        assert_eq!(graph.density(), 2.0 / 6.0);
+        assert_eq!(graph.eccentricity(NodeIndex::new(0)), Some(2));
+        assert_eq!(graph.eccentricity(NodeIndex::new(1)), None);
+        assert_eq!(graph.diameter(), None);
+        assert_eq!(graph.radius(), Some(2));
+
+        graph
+            .add_edge(NodeIndex::new(2), NodeIndex::new(0), ())
+            .unwrap();
+        assert_eq!(graph.diameter(), Some(2));
+        graph.add_node(3);
+        assert_eq!(graph.radius(), None);
+
+        let empty = Graph::<u32>::new();
+        assert_eq!(empty.density(), 0.0);
+        assert_eq!(empty.diameter(), None);
+        assert_eq!(empty.radius(), None);
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Sub;
+
+use super::Measure;
+use crate::{Directed, EdgeIndex, EdgeType, Graph, NodeIndex, Undirected};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MinCut<K> {
+    /// The total weight of the cut edges.
+    pub value: K,
+    /// The nodes on the side of the source, or of node 0 for a global cut.
+    pub side: Vec<NodeIndex>,
+    pub other_side: Vec<NodeIndex>,
+    /// The edges between the two sides, or in directed graphs, from `side`
+    /// to `other_side`.
+    pub cut_edges: Vec<EdgeIndex>,
+}
+
+impl<T, E: Measure> Graph<T, E, Undirected> {
+    /// Finds the cheapest set of edges whose removal splits the graph in two
+    /// with the Stoer-Wagner algorithm, or `None` if there are fewer than two
+    /// nodes. Parallel edges add up, and self-loops never count.
+    ///
+    /// Runs in `O(n³)` time, where `n` is the number of nodes.
+    pub fn min_cut(&self) -> Option<MinCut<E>> {
+        let len = self.nodes.len();
+        if len < 2 {
+            return None;
+        }
+
+        let zero = E::default();
+        let mut weights = vec![vec![zero; len]; len];
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                let target = edge.target.index();
+                if source != target {
+                    weights[source][target] = weights[source][target] + edge.weight;
+                    weights[target][source] = weights[target][source] + edge.weight;
+                }
+            }
+        }
+
+        // Every node left stands for the original nodes merged into it.
+        let mut members: Vec<_> = (0..len).map(|node| vec![node]).collect();
+        let mut active: Vec<_> = (0..len).collect();
+        let mut best: Option<(E, Vec<usize>)> = None;
+        let mut connection = vec![zero; len];
+        let mut added = vec![false; len];
+        while active.len() > 1 {
+            // Grow a set from the first node, always adding the node most
+            // tightly connected to it. The last node added is cut off from
+            // the rest by the cheapest cut that separates it from the one
+            // before it.
+            for &node in active.iter() {
+                connection[node] = zero;
+                added[node] = false;
+            }
+            let (mut previous, mut last) = (active[0], active[0]);
+            for _ in 0..active.len() {
+                let next = active
+                    .iter()
+                    .copied()
+                    .filter(|&node| !added[node])
+                    .reduce(|a, b| if connection[b] > connection[a] { b } else { a })
+                    .expect("a node is left to add");
+                added[next] = true;
+                for &node in active.iter() {
+                    connection[node] = connection[node] + weights[next][node];
+                }
+                (previous, last) = (last, next);
+            }
+
+            let phase_cut = connection[last];
+            if best.as_ref().is_none_or(|(value, _)| phase_cut < *value) {
+                best = Some((phase_cut, members[last].clone()));
+            }
+
+            // Merge the last node into the one before it.
+            let merged = core::mem::take(&mut members[last]);
+            members[previous].extend(merged);
+            for &node in active.iter() {
+                weights[previous][node] = weights[previous][node] + weights[last][node];
+                weights[node][previous] = weights[previous][node];
+            }
+            weights[previous][previous] = zero;
+            active.retain(|&node| node != last);
+        }
+
+        let (value, cut_off) = best.expect("at least one phase ran");
+        let mut side = vec![true; len];
+        for node in cut_off {
+            side[node] = false;
+        }
+        if !side[0] {
+            side.iter_mut().for_each(|side| *side = !*side);
+        }
+        Some(self.cut_from_side(value, &side))
+    }
+}
+
+impl<T, E: Measure + Sub<Output = E>> Graph<T, E, Directed> {
+    /// Finds the cheapest set of edges whose removal leaves no path from
+    /// `source` to `sink`, from the maximum flow between them, treating edge
+    /// weights as capacities. The value of the cut is that of the flow.
+    ///
+    /// Panics if `source` or `sink` is out of bounds.
+    pub fn min_cut_between(&self, source: NodeIndex, sink: NodeIndex) -> MinCut<E> {
+        let flow = self.max_flow(source, sink);
+
+        // The source side is whatever the source still reaches through edges
+        // with capacity to spare, or back along edges carrying flow.
+        let edges: Vec<_> = self.edges().collect();
+        let mut incident = vec![Vec::new(); self.nodes.len()];
+        for (position, edge) in edges.iter().enumerate() {
+            incident[edge.source().index()].push(position);
+            incident[edge.target().index()].push(position);
+        }
+
+        let mut side = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::from([source.index()]);
+        side[source.index()] = true;
+        while let Some(node) = queue.pop_front() {
+            for &position in incident[node].iter() {
+                let edge = &edges[position];
+                let flow = flow.edge_flows[edge.index().index()];
+                let (from, to) = (edge.source().index(), edge.target().index());
+                let next = if from == node && flow < *edge.weight() {
+                    to
+                } else if to == node && flow > E::default() {
+                    from
+                } else {
+                    continue;
+                };
+                if !side[next] {
+                    side[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        self.cut_from_side(flow.value, &side)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    fn cut_from_side<K>(&self, value: K, side: &[bool]) -> MinCut<K> {
+        let (inside, outside): (Vec<_>, Vec<_>) =
+            (0..self.nodes.len()).partition(|&node| side[node]);
+        let cut_edges = self
+            .edges()
+            .filter(|edge| {
+                let (from, to) = (side[edge.source().index()], side[edge.target().index()]);
+                if Ty::is_directed() {
+                    from && !to
+                } else {
+                    from != to
+                }
+            })
+            .map(|edge| edge.index())
+            .collect();
+
+        MinCut {
+            value,
+            side: inside.into_iter().map(NodeIndex::new).collect(),
+            other_side: outside.into_iter().map(NodeIndex::new).collect(),
+            cut_edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EdgeIndex, Graph, NodeIndex, UnGraph};
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    #[test]
+    fn stoer_wagner_example() {
+        // The example from the Stoer-Wagner paper, with nodes 1 to 8 as 0 to
+        // 7.
+        let mut graph = UnGraph::<(), u32>::with_capacity(8, 0);
+        for _ in 0..8 {
+            graph.add_node(());
+        }
+        for (from, to, weight) in [
+            (0, 1, 2),
+            (0, 4, 3),
+            (1, 2, 3),
+            (1, 4, 2),
+            (1, 5, 2),
+            (2, 3, 4),
+            (2, 6, 2),
+            (3, 6, 2),
+            (3, 7, 2),
+            (4, 5, 3),
+            (5, 6, 1),
+            (6, 7, 3),
+        ] {
+            graph.add_edge(n(from), n(to), weight).unwrap();
+        }
+
+        let cut = graph.min_cut().unwrap();
+        assert_eq!(cut.value, 4);
+        assert_eq!(cut.side, vec![n(0), n(1), n(4), n(5)]);
+        assert_eq!(cut.other_side, vec![n(2), n(3), n(6), n(7)]);
+        assert_eq!(cut.cut_edges, vec![EdgeIndex::new(2), EdgeIndex::new(10)]);
+
+        assert_eq!(UnGraph::<(), u32>::path_graph(1).min_cut(), None);
+        let split = UnGraph::<(), u32>::from_edges([(0, 1), (2, 3)]).unwrap();
+        assert_eq!(split.min_cut().unwrap().value, 0);
+    }
+
+    #[test]
+    fn cut_between_from_max_flow() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 16).unwrap();
+        graph.add_edge(n[0], n[2], 13).unwrap();
+        graph.add_edge(n[1], n[3], 12).unwrap();
+        graph.add_edge(n[2], n[1], 4).unwrap();
+        graph.add_edge(n[2], n[4], 14).unwrap();
+        graph.add_edge(n[3], n[2], 9).unwrap();
+        graph.add_edge(n[3], n[5], 20).unwrap();
+        graph.add_edge(n[4], n[3], 7).unwrap();
+        graph.add_edge(n[4], n[5], 4).unwrap();
+
+        let cut = graph.min_cut_between(n[0], n[5]);
+        assert_eq!(cut.value, 23);
+        assert_eq!(cut.side, vec![n[0], n[1], n[2], n[4]]);
+        assert_eq!(cut.other_side, vec![n[3], n[5]]);
+        let weights: i32 = cut
+            .cut_edges
+            .iter()
+            .map(|&cut_edge| {
+                let edge = graph.edges().find(|edge| edge.index() == cut_edge);
+                *edge.unwrap().weight()
+            })
+            .sum();
+        assert_eq!(weights, 23);
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Add;
+
+use crate::{EdgeType, Graph};
+
+pub use bellman_ford::ShortestPaths;
+pub use clustering::ClusteringCoefficients;
+pub use coloring::ColoringOrder;
+pub use dominators::Dominators;
+pub use floyd_warshall::AllPairsShortestPaths;
+pub use max_flow::MaxFlow;
+pub use min_cut::MinCut;
+pub use reachability::ReachabilityIndex;
+pub use simple_paths::SimplePaths;
+
+pub mod articulation;
+pub mod astar;
+pub mod bellman_ford;
+pub mod bipartite;
+pub mod centrality;
+pub mod clique;
+pub mod clustering;
+pub mod coloring;
+pub mod community;
+pub mod components;
+pub mod cycle;
+pub mod dijkstra;
+pub mod dominators;
+pub mod euler;
+pub mod floyd_warshall;
+pub mod k_shortest;
+pub mod longest_path;
+pub mod matching;
+pub mod max_flow;
+pub mod metrics;
+pub mod min_cut;
+pub mod pagerank;
+pub mod reachability;
+pub mod scc;
+pub mod simple_paths;
+pub mod toposort;
+pub mod transitive;
+pub mod tsp;
+
+pub trait Measure: Copy + PartialOrd + Add<Output = Self> + Default {}
+
+impl<M: Copy + PartialOrd + Add<Output = M> + Default> Measure for M {}
+
+/// A cost paired with a node, ordered so that `BinaryHeap` pops the smallest
+/// cost first.
+pub(crate) struct MinScored<K>(pub K, pub usize);
+
+impl<K: PartialOrd> PartialEq for MinScored<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: PartialOrd> Eq for MinScored<K> {}
+
+impl<K: PartialOrd> PartialOrd for MinScored<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: PartialOrd> Ord for MinScored<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .partial_cmp(&self.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Lists the neighbors of every node with edge direction ignored, built in
+    /// a single pass over the edges.
+    pub(crate) fn undirected_adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                adjacency[source].push(edge.target.index());
+                adjacency[edge.target.index()].push(source);
+            }
+        }
+        adjacency
+    }
+
+    /// The distinct neighbors of every node, sorted, with edge direction
+    /// ignored and self-loops left out.
+    pub(crate) fn simple_adjacency(&self) -> Vec<Vec<usize>> {
+        let mut adjacency = self.undirected_adjacency();
+        for (node, neighbors) in adjacency.iter_mut().enumerate() {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors.retain(|&neighbor| neighbor != node);
+        }
+        adjacency
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Scores every node by the probability that a random walk ends up on it,
+    /// where each step follows a random edge with probability `damping` and
+    /// jumps to a random node otherwise. Nodes without outgoing edges jump to
+    /// a random node every time. Parallel edges count once each, and in
+    /// undirected graphs edges are followed both ways.
+    ///
+    /// Iterates until the scores change by less than `tolerance` in total, or
+    /// `max_iterations` times. The scores sum to one.
+    ///
+    /// Panics if `damping` is not between 0 and 1.
+    pub fn pagerank(&self, damping: f64, tolerance: f64, max_iterations: usize) -> Vec<f64> {
+        check_damping(damping);
+        let len = self.nodes.len();
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let out_degrees: Vec<usize> = (0..len).map(|node| self.successors(node).count()).collect();
+        let mut ranks = vec![1.0 / len as f64; len];
+        for _ in 0..max_iterations {
+            let dangling: f64 = (0..len)
+                .filter(|&node| out_degrees[node] == 0)
+                .map(|node| ranks[node])
+                .sum();
+            let mut next = vec![(1.0 - damping + damping * dangling) / len as f64; len];
+            for node in 0..len {
+                if out_degrees[node] == 0 {
+                    continue;
+                }
+                let share = damping * ranks[node] / out_degrees[node] as f64;
+                for target in self.successors(node) {
+                    next[target] += share;
+                }
+            }
+
+            let change: f64 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+            ranks = next;
+            if change < tolerance {
+                break;
+            }
+        }
+        ranks
+    }
+}
+
+pub(crate) fn check_damping(damping: f64) {
+    if !(0.0..=1.0).contains(&damping) {
+        panic!(
+            "damping out of range: damping is {}, but must be between 0 and 1",
+            damping
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_ranks() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[2], n[0], ()).unwrap();
+        graph.add_edge(n[3], n[0], ()).unwrap();
+
+        let ranks = graph.pagerank(0.85, 1e-10, 100);
+        assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(ranks[0] > ranks[1] && ranks[1] > ranks[2] && ranks[2] > ranks[3]);
+        assert!((ranks[3] - 0.15 / 4.0).abs() < 1e-9);
+        assert!(Graph::<i32>::new().pagerank(0.85, 1e-10, 100).is_empty());
+    }
+
+    #[test]
+    fn undirected_and_dangling() {
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+        let ranks = graph.pagerank(0.85, 1e-12, 1000);
+        assert!((ranks[0] - ranks[2]).abs() < 1e-9);
+        assert!(ranks[1] > ranks[0]);
+
+        let mut graph = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        graph.add_edge(a, b, ()).unwrap();
+        let ranks = graph.pagerank(1.0, 1e-12, 1000);
+        assert!((ranks[1] - 2.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_bad_damping() {
+        Graph::<i32>::new().pagerank(1.5, 1e-10, 100);
+    }
+}
@@ -0,0 +1,164 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Whether `to` can be reached from `from`. Every node reaches itself.
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn has_path(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        let len = self.nodes.len();
+        if to.index() >= len {
+            panic!("index to out of range: to is {}, but len is {}", to, len);
+        }
+
+        self.bfs(from).any(|node| node == to)
+    }
+
+    /// Iterates over the nodes that can be reached from `start`, itself
+    /// included, in breadth-first order.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn reachable_from(&self, start: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.bfs(start)
+    }
+
+    /// Precomputes which nodes reach which, to answer many queries on a graph
+    /// that no longer changes.
+    pub fn reachability_index(&self) -> ReachabilityIndex {
+        ReachabilityIndex::new(self)
+    }
+}
+
+/// Answers whether one node reaches another in constant time.
+///
+/// Nodes that reach each other are collapsed into their strongly connected
+/// component, and every component stores the set of components it reaches as
+/// a bitset, so the index takes about `c * c / 8` bytes for `c` components.
+/// It goes stale as soon as the graph changes.
+#[derive(Debug, Clone)]
+pub struct ReachabilityIndex {
+    component_of: Vec<usize>,
+    /// Words per component in `reachable`.
+    stride: usize,
+    reachable: Vec<u64>,
+}
+
+impl ReachabilityIndex {
+    pub fn new<T, E, Ty: EdgeType>(graph: &Graph<T, E, Ty>) -> Self {
+        // In reverse topological order, so each component comes after every
+        // component it reaches.
+        let components = graph.strongly_connected_components();
+        let mut component_of = vec![0; graph.nodes.len()];
+        for (component, members) in components.iter().enumerate() {
+            for member in members.iter() {
+                component_of[member.index()] = component;
+            }
+        }
+
+        let stride = components.len().div_ceil(64);
+        let mut reachable = vec![0u64; stride * components.len()];
+        for (component, members) in components.iter().enumerate() {
+            let (done, rest) = reachable.split_at_mut(component * stride);
+            let row = &mut rest[..stride];
+            row[component / 64] |= 1 << (component % 64);
+            for member in members.iter() {
+                for next in graph.successors(member.index()) {
+                    let next = component_of[next];
+                    if next != component {
+                        let next_row = &done[next * stride..(next + 1) * stride];
+                        for (word, next_word) in row.iter_mut().zip(next_row) {
+                            *word |= next_word;
+                        }
+                    }
+                }
+            }
+        }
+
+        Self {
+            component_of,
+            stride,
+            reachable,
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.component_of.len()
+    }
+
+    /// Whether `to` can be reached from `from`. Every node reaches itself.
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn has_path(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        let (from, to) = (
+            self.component_of[from.index()],
+            self.component_of[to.index()],
+        );
+        self.reachable[from * self.stride + to / 64] & (1 << (to % 64)) != 0
+    }
+
+    /// Iterates over the nodes that can be reached from `start`, itself
+    /// included, in index order.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn reachable_from(&self, start: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        let row = self.component_of[start.index()] * self.stride;
+        let row = &self.reachable[row..row + self.stride];
+        self.component_of
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &component)| row[component / 64] & (1 << (component % 64)) != 0)
+            .map(|(idx, _)| NodeIndex::new(idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn queries_agree_with_index() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+        graph.add_edge(n[2], n[3], ()).unwrap();
+        graph.add_edge(n[4], n[3], ()).unwrap();
+
+        assert!(graph.has_path(n[0], n[3]));
+        assert!(!graph.has_path(n[3], n[0]));
+        assert!(graph.has_path(n[5], n[5]));
+        assert_eq!(
+            graph.reachable_from(n[1]).collect::<Vec<_>>(),
+            vec![n[1], n[2], n[3]]
+        );
+
+        let index = graph.reachability_index();
+        assert_eq!(index.node_count(), 6);
+        for from in n.iter().copied() {
+            for to in n.iter().copied() {
+                assert_eq!(index.has_path(from, to), graph.has_path(from, to));
+            }
+        }
+        assert_eq!(
+            index.reachable_from(n[4]).collect::<Vec<_>>(),
+            vec![n[3], n[4]]
+        );
+    }
+
+    #[test]
+    fn index_spans_many_words() {
+        let graph = Graph::<u32>::path_graph(150);
+        let index = graph.reachability_index();
+        assert!(index.has_path(NodeIndex::new(3), NodeIndex::new(149)));
+        assert!(!index.has_path(NodeIndex::new(149), NodeIndex::new(3)));
+        assert_eq!(index.reachable_from(NodeIndex::new(70)).count(), 80);
+
+        let undirected = UnGraph::<u32>::from_edges([(0, 1), (2, 3)]).unwrap();
+        let index = undirected.reachability_index();
+        assert!(index.has_path(NodeIndex::new(1), NodeIndex::new(0)));
+        assert!(!index.has_path(NodeIndex::new(1), NodeIndex::new(2)));
+    }
+}
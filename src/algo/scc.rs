@@ -0,0 +1,167 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Directed, EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Finds the strongly connected components with Tarjan's algorithm.
+    ///
+    /// Components are returned in reverse topological order: no component has
+    /// an edge to one that comes after it. Nodes within a component are
+    /// sorted by index.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeIndex>> {
+        let len = self.nodes.len();
+        let mut indices: Vec<Option<usize>> = vec![None; len];
+        let mut low_links = vec![0; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..len {
+            if indices[root].is_some() {
+                continue;
+            }
+
+            indices[root] = Some(next_index);
+            low_links[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            let mut call_stack = vec![(root, self.successors(root))];
+            while let Some((node, successors)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(next) = successors.next() {
+                    match indices[next] {
+                        None => {
+                            indices[next] = Some(next_index);
+                            low_links[next] = next_index;
+                            next_index += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            call_stack.push((next, self.successors(next)));
+                        }
+                        Some(index) if on_stack[next] => {
+                            low_links[node] = low_links[node].min(index);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some((parent, _)) = call_stack.last() {
+                    low_links[*parent] = low_links[*parent].min(low_links[node]);
+                }
+
+                if Some(low_links[node]) == indices[node] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(NodeIndex::new(member));
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+}
+
+impl<T, E> Graph<T, E, Directed> {
+    /// Collapses every strongly connected component into a single node that
+    /// holds the indices of its members, with one edge wherever any member of
+    /// one component has an edge to a member of another.
+    ///
+    /// Components are in topological order, so every edge points to a later
+    /// node, and edges out of each component are sorted by target.
+    pub fn condense(&self) -> Graph<Vec<NodeIndex>> {
+        let mut components = self.strongly_connected_components();
+        components.reverse();
+
+        let mut component_of = vec![0; self.nodes.len()];
+        for (component, members) in components.iter().enumerate() {
+            for member in members.iter() {
+                component_of[member.index()] = component;
+            }
+        }
+
+        let mut targets = vec![Vec::new(); components.len()];
+        for edge in self.edges() {
+            let source = component_of[edge.source().index()];
+            let target = component_of[edge.target().index()];
+            if source != target {
+                targets[source].push(target);
+            }
+        }
+
+        let mut condensed: Graph<Vec<NodeIndex>> = components.into_iter().collect();
+        for (source, mut targets) in targets.into_iter().enumerate() {
+            targets.sort_unstable();
+            targets.dedup();
+            for target in targets {
+                condensed
+                    .add_edge(NodeIndex::new(source), NodeIndex::new(target), ())
+                    .unwrap();
+            }
+        }
+        condensed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_components() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..7).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[2], n[0], ()).unwrap();
+        graph.add_edge(n[2], n[3], ()).unwrap();
+        graph.add_edge(n[3], n[4], ()).unwrap();
+        graph.add_edge(n[4], n[5], ()).unwrap();
+        graph.add_edge(n[5], n[3], ()).unwrap();
+        graph.add_edge(n[6], n[5], ()).unwrap();
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![n[3], n[4], n[5]], vec![n[0], n[1], n[2]], vec![n[6]]]
+        );
+
+        graph.add_edge(n[1], n[4], ()).unwrap();
+        let condensed = graph.condense();
+        let members: Vec<_> = condensed.nodes().cloned().collect();
+        assert_eq!(
+            members,
+            vec![vec![n[6]], vec![n[0], n[1], n[2]], vec![n[3], n[4], n[5]]]
+        );
+        let edges: Vec<_> = condensed
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect();
+        assert_eq!(edges, vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn undirected_components() {
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[3], n[2], ()).unwrap();
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![n[0], n[2], n[3]], vec![n[1]]]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Iterates over every path from `from` to `to` that visits no node
+    /// twice, leaving out those with more than `max_len` edges if given.
+    ///
+    /// Paths are generated lazily, depth first. Paths are told apart by
+    /// their nodes, so parallel edges do not yield the same path twice. The
+    /// number of paths can grow exponentially with the size of the graph.
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn all_simple_paths(
+        &self,
+        from: NodeIndex,
+        to: NodeIndex,
+        max_len: Option<usize>,
+    ) -> SimplePaths<'_, T, E, Ty> {
+        SimplePaths::new(self, from, to, max_len)
+    }
+}
+
+pub struct SimplePaths<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    to: usize,
+    max_len: usize,
+    path: Vec<usize>,
+    /// The successors left to try from every node on the path.
+    stack: Vec<vec::IntoIter<usize>>,
+    on_path: Vec<bool>,
+    /// Whether the path of a node to itself, the node alone, is yet to be
+    /// returned.
+    to_itself: bool,
+}
+
+impl<'a, T, E, Ty: EdgeType> SimplePaths<'a, T, E, Ty> {
+    pub fn new(
+        graph: &'a Graph<T, E, Ty>,
+        from: NodeIndex,
+        to: NodeIndex,
+        max_len: Option<usize>,
+    ) -> Self {
+        let len = graph.nodes.len();
+        for (name, idx) in [("from", from), ("to", to)] {
+            if idx.index() >= len {
+                panic!(
+                    "index {} out of range: {} is {}, but len is {}",
+                    name, name, idx, len,
+                );
+            }
+        }
+
+        let mut paths = Self {
+            graph,
+            to: to.index(),
+            max_len: max_len.unwrap_or(usize::MAX),
+            path: Vec::new(),
+            stack: Vec::new(),
+            on_path: vec![false; len],
+            to_itself: from == to,
+        };
+        if from != to {
+            paths.push(from.index());
+        }
+        paths
+    }
+
+    fn push(&mut self, node: usize) {
+        let mut successors: Vec<_> = self.graph.successors(node).collect();
+        successors.sort_unstable();
+        successors.dedup();
+        self.path.push(node);
+        self.stack.push(successors.into_iter());
+        self.on_path[node] = true;
+    }
+}
+
+impl<T, E, Ty: EdgeType> Iterator for SimplePaths<'_, T, E, Ty> {
+    type Item = Vec<NodeIndex>;
+
+    fn next(&mut self) -> Option<Vec<NodeIndex>> {
+        if self.to_itself {
+            self.to_itself = false;
+            return Some(vec![NodeIndex::new(self.to)]);
+        }
+
+        while let Some(successors) = self.stack.last_mut() {
+            let Some(next) = successors.next() else {
+                self.stack.pop();
+                let node = self.path.pop().expect("every frame has a node");
+                self.on_path[node] = false;
+                continue;
+            };
+
+            // Taking the edge to `next` makes a path of `self.path.len()`
+            // edges, which needs one more to get anywhere else.
+            let len = self.path.len();
+            if next == self.to && len <= self.max_len {
+                let mut path: Vec<_> = self.path.iter().copied().map(NodeIndex::new).collect();
+                path.push(NodeIndex::new(next));
+                return Some(path);
+            }
+            if !self.on_path[next] && next != self.to && len < self.max_len {
+                self.push(next);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    #[test]
+    fn directed_paths() {
+        let mut graph =
+            Graph::<u8>::from_edges([(0, 1), (0, 2), (1, 2), (2, 3), (1, 3), (3, 0)]).unwrap();
+        graph.add_edge(n(0), n(1), ()).unwrap();
+
+        let paths: Vec<_> = graph.all_simple_paths(n(0), n(3), None).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec![n(0), n(1), n(2), n(3)],
+                vec![n(0), n(1), n(3)],
+                vec![n(0), n(2), n(3)],
+            ]
+        );
+        assert_eq!(graph.all_simple_paths(n(0), n(3), Some(2)).count(), 2);
+        assert_eq!(graph.all_simple_paths(n(0), n(3), Some(1)).count(), 0);
+        assert_eq!(
+            graph.all_simple_paths(n(2), n(2), None).collect::<Vec<_>>(),
+            vec![vec![n(2)]]
+        );
+
+        let mut paths = graph.all_simple_paths(n(3), n(2), None);
+        assert_eq!(paths.next(), Some(vec![n(3), n(0), n(1), n(2)]));
+        assert_eq!(paths.next(), Some(vec![n(3), n(0), n(2)]));
+        assert_eq!(paths.next(), None);
+        assert_eq!(paths.next(), None);
+    }
+
+    #[test]
+    fn lazy_on_complete_graph() {
+        let mut graph = UnGraph::<u8>::with_capacity(10, 0);
+        for _ in 0..10 {
+            graph.add_node(0);
+        }
+        for from in 0..10 {
+            for to in from + 1..10 {
+                graph.add_edge(n(from), n(to), ()).unwrap();
+            }
+        }
+
+        // There are close to a million paths, so taking a few must not
+        // enumerate them all.
+        let first: Vec<_> = graph.all_simple_paths(n(0), n(9), None).take(2).collect();
+        assert_eq!(first[0].len(), 10);
+        assert_eq!(graph.all_simple_paths(n(0), n(9), Some(2)).count(), 9);
+    }
+}
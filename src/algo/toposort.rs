@@ -0,0 +1,76 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{CycleError, Directed, Graph, NodeIndex};
+
+impl<T, E> Graph<T, E, Directed> {
+    pub fn topological_sort(&self) -> Result<Vec<NodeIndex>, CycleError> {
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for edge_data in self.edges.iter() {
+            for edge in edge_data.iter() {
+                in_degrees[edge.target.index()] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(NodeIndex::new(node));
+            for edge in self.edges[node].iter() {
+                let target = edge.target.index();
+                in_degrees[target] -= 1;
+                if in_degrees[target] == 0 {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            return Ok(order);
+        }
+
+        let cycle = self
+            .find_cycle()
+            .expect("nodes left unsorted must lie on a cycle");
+        Err(CycleError(cycle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CycleError, Graph};
+
+    #[test]
+    fn sorts_dag() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = ["std", "core", "app", "alloc"]
+            .into_iter()
+            .map(|name| graph.add_node(name))
+            .collect();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+
+        assert_eq!(graph.topological_sort(), Ok(vec![n[1], n[3], n[0], n[2]]));
+    }
+
+    #[test]
+    fn reports_cycle() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[2], n[3], ()).unwrap();
+        graph.add_edge(n[3], n[1], ()).unwrap();
+        graph.add_edge(n[3], n[4], ()).unwrap();
+
+        assert_eq!(
+            graph.topological_sort(),
+            Err(CycleError(vec![n[1], n[2], n[3]]))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+
+use crate::{AdjacencyMatrix, CycleError, Directed, Graph, NodeIndex};
+
+impl<T, E> Graph<T, E, Directed> {
+    /// Marks which nodes can be reached from which by a path of at least one
+    /// edge.
+    fn reachability(&self) -> AdjacencyMatrix {
+        let len = self.nodes.len();
+        let mut reachable = AdjacencyMatrix::new(len);
+        let mut discovered = vec![false; len];
+        let mut queue = VecDeque::new();
+        for source in 0..len {
+            discovered
+                .iter_mut()
+                .for_each(|discovered| *discovered = false);
+            queue.push_back(source);
+            while let Some(node) = queue.pop_front() {
+                for next in self.successors(node) {
+                    if !discovered[next] {
+                        discovered[next] = true;
+                        reachable.insert(NodeIndex::new(source), NodeIndex::new(next));
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        reachable
+    }
+}
+
+impl<T: Clone, E> Graph<T, E, Directed> {
+    /// Copies the nodes with an edge from every node to every other node it
+    /// can reach. Nodes on a cycle reach themselves, but the closure
+    /// leaves out self-loops.
+    pub fn transitive_closure(&self) -> Graph<T> {
+        let reachable = self.reachability();
+        let mut closure: Graph<T> = self.nodes.iter().cloned().collect();
+        for source in (0..self.nodes.len()).map(NodeIndex::new) {
+            for target in (0..self.nodes.len()).map(NodeIndex::new) {
+                if source != target && reachable.contains(source, target) {
+                    closure.add_edge(source, target, ()).unwrap();
+                }
+            }
+        }
+        closure
+    }
+}
+
+impl<T: Clone, E: Clone> Graph<T, E, Directed> {
+    /// Copies the graph without the edges that are implied by other paths, and
+    /// without parallel edges, keeping the first edge of each. Every node can
+    /// still reach the same nodes as before.
+    ///
+    /// Fails if the graph has a cycle, as the reduction is not unique then.
+    pub fn transitive_reduction(&self) -> Result<Self, CycleError> {
+        self.topological_sort()?;
+        let reachable = self.reachability();
+
+        let mut reduced = Self::with_capacity(self.nodes.len(), 0);
+        reduced.extend(self.nodes.iter().cloned());
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            for (position, edge) in edge_data.iter().enumerate() {
+                let first = edge_data[..position]
+                    .iter()
+                    .all(|earlier| earlier.target != edge.target);
+                let implied = edge_data.iter().any(|other| {
+                    other.target != edge.target && reachable.contains(other.target, edge.target)
+                });
+                if first && !implied {
+                    reduced
+                        .add_edge(NodeIndex::new(source), edge.target, edge.weight.clone())
+                        .unwrap();
+                }
+            }
+        }
+        Ok(reduced)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    fn edge_list<T, E>(graph: &Graph<T, E>) -> Vec<(usize, usize)> {
+        graph
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect()
+    }
+
+    #[test]
+    fn closure() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+
+        let closure = graph.transitive_closure();
+        assert_eq!(edge_list(&closure), vec![(0, 1), (0, 2), (1, 2), (2, 1)]);
+        assert!(closure.nodes().eq(graph.nodes()));
+    }
+
+    #[test]
+    fn reduction() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        for (from, to, weight) in [
+            (0, 1, 'a'),
+            (0, 2, 'b'),
+            (0, 3, 'c'),
+            (1, 3, 'd'),
+            (2, 3, 'e'),
+            (1, 3, 'f'),
+        ] {
+            graph.add_edge(n[from], n[to], weight).unwrap();
+        }
+
+        let reduced = graph.transitive_reduction().unwrap();
+        assert_eq!(edge_list(&reduced), vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(reduced.edge_weight(n[1], n[3]), Some(&'d'));
+        assert!(reduced.transitive_closure() == graph.transitive_closure());
+
+        graph.add_edge(n[3], n[0], 'g').unwrap();
+        assert!(graph.transitive_reduction().is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Measure;
+use crate::{Graph, NodeIndex, Undirected};
+
+/// The most nodes [`Graph::tsp_held_karp`] accepts, as it needs memory for
+/// `2^(n - 1) * (n - 1)` partial tours.
+pub const HELD_KARP_MAX_NODES: usize = 16;
+
+impl<T, E: Measure> Graph<T, E, Undirected> {
+    /// Builds a tour through every node from `start`, always going on to the
+    /// nearest node not yet visited, and returns it with its cost. The tour
+    /// lists every node once, and its cost includes the edge back to `start`.
+    ///
+    /// The tour can be far from the best one, so it is worth refining with
+    /// [`Graph::tsp_two_opt`]. Returns `None` if the graph is empty, whatever
+    /// `start` is, or if the greedy choices lead to a node with no edge to any
+    /// node left, which cannot happen in a complete graph. Parallel edges
+    /// count with the cheapest of them.
+    ///
+    /// Panics if `start` is out of bounds in a graph that is not empty.
+    pub fn tsp_nearest_neighbor(&self, start: NodeIndex) -> Option<(E, Vec<NodeIndex>)> {
+        let len = self.nodes.len();
+        if len == 0 {
+            return None;
+        }
+        if start.index() >= len {
+            panic!(
+                "index start out of range: start is {}, but len is {}",
+                start, len
+            );
+        }
+
+        let distances = self.distance_matrix();
+        let mut visited = vec![false; len];
+        let mut tour = vec![start.index()];
+        visited[start.index()] = true;
+        while tour.len() < len {
+            let last = *tour.last().unwrap();
+            let (next, _) = (0..len)
+                .filter(|&node| !visited[node])
+                .filter_map(|node| Some((node, distances[last][node]?)))
+                .reduce(|a, b| if b.1 < a.1 { b } else { a })?;
+            visited[next] = true;
+            tour.push(next);
+        }
+
+        Some((tour_cost(&distances, &tour)?, to_indices(tour)))
+    }
+
+    /// Improves `tour` by undoing crossings with the 2-opt heuristic: as long
+    /// as swapping two of its edges for two others makes it cheaper, the
+    /// stretch of the tour between them is reversed. Returns the improved
+    /// tour, which starts at the same node, with its cost, or `None` if the
+    /// tour given uses a missing edge.
+    ///
+    /// Panics if `tour` does not list every node exactly once.
+    pub fn tsp_two_opt(&self, tour: &[NodeIndex]) -> Option<(E, Vec<NodeIndex>)> {
+        let len = self.nodes.len();
+        let mut seen = vec![false; len];
+        for node in tour.iter() {
+            if node.index() >= len || seen[node.index()] {
+                panic!("tour must visit every node exactly once");
+            }
+            seen[node.index()] = true;
+        }
+        if tour.len() != len {
+            panic!("tour must visit every node exactly once");
+        }
+
+        let distances = self.distance_matrix();
+        let mut tour: Vec<_> = tour.iter().map(|node| node.index()).collect();
+        tour_cost(&distances, &tour)?;
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..len.saturating_sub(2) {
+                for j in i + 2..len {
+                    let (a, b) = (tour[i], tour[i + 1]);
+                    let (c, d) = (tour[j], tour[(j + 1) % len]);
+                    if d == a {
+                        continue;
+                    }
+
+                    // The edges of the tour are known to exist.
+                    let current = distances[a][b].unwrap() + distances[c][d].unwrap();
+                    let (Some(ac), Some(bd)) = (distances[a][c], distances[b][d]) else {
+                        continue;
+                    };
+                    if ac + bd < current {
+                        tour[i + 1..=j].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+
+        Some((tour_cost(&distances, &tour)?, to_indices(tour)))
+    }
+
+    /// Finds the cheapest tour through every node with the Held-Karp
+    /// algorithm, in `O(2^n * n²)` time, and returns it with its cost. The
+    /// tour starts at node 0. Returns `None` if the graph is empty or has no
+    /// tour at all.
+    ///
+    /// Panics if the graph has more than [`HELD_KARP_MAX_NODES`] nodes.
+    pub fn tsp_held_karp(&self) -> Option<(E, Vec<NodeIndex>)> {
+        let len = self.nodes.len();
+        if len > HELD_KARP_MAX_NODES {
+            panic!(
+                "too many nodes for an exact tour: len is {}, but at most {} are allowed",
+                len, HELD_KARP_MAX_NODES
+            );
+        }
+        if len <= 1 {
+            return (len == 1).then(|| (E::default(), vec![NodeIndex::new(0)]));
+        }
+
+        // The cheapest path from node 0 through the nodes in `mask` that ends
+        // at node `last + 1`, with node `i + 1` as bit `i`, and the node
+        // before that last one.
+        let distances = self.distance_matrix();
+        let rest = len - 1;
+        let slot = |mask: usize, last: usize| mask * rest + last;
+        let mut costs: Vec<Option<E>> = vec![None; (1 << rest) * rest];
+        let mut previous = vec![0; (1 << rest) * rest];
+        for last in 0..rest {
+            costs[slot(1 << last, last)] = distances[0][last + 1];
+        }
+        for mask in 1..1usize << rest {
+            for last in (0..rest).filter(|&last| mask & (1 << last) != 0) {
+                let Some(cost) = costs[slot(mask, last)] else {
+                    continue;
+                };
+                for next in (0..rest).filter(|&next| mask & (1 << next) == 0) {
+                    let Some(weight) = distances[last + 1][next + 1] else {
+                        continue;
+                    };
+                    let target = slot(mask | (1 << next), next);
+                    if costs[target].is_none_or(|current| cost + weight < current) {
+                        costs[target] = Some(cost + weight);
+                        previous[target] = last;
+                    }
+                }
+            }
+        }
+
+        let full = (1 << rest) - 1;
+        let (cost, mut last) = (0..rest)
+            .filter_map(|last| Some((costs[slot(full, last)]? + distances[last + 1][0]?, last)))
+            .reduce(|a, b| if b.0 < a.0 { b } else { a })?;
+        let mut tour = Vec::with_capacity(len);
+        let mut mask = full;
+        while mask != 0 {
+            tour.push(last + 1);
+            let before = previous[slot(mask, last)];
+            mask &= !(1 << last);
+            last = before;
+        }
+        tour.push(0);
+        tour.reverse();
+        Some((cost, to_indices(tour)))
+    }
+
+    /// The cheapest edge between every pair of distinct nodes.
+    fn distance_matrix(&self) -> Vec<Vec<Option<E>>> {
+        let len = self.nodes.len();
+        let mut distances = vec![vec![None; len]; len];
+        for (source, row) in distances.iter_mut().enumerate() {
+            for (target, &weight) in self.weighted_successors(source) {
+                if target != source && row[target].is_none_or(|current| weight < current) {
+                    row[target] = Some(weight);
+                }
+            }
+        }
+        distances
+    }
+}
+
+/// The cost of following `tour` and returning to its start, if every edge
+/// on the way exists.
+fn tour_cost<E: Measure>(distances: &[Vec<Option<E>>], tour: &[usize]) -> Option<E> {
+    if tour.len() < 2 {
+        return Some(E::default());
+    }
+    tour.iter()
+        .zip(tour.iter().cycle().skip(1))
+        .try_fold(E::default(), |cost, (&from, &to)| {
+            Some(cost + distances[from][to]?)
+        })
+}
+
+fn to_indices(tour: Vec<usize>) -> Vec<NodeIndex> {
+    tour.into_iter().map(NodeIndex::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NodeIndex, UnGraph};
+
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
+    /// A complete graph of cities on a line, with the distances between them.
+    fn line(positions: &[i32]) -> UnGraph<i32, i32> {
+        let mut graph: UnGraph<i32, i32> = positions.iter().copied().collect();
+        for from in 0..positions.len() {
+            for to in from + 1..positions.len() {
+                let distance = (positions[from] - positions[to]).abs();
+                graph.add_edge(n(from), n(to), distance).unwrap();
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn heuristics_and_exact() {
+        let graph = line(&[0, 1, -2, 5]);
+
+        let (cost, tour) = graph.tsp_nearest_neighbor(n(0)).unwrap();
+        assert_eq!((cost, tour.clone()), (16, vec![n(0), n(1), n(2), n(3)]));
+        let (cost, tour) = graph.tsp_two_opt(&tour).unwrap();
+        assert_eq!(cost, 14);
+        assert_eq!(tour[0], n(0));
+
+        assert_eq!(graph.tsp_held_karp().unwrap().0, 14);
+        let larger = line(&[3, -7, 12, 0, 8, -1, 5, 20, -4]);
+        assert_eq!(larger.tsp_held_karp().unwrap().0, 54);
+        let (cost, tour) = larger.tsp_held_karp().unwrap();
+        assert_eq!(larger.tsp_two_opt(&tour).unwrap().0, cost);
+    }
+
+    #[test]
+    fn small_and_incomplete_graphs() {
+        assert_eq!(UnGraph::<i32, i32>::default().tsp_held_karp(), None);
+        assert_eq!(
+            UnGraph::<i32, i32>::default().tsp_nearest_neighbor(n(3)),
+            None
+        );
+        assert_eq!(line(&[4]).tsp_held_karp(), Some((0, vec![n(0)])));
+        assert_eq!(line(&[4]).tsp_nearest_neighbor(n(0)), Some((0, vec![n(0)])));
+        assert_eq!(line(&[0, 3]).tsp_held_karp(), Some((6, vec![n(0), n(1)])));
+
+        // A star has no tour.
+        let mut star = UnGraph::<(), i32>::from_edges([(0, 1), (0, 2), (0, 3)]).unwrap();
+        assert_eq!(star.tsp_nearest_neighbor(n(0)), None);
+        assert_eq!(star.tsp_held_karp(), None);
+        star.add_edge(n(1), n(2), 0).unwrap();
+        star.add_edge(n(2), n(3), 0).unwrap();
+        let (cost, tour) = star.tsp_held_karp().unwrap();
+        assert_eq!((cost, tour.len(), tour[0]), (0, 4, n(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 16 are allowed")]
+    fn held_karp_size_limit() {
+        line(&[0; 17]).tsp_held_karp();
+    }
+}
@@ -0,0 +1,139 @@
+//! Random graphs for property tests, through quickcheck and proptest. Both
+//! leave out self-loops, and shrink by removing nodes and edges.
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use quickcheck::{Arbitrary, Gen};
+
+    use crate::{EdgeType, Graph, NodeIndex};
+
+    impl<T, E, Ty> Arbitrary for Graph<T, E, Ty>
+    where
+        T: Arbitrary,
+        E: Arbitrary,
+        Ty: EdgeType + Clone + Send + 'static,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let nodes = Vec::<T>::arbitrary(g);
+            let edges = Vec::<(usize, usize, E)>::arbitrary(g);
+            let mut graph: Self = nodes.into_iter().collect();
+            if graph.nodes.is_empty() {
+                return graph;
+            }
+
+            for (from, to, weight) in edges {
+                let from = NodeIndex::new(from % graph.nodes.len());
+                let to = NodeIndex::new(to % graph.nodes.len());
+                if from != to {
+                    graph.add_edge(from, to, weight).unwrap();
+                }
+            }
+            graph
+        }
+
+        /// Tries every graph with one node removed, then every graph with one
+        /// edge removed.
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let graph = self.clone();
+            let without_nodes = (0..graph.nodes.len()).map({
+                let graph = graph.clone();
+                move |node| {
+                    let mut smaller = graph.clone();
+                    smaller.remove_node(NodeIndex::new(node));
+                    smaller
+                }
+            });
+            let without_edges = (0..graph.edge_count).map(move |edge| {
+                let mut smaller = graph.clone();
+                smaller.retain_edges(|edge_ref| edge_ref.index().index() != edge);
+                smaller
+            });
+            Box::new(without_nodes.chain(without_edges))
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::arbitrary::{any, Arbitrary};
+    use proptest::collection::vec;
+    use proptest::sample::Index;
+    use proptest::strategy::{BoxedStrategy, Strategy};
+
+    use crate::{EdgeType, Graph, NodeIndex};
+
+    /// Node and edge lists shrink towards shorter lists, which removes nodes
+    /// and edges from the generated graph.
+    impl<T, E, Ty> Arbitrary for Graph<T, E, Ty>
+    where
+        T: Arbitrary + 'static,
+        E: Arbitrary + 'static,
+        Ty: EdgeType + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            (
+                vec(any::<T>(), 0..16),
+                vec((any::<Index>(), any::<Index>(), any::<E>()), 0..32),
+            )
+                .prop_map(|(nodes, edges)| {
+                    let mut graph: Self = nodes.into_iter().collect();
+                    if graph.nodes.is_empty() {
+                        return graph;
+                    }
+
+                    for (from, to, weight) in edges {
+                        let from = NodeIndex::new(from.index(graph.nodes.len()));
+                        let to = NodeIndex::new(to.index(graph.nodes.len()));
+                        if from != to {
+                            graph.add_edge(from, to, weight).unwrap();
+                        }
+                    }
+                    graph
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, EdgeType, Graph};
+
+    fn is_consistent<T, E, Ty: EdgeType>(graph: &Graph<T, E, Ty>) -> bool {
+        graph.edges().count() == graph.edge_count()
+            && graph.edges().all(|edge| {
+                edge.source() != edge.target() && edge.target().index() < graph.node_count()
+            })
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck_graphs() {
+        use quickcheck::{Arbitrary, QuickCheck};
+
+        use crate::UnGraph;
+
+        fn consistent(graph: UnGraph<u8, i8>) -> bool {
+            is_consistent(&graph)
+        }
+        QuickCheck::new().quickcheck(consistent as fn(UnGraph<u8, i8>) -> bool);
+
+        let graph = DiGraph::<u8>::from_edges([(0, 1), (1, 2)]).unwrap();
+        let shrunk: Vec<_> = graph.shrink().collect();
+        assert_eq!(shrunk.len(), 5);
+        assert!(shrunk.iter().all(is_consistent));
+        assert_eq!(shrunk[0].edge_count(), 1);
+        assert_eq!(shrunk[4].edge_count(), 1);
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_graphs(graph in proptest::arbitrary::any::<DiGraph<u8, u8>>()) {
+            proptest::prop_assert!(is_consistent(&graph));
+        }
+    }
+}
@@ -0,0 +1,375 @@
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+use core::ops::ControlFlow;
+
+use crate::visit::{depth_first_search, DfsEvent};
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// The space between two boxes in a layer.
+const GAP: usize = 2;
+
+type Pairs = Vec<(usize, usize)>;
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Draws the graph as text, with every node in a box and the nodes laid
+    /// out in layers from top to bottom, meant for small graphs in a
+    /// terminal.
+    ///
+    /// Directed graphs are layered so that edges point down, apart from the
+    /// ones that close a cycle, and undirected graphs by distance from the
+    /// first node of every component. Edges between neighboring layers are
+    /// drawn as lines, ending in `v` for directed graphs. Every other edge,
+    /// such as one skipping a layer, pointing up or a self-loop, is listed
+    /// below the drawing as in the `Display` output.
+    ///
+    /// Labels are cut short, ending in `~`, so that every layer fits in
+    /// `width` columns, although a layer with too many nodes can still be
+    /// wider.
+    pub fn render_ascii(&self, width: usize) -> String {
+        let layers = self.ascii_layers();
+        let (drawn, others) = self.split_ascii_edges(&layers);
+        let rows = order_rows(&layers, &drawn);
+
+        let labels: Vec<_> = self.nodes.iter().map(ToString::to_string).collect();
+        let mut boxes = vec![(0, String::new()); self.nodes.len()];
+        for row in rows.iter() {
+            let max_label = (width.saturating_sub(GAP * (row.len() - 1)) / row.len())
+                .saturating_sub(4)
+                .max(2);
+            let mut x = 0;
+            for &node in row.iter() {
+                let label = shorten(&labels[node], max_label);
+                let next = x + label.chars().count() + 4 + GAP;
+                boxes[node] = (x, label);
+                x = next;
+            }
+        }
+        let center = |node: usize| boxes[node].0 + (boxes[node].1.chars().count() + 4) / 2;
+        // Lines enter a box straight from above wherever it is wide enough.
+        let entry = |from: usize, to: usize| {
+            let (x, label) = &boxes[to];
+            center(from).clamp(x + 1, x + label.chars().count() + 2)
+        };
+
+        let mut canvas = Canvas::default();
+        let mut y = 0;
+        for (layer, row) in rows.iter().enumerate() {
+            for &node in row.iter() {
+                let (x, label) = &boxes[node];
+                let border = ["+", &"-".repeat(label.chars().count() + 2), "+"].concat();
+                canvas.text(y, *x, &border);
+                canvas.text(y + 1, *x, &["| ", label, " |"].concat());
+                canvas.text(y + 2, *x, &border);
+            }
+            y += 3;
+            if layer + 1 == rows.len() {
+                break;
+            }
+
+            let sources: Vec<_> = row
+                .iter()
+                .filter_map(|&node| {
+                    let mut targets: Vec<_> = drawn
+                        .iter()
+                        .filter(|&&(top, _)| top == node)
+                        .map(|&(_, bottom)| entry(node, bottom))
+                        .collect();
+                    targets.sort_unstable();
+                    targets.dedup();
+                    (!targets.is_empty()).then_some((center(node), targets))
+                })
+                .collect();
+            if sources.is_empty() {
+                y += 1;
+                continue;
+            }
+
+            // Every source whose lines bend gets a row of its own below the
+            // layer, where a line runs across to the columns its targets are
+            // entered at. The others go straight down.
+            let mut tracks = Vec::with_capacity(sources.len());
+            let mut bent = 0;
+            for (from, targets) in sources.iter() {
+                if targets.iter().any(|target| target != from) {
+                    bent += 1;
+                    tracks.push(y + bent);
+                } else {
+                    tracks.push(y);
+                }
+            }
+            let arrow_row = y + 1 + bent;
+            let head = if Ty::is_directed() { 'v' } else { '|' };
+            for ((from, targets), &track) in sources.iter().zip(tracks.iter()) {
+                let (left, right) = (targets[0].min(*from), targets[targets.len() - 1].max(*from));
+                for x in left..=right {
+                    canvas.put(track, x, '-');
+                }
+            }
+            for ((from, targets), &track) in sources.iter().zip(tracks.iter()) {
+                for row in y..=track {
+                    canvas.put(row, *from, '|');
+                }
+                for &target in targets.iter() {
+                    for row in track..arrow_row {
+                        canvas.put(row, target, '|');
+                    }
+                    canvas.put(arrow_row, target, head);
+                }
+            }
+            for ((from, targets), &track) in sources.iter().zip(tracks.iter()) {
+                if track > y {
+                    for &x in targets.iter().chain([from]) {
+                        canvas.put(track, x, '+');
+                    }
+                }
+            }
+            y = arrow_row + 1;
+        }
+
+        let mut drawing = canvas.finish();
+        if !others.is_empty() {
+            let arrow = if Ty::is_directed() { "->" } else { "--" };
+            drawing.push('\n');
+            for (from, to) in others {
+                writeln!(drawing, "{} {} {}", labels[from], arrow, labels[to]).unwrap();
+            }
+        }
+        drawing
+    }
+
+    /// The layer of every node, counted from the top.
+    fn ascii_layers(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let mut layers = vec![0; n];
+        if Ty::is_directed() {
+            // Longest path layering, without the back edges of a depth-first
+            // search, whose reversed finishing order is then topological.
+            let mut back_edges = BTreeSet::new();
+            let mut finished = Vec::with_capacity(n);
+            let _ = depth_first_search(self, (0..n).map(NodeIndex::new), |event| {
+                match event {
+                    DfsEvent::BackEdge(from, to) => {
+                        back_edges.insert((from.index(), to.index()));
+                    }
+                    DfsEvent::Finish(node) => finished.push(node.index()),
+                    _ => {}
+                }
+                ControlFlow::<()>::Continue(())
+            });
+            for &node in finished.iter().rev() {
+                for target in self.successors(node) {
+                    if !back_edges.contains(&(node, target)) {
+                        layers[target] = layers[target].max(layers[node] + 1);
+                    }
+                }
+            }
+        } else {
+            let mut visited = vec![false; n];
+            let mut queue = VecDeque::new();
+            for start in 0..n {
+                if core::mem::replace(&mut visited[start], true) {
+                    continue;
+                }
+                queue.push_back(start);
+                while let Some(node) = queue.pop_front() {
+                    for next in self.successors(node) {
+                        if !core::mem::replace(&mut visited[next], true) {
+                            layers[next] = layers[node] + 1;
+                            queue.push_back(next);
+                        }
+                    }
+                }
+            }
+        }
+        layers
+    }
+
+    /// Splits the edges into the ones drawn, as `(top, bottom)` pairs, and
+    /// the ones listed below the drawing, in [`crate::EdgeIndex`] order.
+    fn split_ascii_edges(&self, layers: &[usize]) -> (Pairs, Pairs) {
+        let mut drawn = Vec::new();
+        let mut others = Vec::new();
+        for edge in self.edges() {
+            let (from, to) = (edge.source().index(), edge.target().index());
+            if layers[to] == layers[from] + 1 {
+                drawn.push((from, to));
+            } else if !Ty::is_directed() && layers[from] == layers[to] + 1 {
+                drawn.push((to, from));
+            } else {
+                others.push((from, to));
+            }
+        }
+        (drawn, others)
+    }
+}
+
+/// Groups the nodes by layer, ordering every layer but the first by the
+/// average position of the nodes above it they are drawn connected to.
+fn order_rows(layers: &[usize], drawn: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let count = layers.iter().max().map_or(0, |&max| max + 1);
+    let mut rows = vec![Vec::new(); count];
+    for (node, &layer) in layers.iter().enumerate() {
+        rows[layer].push(node);
+    }
+
+    let mut positions = vec![0; layers.len()];
+    for (layer, row) in rows.iter_mut().enumerate() {
+        if layer > 0 {
+            let keys: Vec<_> = row
+                .iter()
+                .map(|&node| {
+                    let above: Vec<_> = drawn
+                        .iter()
+                        .filter(|&&(_, bottom)| bottom == node)
+                        .map(|&(top, _)| positions[top] as f64)
+                        .collect();
+                    if above.is_empty() {
+                        f64::INFINITY
+                    } else {
+                        above.iter().sum::<f64>() / above.len() as f64
+                    }
+                })
+                .collect();
+            let mut order: Vec<_> = (0..row.len()).collect();
+            order.sort_by(|&a, &b| keys[a].partial_cmp(&keys[b]).unwrap());
+            *row = order.into_iter().map(|i| row[i]).collect();
+        }
+        for (position, &node) in row.iter().enumerate() {
+            positions[node] = position;
+        }
+    }
+    rows
+}
+
+fn shorten(label: &str, max: usize) -> String {
+    if label.chars().count() <= max {
+        return label.to_string();
+    }
+    let mut short: String = label.chars().take(max - 1).collect();
+    short.push('~');
+    short
+}
+
+#[derive(Default)]
+struct Canvas {
+    rows: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn put(&mut self, row: usize, col: usize, ch: char) {
+        if self.rows.len() <= row {
+            self.rows.resize(row + 1, Vec::new());
+        }
+        let line = &mut self.rows[row];
+        if line.len() <= col {
+            line.resize(col + 1, ' ');
+        }
+        line[col] = ch;
+    }
+
+    fn text(&mut self, row: usize, col: usize, text: &str) {
+        for (offset, ch) in text.chars().enumerate() {
+            self.put(row, col + offset, ch);
+        }
+    }
+
+    fn finish(self) -> String {
+        let mut text = String::new();
+        for line in self.rows {
+            let line: String = line.into_iter().collect();
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, Graph, UnGraph};
+
+    fn diamond() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        let start = graph.add_node("start");
+        let left = graph.add_node("left");
+        let right = graph.add_node("right");
+        let end = graph.add_node("end");
+        for (from, to) in [
+            (start, left),
+            (start, right),
+            (left, end),
+            (right, end),
+            (start, end),
+            (end, start),
+        ] {
+            graph.add_edge(from, to, ()).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn layers_and_leftover_edges() {
+        assert_eq!(
+            diamond().render_ascii(40),
+            "\
++-------+
+| start |
++-------+
+    |
+    +------+
+    v      v
++------+  +-------+
+| left |  | right |
++------+  +-------+
+    |         |
+    |+--------+
+    vv
++-----+
+| end |
++-----+
+
+start -> end
+end -> start
+"
+        );
+        assert_eq!(DiGraph::<u8>::default().render_ascii(40), "");
+    }
+
+    #[test]
+    fn narrow_and_undirected() {
+        let narrow = diamond().render_ascii(8);
+        assert!(narrow.contains("| sta~ |"));
+        assert!(narrow.contains("| l~ |  | r~ |"));
+
+        let mut graph = UnGraph::new_undirected();
+        let (a, b, c) = (
+            graph.add_node('a'),
+            graph.add_node('b'),
+            graph.add_node('c'),
+        );
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            graph.add_edge(from, to, ()).unwrap();
+        }
+        // The edge stored as `c -- a` is drawn from `a` down to `c`, and the
+        // one within the second layer is listed below.
+        assert_eq!(
+            graph.render_ascii(20),
+            "\
++---+
+| a |
++---+
+  |
+  +-----+
+  |     |
++---+  +---+
+| b |  | c |
++---+  +---+
+
+b -- c
+"
+        );
+    }
+}
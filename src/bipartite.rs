@@ -0,0 +1,320 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Graph, GraphError, NodeIndex, UnGraph};
+
+/// The position of a node on the left side of a [`BipartiteGraph`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct LeftIndex(usize);
+
+/// The position of a node on the right side of a [`BipartiteGraph`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct RightIndex(usize);
+
+impl LeftIndex {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl RightIndex {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// An undirected graph whose nodes are split into a left and a right side,
+/// with payloads of different types, and whose edges always connect a node
+/// on the left to one on the right.
+///
+/// Edges are stored with their left node, and the right nodes keep the
+/// left nodes they are connected to, so both sides can list their
+/// neighbors.
+#[derive(Debug, Clone)]
+pub struct BipartiteGraph<L, R, E = ()> {
+    left: Vec<L>,
+    right: Vec<R>,
+    edges: Vec<Vec<(RightIndex, E)>>,
+    /// The left node of every edge of every right node, in the order the
+    /// edges were added.
+    incoming: Vec<Vec<LeftIndex>>,
+}
+
+impl<L, R, E> Default for BipartiteGraph<L, R, E> {
+    fn default() -> Self {
+        Self {
+            left: Vec::new(),
+            right: Vec::new(),
+            edges: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+}
+
+impl<L, R, E> BipartiteGraph<L, R, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_left(&mut self, weight: L) -> LeftIndex {
+        self.left.push(weight);
+        self.edges.push(Vec::new());
+        LeftIndex(self.left.len() - 1)
+    }
+
+    pub fn add_right(&mut self, weight: R) -> RightIndex {
+        self.right.push(weight);
+        self.incoming.push(Vec::new());
+        RightIndex(self.right.len() - 1)
+    }
+
+    pub fn left(&self, idx: LeftIndex) -> Option<&L> {
+        self.left.get(idx.0)
+    }
+
+    pub fn left_mut(&mut self, idx: LeftIndex) -> Option<&mut L> {
+        self.left.get_mut(idx.0)
+    }
+
+    pub fn right(&self, idx: RightIndex) -> Option<&R> {
+        self.right.get(idx.0)
+    }
+
+    pub fn right_mut(&mut self, idx: RightIndex) -> Option<&mut R> {
+        self.right.get_mut(idx.0)
+    }
+
+    pub fn left_count(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn right_count(&self) -> usize {
+        self.right.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// Adds an edge between `left` and `right`. Out-of-bounds indices are
+    /// reported as [`GraphError::NodeOutOfBounds`] with their position on
+    /// their own side.
+    pub fn add_edge(
+        &mut self,
+        left: LeftIndex,
+        right: RightIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        for (index, len) in [(left.0, self.left.len()), (right.0, self.right.len())] {
+            if index >= len {
+                return Err(GraphError::NodeOutOfBounds {
+                    index: NodeIndex::new(index),
+                    len,
+                });
+            }
+        }
+
+        self.edges[left.0].push((right, weight));
+        self.incoming[right.0].push(left);
+        Ok(())
+    }
+
+    pub fn edge_weight(&self, left: LeftIndex, right: RightIndex) -> Option<&E> {
+        self.edges
+            .get(left.0)?
+            .iter()
+            .find(|(target, _)| *target == right)
+            .map(|(_, weight)| weight)
+    }
+
+    /// Iterates over every edge as `(left, right, weight)`, grouped by left
+    /// node.
+    pub fn edges(&self) -> impl Iterator<Item = (LeftIndex, RightIndex, &E)> {
+        self.edges.iter().enumerate().flat_map(|(left, edge_data)| {
+            edge_data
+                .iter()
+                .map(move |(right, weight)| (LeftIndex(left), *right, weight))
+        })
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors_of_left(&self, idx: LeftIndex) -> impl Iterator<Item = RightIndex> + '_ {
+        self.edges[idx.0].iter().map(|(right, _)| *right)
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors_of_right(&self, idx: RightIndex) -> impl Iterator<Item = LeftIndex> + '_ {
+        self.incoming[idx.0].iter().copied()
+    }
+
+    /// Puts both sides into a single undirected graph, the left nodes first
+    /// and then the right nodes, so right node `i` ends up at index
+    /// `left_count() + i`.
+    pub fn into_graph<T>(
+        self,
+        mut left: impl FnMut(L) -> T,
+        mut right: impl FnMut(R) -> T,
+    ) -> UnGraph<T, E> {
+        let offset = self.left.len();
+        let mut graph = Graph::with_capacity(offset + self.right.len(), self.edge_count());
+        for weight in self.left {
+            graph.add_node(left(weight));
+        }
+        for weight in self.right {
+            graph.add_node(right(weight));
+        }
+        for (source, edge_data) in self.edges.into_iter().enumerate() {
+            for (target, weight) in edge_data {
+                graph
+                    .add_edge(
+                        NodeIndex::new(source),
+                        NodeIndex::new(offset + target.0),
+                        weight,
+                    )
+                    .expect("both sides are in the graph");
+            }
+        }
+        graph
+    }
+
+    fn rights_by_left(&self) -> Vec<Vec<usize>> {
+        self.edges
+            .iter()
+            .map(|edge_data| edge_data.iter().map(|(right, _)| right.0).collect())
+            .collect()
+    }
+
+    fn lefts_by_right(&self) -> Vec<Vec<usize>> {
+        self.incoming
+            .iter()
+            .map(|lefts| lefts.iter().map(|left| left.0).collect())
+            .collect()
+    }
+}
+
+impl<L: Clone, R, E> BipartiteGraph<L, R, E> {
+    /// Builds the graph of the left nodes, with an edge between every two of
+    /// them that share a right neighbor, weighted by how many they share.
+    /// Node `i` of the projection is left node `i`.
+    pub fn project_left(&self) -> UnGraph<L, usize> {
+        project(&self.left, &self.rights_by_left(), &self.lefts_by_right())
+    }
+}
+
+impl<L, R: Clone, E> BipartiteGraph<L, R, E> {
+    /// Like [`BipartiteGraph::project_left`], for the right nodes.
+    pub fn project_right(&self) -> UnGraph<R, usize> {
+        project(&self.right, &self.lefts_by_right(), &self.rights_by_left())
+    }
+}
+
+/// Connects every two `nodes` that share a node on the opposite side,
+/// given the neighbors of each side.
+fn project<T: Clone>(
+    nodes: &[T],
+    neighbors: &[Vec<usize>],
+    opposite: &[Vec<usize>],
+) -> UnGraph<T, usize> {
+    let mut graph = Graph::with_capacity(nodes.len(), 0);
+    for weight in nodes.iter() {
+        graph.add_node(weight.clone());
+    }
+
+    let mut shared = vec![0; nodes.len()];
+    let mut touched = Vec::new();
+    for (node, across) in neighbors.iter().enumerate() {
+        let mut across = across.clone();
+        across.sort_unstable();
+        across.dedup();
+        for other_side in across {
+            let mut others = opposite[other_side].clone();
+            others.sort_unstable();
+            others.dedup();
+            for other in others.into_iter().filter(|&other| other > node) {
+                if shared[other] == 0 {
+                    touched.push(other);
+                }
+                shared[other] += 1;
+            }
+        }
+
+        touched.sort_unstable();
+        for other in touched.drain(..) {
+            graph
+                .add_edge(NodeIndex::new(node), NodeIndex::new(other), shared[other])
+                .expect("both nodes are in the projection");
+            shared[other] = 0;
+        }
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BipartiteGraph, LeftIndex};
+    use crate::{GraphError, NodeIndex};
+
+    #[test]
+    fn authors_and_papers() {
+        let mut graph = BipartiteGraph::<&str, u32>::new();
+        let ada = graph.add_left("ada");
+        let bob = graph.add_left("bob");
+        let cy = graph.add_left("cy");
+        let first = graph.add_right(2001);
+        let second = graph.add_right(2005);
+        for (author, paper) in [
+            (ada, first),
+            (bob, first),
+            (ada, second),
+            (bob, second),
+            (cy, second),
+        ] {
+            graph.add_edge(author, paper, ()).unwrap();
+        }
+
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(
+            graph.neighbors_of_right(second).collect::<Vec<_>>(),
+            vec![ada, bob, cy]
+        );
+        assert_eq!(
+            graph.add_edge(LeftIndex::new(3), first, ()),
+            Err(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(3),
+                len: 3
+            })
+        );
+
+        let authors = graph.project_left();
+        assert_eq!(authors.to_string(), "ada -- bob\nada -- cy\nbob -- cy\n");
+        assert_eq!(
+            authors.edge_weight(NodeIndex::new(0), NodeIndex::new(1)),
+            Some(&2)
+        );
+        assert_eq!(
+            authors.edge_weight(NodeIndex::new(1), NodeIndex::new(2)),
+            Some(&1)
+        );
+        let papers = graph.project_right();
+        assert_eq!(
+            papers.edge_weight(NodeIndex::new(0), NodeIndex::new(1)),
+            Some(&2)
+        );
+
+        let whole = graph.into_graph(|author| author.to_string(), |paper| paper.to_string());
+        assert_eq!(whole.node_count(), 5);
+        assert_eq!(
+            whole.neighbors(NodeIndex::new(3)).collect::<Vec<_>>(),
+            vec![NodeIndex::new(0), NodeIndex::new(1)]
+        );
+        assert!(whole.is_bipartite());
+    }
+}
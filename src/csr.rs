@@ -0,0 +1,215 @@
+//! A compressed sparse row representation for graphs that are done changing.
+//!
+//! All adjacency lists are stored back to back in one flat vector, with a
+//! second vector of offsets marking where the list of every node begins, so
+//! traversals read memory in order instead of following a pointer per node.
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{Directed, EdgeIndex, EdgeType, Graph, NodeIndex};
+
+pub struct CsrGraph<T, E = (), Ty = Directed> {
+    nodes: Vec<T>,
+    /// The list of node `i` is `targets[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    targets: Vec<NodeIndex>,
+    /// The edge each entry of `targets` belongs to, to look up its weight.
+    /// Undirected edges have an entry in the list of both of their ends.
+    edge_indices: Vec<EdgeIndex>,
+    weights: Vec<E>,
+    edge_type: PhantomData<Ty>,
+}
+
+impl<T, E, Ty: EdgeType> From<Graph<T, E, Ty>> for CsrGraph<T, E, Ty> {
+    fn from(graph: Graph<T, E, Ty>) -> Self {
+        let len = graph.nodes.len();
+        let mut offsets = vec![0; len + 1];
+        for node in 0..len {
+            let mut row = graph.edges[node].len();
+            if !Ty::is_directed() {
+                row += graph.in_degrees[node];
+            }
+            offsets[node + 1] = offsets[node] + row;
+        }
+
+        let mut next = offsets[..len].to_vec();
+        let mut targets = vec![NodeIndex::default(); offsets[len]];
+        let mut edge_indices = vec![EdgeIndex::default(); offsets[len]];
+        let mut weights = Vec::with_capacity(graph.edge_count);
+        let mut place = |from: usize, to: NodeIndex, index: EdgeIndex| {
+            targets[next[from]] = to;
+            edge_indices[next[from]] = index;
+            next[from] += 1;
+        };
+        for (source, edge_data) in graph.edges.into_iter().enumerate() {
+            for edge in edge_data {
+                let index = EdgeIndex::new(weights.len());
+                place(source, edge.target, index);
+                if !Ty::is_directed() {
+                    place(edge.target.index(), NodeIndex::new(source), index);
+                }
+                weights.push(edge.weight);
+            }
+        }
+
+        Self {
+            nodes: graph.nodes,
+            offsets,
+            targets,
+            edge_indices,
+            weights,
+            edge_type: PhantomData,
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn into_csr(self) -> CsrGraph<T, E, Ty> {
+        CsrGraph::from(self)
+    }
+}
+
+impl<T, E, Ty: EdgeType> CsrGraph<T, E, Ty> {
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn get_node(&self, idx: NodeIndex) -> Option<&T> {
+        self.nodes.get(idx.index())
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter()
+    }
+
+    /// Edges keep the [`EdgeIndex`] they had in the [`Graph`].
+    pub fn edge_weight(&self, idx: EdgeIndex) -> Option<&E> {
+        self.weights.get(idx.index())
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors(&self, idx: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.targets[self.row(idx)].iter().copied()
+    }
+
+    /// Panics if `idx` is out of bounds.
+    pub fn edges_from(&self, idx: NodeIndex) -> impl Iterator<Item = (NodeIndex, &E)> {
+        let row = self.row(idx);
+        self.targets[row.clone()]
+            .iter()
+            .zip(&self.edge_indices[row])
+            .map(|(&target, index)| (target, &self.weights[index.index()]))
+    }
+
+    pub fn bfs(&self, start: NodeIndex) -> Bfs<'_, T, E, Ty> {
+        Bfs::new(self, start)
+    }
+
+    fn row(&self, idx: NodeIndex) -> core::ops::Range<usize> {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.nodes.len(),
+            );
+        }
+
+        self.offsets[idx.index()]..self.offsets[idx.index() + 1]
+    }
+}
+
+pub struct Bfs<'a, T, E, Ty> {
+    graph: &'a CsrGraph<T, E, Ty>,
+    queue: VecDeque<NodeIndex>,
+    discovered: Vec<bool>,
+}
+
+impl<'a, T, E, Ty: EdgeType> Bfs<'a, T, E, Ty> {
+    pub fn new(graph: &'a CsrGraph<T, E, Ty>, start: NodeIndex) -> Self {
+        let len = graph.nodes.len();
+        if start.index() >= len {
+            panic!(
+                "index start out of range: start is {}, but len is {}",
+                start, len,
+            );
+        }
+
+        let mut discovered = vec![false; len];
+        discovered[start.index()] = true;
+        Self {
+            graph,
+            queue: VecDeque::from([start]),
+            discovered,
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Iterator for Bfs<'_, T, E, Ty> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<NodeIndex> {
+        let node = self.queue.pop_front()?;
+        for next in self.graph.neighbors(node) {
+            if !self.discovered[next.index()] {
+                self.discovered[next.index()] = true;
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EdgeIndex, Graph, NodeIndex};
+
+    #[test]
+    fn directed_csr() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[2], 'a').unwrap();
+        graph.add_edge(n[0], n[1], 'b').unwrap();
+        graph.add_edge(n[2], n[3], 'c').unwrap();
+        graph.add_edge(n[1], n[4], 'd').unwrap();
+        let bfs: Vec<_> = graph.bfs(n[0]).collect();
+
+        let csr = graph.into_csr();
+        assert_eq!(csr.node_count(), 5);
+        assert_eq!(csr.edge_count(), 4);
+        assert_eq!(csr.bfs(n[0]).collect::<Vec<_>>(), bfs);
+        assert_eq!(
+            csr.edges_from(n[0]).collect::<Vec<_>>(),
+            vec![(n[2], &'a'), (n[1], &'b')]
+        );
+        assert_eq!(csr.neighbors(n[4]).count(), 0);
+        assert_eq!(csr.edge_weight(EdgeIndex::new(2)), Some(&'d'));
+    }
+
+    #[test]
+    fn undirected_csr() {
+        let mut graph = Graph::new_undirected();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 1).unwrap();
+        graph.add_edge(n[2], n[1], 2).unwrap();
+
+        let csr = graph.into_csr();
+        assert_eq!(csr.edge_count(), 2);
+        assert_eq!(
+            csr.edges_from(n[1]).collect::<Vec<_>>(),
+            vec![(n[0], &1), (n[2], &2)]
+        );
+        let bfs: Vec<_> = csr.bfs(n[2]).map(NodeIndex::index).collect();
+        assert_eq!(bfs, vec![2, 1, 0]);
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// What changed between two versions of a graph, as returned by
+/// [`Graph::diff`].
+///
+/// Nodes of the old graph are given by their index in the old graph and
+/// nodes of the new graph by their index in the new one. Edges are compared
+/// by their ends only, not by their weights.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GraphDiff {
+    /// Pairs of the old and new index of every node in both graphs.
+    pub matched_nodes: Vec<(NodeIndex, NodeIndex)>,
+    pub added_nodes: Vec<NodeIndex>,
+    pub removed_nodes: Vec<NodeIndex>,
+    pub added_edges: Vec<(NodeIndex, NodeIndex)>,
+    pub removed_edges: Vec<(NodeIndex, NodeIndex)>,
+}
+
+impl GraphDiff {
+    /// Whether the graphs have the same nodes and edges.
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+impl<T: Ord, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Compares the graph with a newer version of it, matching nodes with
+    /// equal payloads.
+    pub fn diff(&self, other: &Self) -> GraphDiff {
+        self.diff_by_key(other, |node| node)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Compares the graph with a newer version of it, matching nodes with
+    /// equal keys. Nodes that share a key are matched in index order.
+    pub fn diff_by_key<'a, K: Ord>(
+        &'a self,
+        other: &'a Self,
+        key: impl Fn(&'a T) -> K,
+    ) -> GraphDiff {
+        let mut diff = GraphDiff::default();
+
+        let mut unmatched: BTreeMap<K, VecDeque<usize>> = BTreeMap::new();
+        for (idx, node) in other.nodes.iter().enumerate() {
+            unmatched.entry(key(node)).or_default().push_back(idx);
+        }
+        let mut old_to_new = vec![None; self.nodes.len()];
+        let mut matched = vec![false; other.nodes.len()];
+        for (idx, node) in self.nodes.iter().enumerate() {
+            match unmatched.get_mut(&key(node)).and_then(VecDeque::pop_front) {
+                Some(new_idx) => {
+                    old_to_new[idx] = Some(new_idx);
+                    matched[new_idx] = true;
+                    diff.matched_nodes
+                        .push((NodeIndex::new(idx), NodeIndex::new(new_idx)));
+                }
+                None => diff.removed_nodes.push(NodeIndex::new(idx)),
+            }
+        }
+        diff.added_nodes = (0..other.nodes.len())
+            .filter(|&idx| !matched[idx])
+            .map(NodeIndex::new)
+            .collect();
+
+        // Undirected edges are counted under the same key from either end.
+        let edge_key = |source: usize, target: usize| {
+            if Ty::is_directed() || source <= target {
+                (source, target)
+            } else {
+                (target, source)
+            }
+        };
+        let mut new_edges: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for edge in other.edges() {
+            *new_edges
+                .entry(edge_key(edge.source.index(), edge.target.index()))
+                .or_default() += 1;
+        }
+        for edge in self.edges() {
+            let mapped = old_to_new[edge.source.index()].zip(old_to_new[edge.target.index()]);
+            let count =
+                mapped.and_then(|(source, target)| new_edges.get_mut(&edge_key(source, target)));
+            match count {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => diff.removed_edges.push((edge.source, edge.target)),
+            }
+        }
+        for edge in other.edges() {
+            let count = new_edges
+                .get_mut(&edge_key(edge.source.index(), edge.target.index()))
+                .expect("every edge was counted");
+            if *count > 0 {
+                *count -= 1;
+                diff.added_edges.push((edge.source, edge.target));
+            }
+        }
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphDiff;
+    use crate::{DiGraph, UnGraph};
+
+    #[test]
+    fn dependency_graph_versions() {
+        let mut old = DiGraph::new();
+        let app = old.add_node("app");
+        let log = old.add_node("log");
+        let json = old.add_node("json");
+        old.add_edge(app, log, ()).unwrap();
+        old.add_edge(app, json, ()).unwrap();
+
+        let mut new = DiGraph::new();
+        let json2 = new.add_node("json");
+        let app2 = new.add_node("app");
+        let http = new.add_node("http");
+        new.add_edge(app2, json2, ()).unwrap();
+        new.add_edge(app2, http, ()).unwrap();
+        new.add_edge(http, json2, ()).unwrap();
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff,
+            GraphDiff {
+                matched_nodes: vec![(app, app2), (json, json2)],
+                added_nodes: vec![http],
+                removed_nodes: vec![log],
+                added_edges: vec![(app2, http), (http, json2)],
+                removed_edges: vec![(app, log)],
+            }
+        );
+        assert!(!diff.is_empty());
+        assert!(old.diff(&old.clone()).is_empty());
+    }
+
+    #[test]
+    fn keys_and_undirected_edges() {
+        let mut old = UnGraph::new_undirected();
+        let a = old.add_node(("a", 1));
+        let b = old.add_node(("b", 1));
+        old.add_edge(a, b, ()).unwrap();
+        old.add_edge(a, b, ()).unwrap();
+
+        let mut new = UnGraph::new_undirected();
+        let b2 = new.add_node(("b", 2));
+        let a2 = new.add_node(("a", 2));
+        new.add_edge(b2, a2, ()).unwrap();
+
+        let by_name = old.diff_by_key(&new, |&(name, _)| name);
+        assert_eq!(by_name.matched_nodes, vec![(a, a2), (b, b2)]);
+        assert_eq!(by_name.removed_edges, vec![(a, b)]);
+        assert!(by_name.added_edges.is_empty());
+
+        let by_payload = old.diff(&new);
+        assert_eq!(by_payload.removed_nodes, vec![a, b]);
+        assert_eq!(by_payload.added_edges, vec![(b2, a2)]);
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph};
+
+mod parse;
+
+pub use parse::DotParseError;
+
+type Label<'a, X> = Box<dyn Fn(&X) -> String + 'a>;
+
+/// Formats a graph in the Graphviz DOT language.
+///
+/// Nodes are identified by their index and labeled with their payload.
+/// Edges are unlabeled unless a label is requested.
+pub struct Dot<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    node_label: Label<'a, T>,
+    edge_label: Option<Label<'a, E>>,
+}
+
+impl<'a, T: Display, E, Ty: EdgeType> Dot<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>) -> Self {
+        Self::with_node_label(graph, |node| node.to_string())
+    }
+}
+
+impl<'a, T, E, Ty: EdgeType> Dot<'a, T, E, Ty> {
+    pub fn with_node_label(graph: &'a Graph<T, E, Ty>, label: impl Fn(&T) -> String + 'a) -> Self {
+        Self {
+            graph,
+            node_label: Box::new(label),
+            edge_label: None,
+        }
+    }
+
+    pub fn edge_label(mut self, label: impl Fn(&E) -> String + 'a) -> Self {
+        self.edge_label = Some(Box::new(label));
+        self
+    }
+
+    pub fn edge_labels(self) -> Self
+    where
+        E: Display,
+    {
+        self.edge_label(|weight| weight.to_string())
+    }
+}
+
+impl<T, E, Ty: EdgeType> Display for Dot<'_, T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (kind, arrow) = if Ty::is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        writeln!(f, "{} {{", kind)?;
+        for (idx, node) in self.graph.nodes.iter().enumerate() {
+            writeln!(
+                f,
+                "    {} [ label = {} ]",
+                idx,
+                Quoted(&(self.node_label)(node))
+            )?;
+        }
+        for (source, edge_data) in self.graph.edges.iter().enumerate() {
+            for edge in edge_data.iter() {
+                write!(f, "    {} {} {}", source, arrow, edge.target)?;
+                if let Some(edge_label) = &self.edge_label {
+                    write!(f, " [ label = {} ]", Quoted(&edge_label(&edge.weight)))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+pub(crate) struct Quoted<'a>(pub(crate) &'a str);
+
+impl Display for Quoted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' | '\\' => write!(f, "\\{}", c)?,
+                '\n' => write!(f, "\\n")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn to_dot(&self) -> String {
+        Dot::new(self).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dot;
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_dot() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("say \"hi\"");
+        graph.add_edge(a, b, 1.5).unwrap();
+        graph.add_edge(b, a, 2.0).unwrap();
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0 [ label = \"a\" ]\n    1 [ label = \"say \\\"hi\\\"\" ]\n    0 -> 1\n    1 -> 0\n}\n"
+        );
+        assert_eq!(
+            Dot::new(&graph).edge_labels().to_string(),
+            "digraph {\n    0 [ label = \"a\" ]\n    1 [ label = \"say \\\"hi\\\"\" ]\n    0 -> 1 [ label = \"1.5\" ]\n    1 -> 0 [ label = \"2\" ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn undirected_custom_labels() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node(vec![1, 2]);
+        let b = graph.add_node(vec![]);
+        graph.add_edge(a, b, "x").unwrap();
+
+        let dot = Dot::with_node_label(&graph, |node| format!("{} items", node.len()))
+            .edge_label(|weight| weight.to_uppercase());
+        assert_eq!(
+            dot.to_string(),
+            "graph {\n    0 [ label = \"2 items\" ]\n    1 [ label = \"0 items\" ]\n    0 -- 1 [ label = \"X\" ]\n}\n"
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+#[derive(Debug, PartialEq)]
+pub struct DotParseError(pub String);
+
+impl Display for DotParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid DOT: {}", self.0)
+    }
+}
+
+impl core::error::Error for DotParseError {}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Id(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semicolon,
+    Comma,
+    Equals,
+    Arrow,
+    Line,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, DotParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\n' => {
+                line += 1;
+                continue;
+            }
+            c if c.is_whitespace() => continue,
+            '#' => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let start = line;
+                loop {
+                    match chars.next() {
+                        Some('*') if chars.next_if_eq(&'/').is_some() => break,
+                        Some('\n') => line += 1,
+                        Some(_) => {}
+                        None => {
+                            return Err(DotParseError(format!(
+                                "unterminated comment starting on line {}",
+                                start
+                            )))
+                        }
+                    }
+                }
+                continue;
+            }
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ';' => Token::Semicolon,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            '-' if chars.next_if_eq(&'>').is_some() => Token::Arrow,
+            '-' if chars.next_if_eq(&'-').is_some() => Token::Line,
+            '"' => {
+                let start = line;
+                let mut id = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => id.push('\n'),
+                            Some(c @ ('"' | '\\')) => id.push(c),
+                            Some(c) => {
+                                id.push('\\');
+                                id.push(c);
+                            }
+                            None => {}
+                        },
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            id.push(c);
+                        }
+                        None => {
+                            return Err(DotParseError(format!(
+                                "unterminated string starting on line {}",
+                                start
+                            )))
+                        }
+                    }
+                }
+                Token::Id(id)
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let mut id = c.to_string();
+                while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+                {
+                    id.push(c);
+                }
+                Token::Id(id)
+            }
+            c => {
+                return Err(DotParseError(format!(
+                    "unexpected character '{}' on line {}",
+                    c, line
+                )))
+            }
+        };
+        tokens.push((token, line));
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<Ty> {
+    tokens: core::iter::Peekable<alloc::vec::IntoIter<(Token, usize)>>,
+    line: usize,
+    graph: Graph<String, String, Ty>,
+    ids: BTreeMap<String, NodeIndex>,
+}
+
+impl<Ty: EdgeType> Parser<Ty> {
+    fn next(&mut self) -> Result<Token, DotParseError> {
+        match self.tokens.next() {
+            Some((token, line)) => {
+                self.line = line;
+                Ok(token)
+            }
+            None => Err(DotParseError("unexpected end of input".to_string())),
+        }
+    }
+
+    fn peek(&mut self) -> Option<&Token> {
+        self.tokens.peek().map(|(token, _)| token)
+    }
+
+    fn error(&self, message: &str) -> DotParseError {
+        DotParseError(format!("{} on line {}", message, self.line))
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), DotParseError> {
+        let token = self.next()?;
+        if token != expected {
+            return Err(self.error(&format!("expected {:?}, found {:?}", expected, token)));
+        }
+        Ok(())
+    }
+
+    fn id(&mut self) -> Result<String, DotParseError> {
+        match self.next()? {
+            Token::Id(id) => Ok(id),
+            token => Err(self.error(&format!("expected an identifier, found {:?}", token))),
+        }
+    }
+
+    fn node(&mut self, id: String) -> NodeIndex {
+        if let Some(&idx) = self.ids.get(&id) {
+            return idx;
+        }
+
+        let idx = self.graph.add_node(id.clone());
+        self.ids.insert(id, idx);
+        idx
+    }
+
+    fn parse(mut self) -> Result<Graph<String, String, Ty>, DotParseError> {
+        let mut kind = self.id()?;
+        if kind.eq_ignore_ascii_case("strict") {
+            kind = self.id()?;
+        }
+
+        let expected = if Ty::is_directed() {
+            "digraph"
+        } else {
+            "graph"
+        };
+        if !kind.eq_ignore_ascii_case(expected) {
+            return Err(self.error(&format!("expected {}, found {}", expected, kind)));
+        }
+
+        if let Some(Token::Id(_)) = self.peek() {
+            self.next()?;
+        }
+        self.expect(Token::LBrace)?;
+
+        loop {
+            match self.next()? {
+                Token::RBrace => break,
+                Token::Semicolon => {}
+                Token::Id(id) => self.statement(id)?,
+                token => return Err(self.error(&format!("unexpected {:?}", token))),
+            }
+        }
+
+        if self.tokens.next().is_some() {
+            return Err(self.error("unexpected input after closing brace"));
+        }
+        Ok(self.graph)
+    }
+
+    fn statement(&mut self, id: String) -> Result<(), DotParseError> {
+        let is_keyword = |keyword: &str| id.eq_ignore_ascii_case(keyword);
+        if is_keyword("subgraph") {
+            return Err(self.error("subgraphs are not supported"));
+        }
+        if ["graph", "node", "edge"].into_iter().any(is_keyword) {
+            self.attributes()?;
+            return Ok(());
+        }
+
+        if self.peek() == Some(&Token::Equals) {
+            self.next()?;
+            self.id()?;
+            return Ok(());
+        }
+
+        let mut chain = vec![self.node(id)];
+        let edge_op = if Ty::is_directed() {
+            Token::Arrow
+        } else {
+            Token::Line
+        };
+        while let Some(token @ (Token::Arrow | Token::Line)) = self.peek() {
+            if *token != edge_op {
+                let op = if *token == Token::Arrow { "->" } else { "--" };
+                self.next()?;
+                return Err(self.error(&format!("unexpected '{}' edge", op)));
+            }
+            self.next()?;
+            let id = self.id()?;
+            chain.push(self.node(id));
+        }
+
+        let label = self
+            .attributes()?
+            .into_iter()
+            .rev()
+            .find(|(key, _)| key == "label")
+            .map(|(_, value)| value);
+
+        if chain.len() == 1 {
+            if let Some(label) = label {
+                self.graph.nodes[chain[0].index()] = label;
+            }
+            return Ok(());
+        }
+
+        for pair in chain.windows(2) {
+            if pair[0] == pair[1] {
+                self.graph.allows_self_loops = true;
+            }
+            self.graph
+                .add_edge(pair[0], pair[1], label.clone().unwrap_or_default())
+                .map_err(|err| self.error(&err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn attributes(&mut self) -> Result<Vec<(String, String)>, DotParseError> {
+        let mut attributes = Vec::new();
+        while self.peek() == Some(&Token::LBracket) {
+            self.next()?;
+            loop {
+                match self.next()? {
+                    Token::RBracket => break,
+                    Token::Comma | Token::Semicolon => {}
+                    Token::Id(key) => {
+                        self.expect(Token::Equals)?;
+                        let value = self.id()?;
+                        attributes.push((key, value));
+                    }
+                    token => return Err(self.error(&format!("unexpected {:?}", token))),
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
+
+impl<Ty: EdgeType> Graph<String, String, Ty> {
+    /// Parses a graph written in the Graphviz DOT language.
+    ///
+    /// Nodes hold their `label` attribute, or their identifier when they have
+    /// none, and edges hold their `label` attribute or an empty string. The
+    /// `graph`/`digraph` keyword must match the edge type being parsed into.
+    /// Other attributes are ignored and subgraphs are not supported. The
+    /// parsed graph allows self-loops if the input has any.
+    pub fn from_dot(input: &str) -> Result<Self, DotParseError> {
+        Parser {
+            tokens: tokenize(input)?.into_iter().peekable(),
+            line: 1,
+            graph: Graph::default(),
+            ids: BTreeMap::new(),
+        }
+        .parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DotParseError;
+    use crate::{DiGraph, Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn parses_statements() {
+        let input = r#"
+            digraph deps {
+                // crates
+                rankdir = LR;
+                node [shape = box]
+                core [label = "the core"];
+                core -> alloc -> std [label = uses, color = red]
+                "my app" -> std
+            }
+        "#;
+
+        let graph: Graph<String, String> = Graph::from_dot(input).unwrap();
+        assert_eq!(
+            graph.nodes().collect::<Vec<_>>(),
+            vec!["the core", "alloc", "std", "my app"]
+        );
+        let weight = |from, to| graph.edge_weight(NodeIndex::new(from), NodeIndex::new(to));
+        assert_eq!(weight(0, 1).map(String::as_str), Some("uses"));
+        assert_eq!(weight(1, 2).map(String::as_str), Some("uses"));
+        assert_eq!(weight(3, 2).map(String::as_str), Some(""));
+    }
+
+    #[test]
+    fn round_trips_export() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node("say \"hi\"".to_string());
+        let b = graph.add_node("b".to_string());
+        graph.add_edge(a, b, "x".to_string()).unwrap();
+
+        let parsed = UnGraph::from_dot(&graph.to_dot()).unwrap();
+        assert_eq!(parsed.nodes().collect::<Vec<_>>(), vec!["say \"hi\"", "b"]);
+        assert_eq!(parsed.get_edges(b), Ok(vec![a]));
+    }
+
+    #[test]
+    fn parses_self_loops() {
+        let graph = DiGraph::from_dot("digraph { a -> a -> b }").unwrap();
+        assert!(graph.allows_self_loops());
+        assert!(graph.contains_edge(NodeIndex::new(0), NodeIndex::new(0)));
+
+        let mut graph = UnGraph::new_undirected().allow_self_loops(true);
+        let a = graph.add_node("a".to_string());
+        graph.add_edge(a, a, "x".to_string()).unwrap();
+        let parsed = UnGraph::from_dot(&graph.to_dot()).unwrap();
+        assert!(parsed.contains_edge(a, a));
+        assert!(!DiGraph::from_dot("digraph { a -> b }")
+            .unwrap()
+            .allows_self_loops());
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            UnGraph::from_dot("digraph { a -> b }").err(),
+            Some(DotParseError(
+                "expected graph, found digraph on line 1".to_string()
+            ))
+        );
+        assert_eq!(
+            DiGraph::from_dot("digraph {\n a -- b }").err(),
+            Some(DotParseError("unexpected '--' edge on line 2".to_string()))
+        );
+        assert_eq!(
+            DiGraph::from_dot("digraph {").unwrap_err().to_string(),
+            "invalid DOT: unexpected end of input"
+        );
+        assert!(DiGraph::from_dot("digraph { a -> }").is_err());
+        assert!(DiGraph::from_dot("digraph { subgraph { a } }").is_err());
+    }
+}
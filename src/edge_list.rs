@@ -0,0 +1,312 @@
+//! Plain-text edge lists with one `from,to[,weight]` edge per line, as used
+//! by CSV exports and by SNAP datasets, which separate fields with tabs.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+#[derive(Debug)]
+pub enum EdgeListError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl Display for EdgeListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EdgeListError::Io(err) => write!(f, "{}", err),
+            EdgeListError::Parse { line, message } => write!(f, "{} on line {}", message, line),
+        }
+    }
+}
+
+impl std::error::Error for EdgeListError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EdgeListError::Io(err) => Some(err),
+            EdgeListError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for EdgeListError {
+    fn from(err: io::Error) -> Self {
+        EdgeListError::Io(err)
+    }
+}
+
+/// Reads and writes edge lists with a chosen delimiter, a comma by default.
+///
+/// Nodes are written as their payload, so isolated nodes are left out. When
+/// reading, a node is added the first time its name appears and its payload
+/// is parsed from that name. Blank lines and lines starting with `#` are
+/// skipped, and edges without a weight get the default weight. The graph
+/// read allows self-loops if any line has the same node twice.
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeList {
+    delimiter: Option<char>,
+    allows_self_loops: bool,
+}
+
+impl Default for EdgeList {
+    fn default() -> Self {
+        Self {
+            delimiter: Some(','),
+            allows_self_loops: true,
+        }
+    }
+}
+
+impl EdgeList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Splits fields on any run of whitespace when reading, and separates
+    /// them with tabs when writing.
+    pub fn whitespace(mut self) -> Self {
+        self.delimiter = None;
+        self
+    }
+
+    /// Self-loops are read by default. Forbidding them makes a line with the
+    /// same node twice an error.
+    pub fn allow_self_loops(mut self, allow: bool) -> Self {
+        self.allows_self_loops = allow;
+        self
+    }
+
+    /// Reads edges without weights, ignoring any weight column.
+    pub fn read<T, E, Ty>(&self, reader: impl BufRead) -> Result<Graph<T, E, Ty>, EdgeListError>
+    where
+        T: FromStr,
+        E: Default,
+        Ty: EdgeType,
+    {
+        self.read_lines(reader, |_| Some(E::default()))
+    }
+
+    pub fn read_weighted<T, E, Ty>(
+        &self,
+        reader: impl BufRead,
+    ) -> Result<Graph<T, E, Ty>, EdgeListError>
+    where
+        T: FromStr,
+        E: FromStr + Default,
+        Ty: EdgeType,
+    {
+        self.read_lines(reader, |weight| weight.parse().ok())
+    }
+
+    fn read_lines<T: FromStr, E: Default, Ty: EdgeType>(
+        &self,
+        reader: impl BufRead,
+        weight: impl Fn(&str) -> Option<E>,
+    ) -> Result<Graph<T, E, Ty>, EdgeListError> {
+        let mut graph = Graph::default();
+        let mut ids = BTreeMap::new();
+        for (line, text) in (1..).zip(reader.lines()) {
+            let text = text?;
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let error = |message: String| EdgeListError::Parse { line, message };
+            let fields: Vec<&str> = match self.delimiter {
+                Some(delimiter) => text.split(delimiter).map(str::trim).collect(),
+                None => text.split_whitespace().collect(),
+            };
+            if !(2..=3).contains(&fields.len()) {
+                return Err(error(format!(
+                    "expected 2 or 3 fields, found {}",
+                    fields.len()
+                )));
+            }
+
+            let mut node = |name: &str| -> Result<NodeIndex, EdgeListError> {
+                match ids.get(name) {
+                    Some(&idx) => Ok(idx),
+                    None => {
+                        let payload = name
+                            .parse()
+                            .map_err(|_| error(format!("invalid node '{}'", name)))?;
+                        let idx = graph.add_node(payload);
+                        ids.insert(name.to_string(), idx);
+                        Ok(idx)
+                    }
+                }
+            };
+            let from = node(fields[0])?;
+            let to = node(fields[1])?;
+            let weight = match fields.get(2) {
+                Some(field) => {
+                    weight(field).ok_or_else(|| error(format!("invalid weight '{}'", field)))?
+                }
+                None => E::default(),
+            };
+            if from == to && self.allows_self_loops {
+                graph.allows_self_loops = true;
+            }
+            graph
+                .add_edge(from, to, weight)
+                .map_err(|err| error(err.to_string()))?;
+        }
+
+        Ok(graph)
+    }
+
+    pub fn write<T: Display, E, Ty: EdgeType>(
+        &self,
+        graph: &Graph<T, E, Ty>,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        self.write_lines(graph, writer, |_| None)
+    }
+
+    pub fn write_weighted<T: Display, E: Display, Ty: EdgeType>(
+        &self,
+        graph: &Graph<T, E, Ty>,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        self.write_lines(graph, writer, |weight| Some(weight.to_string()))
+    }
+
+    fn write_lines<T: Display, E, Ty: EdgeType>(
+        &self,
+        graph: &Graph<T, E, Ty>,
+        mut writer: impl Write,
+        weight: impl Fn(&E) -> Option<String>,
+    ) -> io::Result<()> {
+        let delimiter = self.delimiter.unwrap_or('\t');
+        for edge in graph.edges() {
+            let (source, target) = (&graph[edge.source()], &graph[edge.target()]);
+            write!(writer, "{}{}{}", source, delimiter, target)?;
+            if let Some(weight) = weight(edge.weight()) {
+                write!(writer, "{}{}", delimiter, weight)?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+}
+
+impl<T: FromStr, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Reads comma-separated `from,to` lines, ignoring any weight column. Use
+    /// [`EdgeList`] for other delimiters.
+    pub fn from_edge_list_reader(reader: impl BufRead) -> Result<Self, EdgeListError> {
+        EdgeList::new().read(reader)
+    }
+
+    /// Reads comma-separated `from,to[,weight]` lines.
+    pub fn from_weighted_edge_list_reader(reader: impl BufRead) -> Result<Self, EdgeListError>
+    where
+        E: FromStr,
+    {
+        EdgeList::new().read_weighted(reader)
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Writes every edge as a `from,to` line, leaving out weights.
+    pub fn write_edge_list(&self, writer: impl Write) -> io::Result<()> {
+        EdgeList::new().write(self, writer)
+    }
+
+    /// Writes every edge as a `from,to,weight` line.
+    pub fn write_weighted_edge_list(&self, writer: impl Write) -> io::Result<()>
+    where
+        E: Display,
+    {
+        EdgeList::new().write_weighted(self, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EdgeList;
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn reads_snap_edges() {
+        let input =
+            "# Directed graph: web-Example.txt\n# FromNodeId\tToNodeId\n30\t1\t1217567877\n30  7\n\n7\t1\n";
+        let graph: DiGraph<u32> = EdgeList::new().whitespace().read(input.as_bytes()).unwrap();
+
+        assert_eq!(graph.nodes().collect::<Vec<_>>(), vec![&30, &1, &7]);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.contains_edge(NodeIndex::new(2), NodeIndex::new(1)));
+    }
+
+    #[test]
+    fn round_trips_weights() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(a, b, 1.5).unwrap();
+        graph.add_edge(c, a, 2.0).unwrap();
+
+        let mut written = Vec::new();
+        graph.write_weighted_edge_list(&mut written).unwrap();
+        assert_eq!(
+            String::from_utf8(written.clone()).unwrap(),
+            "a,b,1.5\nc,a,2\n"
+        );
+        let read = UnGraph::<String, f64>::from_weighted_edge_list_reader(&written[..]).unwrap();
+        assert!(read == graph);
+
+        let mut unweighted = Vec::new();
+        EdgeList::new()
+            .delimiter(';')
+            .write(&graph, &mut unweighted)
+            .unwrap();
+        assert_eq!(String::from_utf8(unweighted).unwrap(), "a;b\nc;a\n");
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        let read = |input: &str| {
+            DiGraph::<u32, f64>::from_weighted_edge_list_reader(input.as_bytes())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            read("1,2\n3\n"),
+            "expected 2 or 3 fields, found 1 on line 2"
+        );
+        assert_eq!(read("# nodes\n1,x"), "invalid node 'x' on line 2");
+        assert_eq!(read("1,2,heavy"), "invalid weight 'heavy' on line 1");
+        let looped: Result<DiGraph<u32>, _> = EdgeList::new()
+            .allow_self_loops(false)
+            .read("1,2\n\n2,2".as_bytes());
+        assert_eq!(
+            looped.unwrap_err().to_string(),
+            "node 1 cannot point to itself on line 3"
+        );
+    }
+
+    #[test]
+    fn reads_self_loops() {
+        let input = "1\t1\n1\t2\n";
+        let graph: DiGraph<u32> = EdgeList::new().whitespace().read(input.as_bytes()).unwrap();
+        assert!(graph.allows_self_loops());
+        assert!(graph.contains_edge(NodeIndex::new(0), NodeIndex::new(0)));
+
+        let mut written = Vec::new();
+        graph.write_edge_list(&mut written).unwrap();
+        let read = DiGraph::<u32>::from_edge_list_reader(&written[..]).unwrap();
+        assert!(read == graph);
+        let simple = DiGraph::<u32>::from_edge_list_reader("1,2".as_bytes()).unwrap();
+        assert!(!simple.allows_self_loops());
+    }
+}
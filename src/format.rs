@@ -0,0 +1,186 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// Configures how a graph is written as text, one edge per line.
+///
+/// The `Display` implementation of [`Graph`] uses the defaults: `->` for
+/// directed and `--` for undirected edges, no indices, no isolated nodes and
+/// no limit.
+pub struct GraphFormatter<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    arrow: Option<&'a str>,
+    isolated_nodes: bool,
+    indices: bool,
+    limit: Option<usize>,
+}
+
+impl<'a, T: Display, E, Ty: EdgeType> GraphFormatter<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>) -> Self {
+        Self {
+            graph,
+            arrow: None,
+            isolated_nodes: false,
+            indices: false,
+            limit: None,
+        }
+    }
+
+    pub fn arrow(mut self, arrow: &'a str) -> Self {
+        self.arrow = Some(arrow);
+        self
+    }
+
+    /// Writes nodes without any edges on lines of their own, which are
+    /// otherwise left out.
+    pub fn isolated_nodes(mut self, enabled: bool) -> Self {
+        self.isolated_nodes = enabled;
+        self
+    }
+
+    /// Writes the index of every node in brackets before its payload.
+    pub fn indices(mut self, enabled: bool) -> Self {
+        self.indices = enabled;
+        self
+    }
+
+    /// Stops after `lines` lines, ending with a line that counts the ones
+    /// left out.
+    pub fn limit(mut self, lines: usize) -> Self {
+        self.limit = Some(lines);
+        self
+    }
+
+    fn node(&self, idx: usize) -> Node<'_, T> {
+        Node {
+            payload: &self.graph.nodes[idx],
+            index: self.indices.then_some(idx),
+        }
+    }
+}
+
+struct Node<'a, T> {
+    payload: &'a T,
+    index: Option<usize>,
+}
+
+impl<T: Display> Display for Node<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(index) = self.index {
+            write!(f, "[{}] ", index)?;
+        }
+        write!(f, "{}", self.payload)
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Display for GraphFormatter<'_, T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let arrow = match self.arrow {
+            Some(arrow) => arrow,
+            None if Ty::is_directed() => "->",
+            None => "--",
+        };
+        let is_isolated = |idx: usize| {
+            self.isolated_nodes
+                && self.graph.edges[idx].is_empty()
+                && self.graph.in_degree(NodeIndex::new(idx)) == 0
+        };
+        let lines = self
+            .graph
+            .edges
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, targets)| {
+                let isolated = is_isolated(idx).then_some(None);
+                let targets = targets.iter().map(|edge| Some(edge.target.index()));
+                isolated
+                    .into_iter()
+                    .chain(targets)
+                    .map(move |target| (idx, target))
+            });
+
+        let limit = self.limit.unwrap_or(usize::MAX);
+        let mut skipped = 0;
+        for (line, (idx, target)) in lines.enumerate() {
+            if line >= limit {
+                skipped += 1;
+                continue;
+            }
+
+            match target {
+                Some(target) => writeln!(f, "{} {} {}", self.node(idx), arrow, self.node(target))?,
+                None => writeln!(f, "{}", self.node(idx))?,
+            }
+        }
+
+        if skipped > 0 {
+            writeln!(f, "... {} more", skipped)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn formatter(&self) -> GraphFormatter<'_, T, E, Ty> {
+        GraphFormatter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    fn graph() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_node("lonely");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(a, d, ()).unwrap();
+        graph.add_edge(d, a, ()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn default_matches_display() {
+        let graph = graph();
+        assert_eq!(graph.formatter().to_string(), graph.to_string());
+        assert_eq!(graph.to_string(), "a -> b\na -> d\nd -> a\n");
+    }
+
+    #[test]
+    fn options() {
+        let graph = graph();
+        assert_eq!(
+            graph
+                .formatter()
+                .arrow("=>")
+                .indices(true)
+                .isolated_nodes(true)
+                .to_string(),
+            "[0] a => [1] b\n[0] a => [3] d\n[2] lonely\n[3] d => [0] a\n"
+        );
+
+        let undirected = UnGraph::<u8>::from_edges([(0, 1)]).unwrap();
+        assert_eq!(
+            undirected.formatter().indices(true).to_string(),
+            "[0] 0 -- [1] 0\n"
+        );
+    }
+
+    #[test]
+    fn limits_lines() {
+        let graph = graph();
+        assert_eq!(
+            graph.formatter().limit(1).to_string(),
+            "a -> b\n... 2 more\n"
+        );
+        assert_eq!(
+            graph.formatter().isolated_nodes(true).limit(2).to_string(),
+            "a -> b\na -> d\n... 2 more\n"
+        );
+        assert_eq!(graph.formatter().limit(3).to_string(), graph.to_string());
+        assert_eq!(graph.formatter().limit(0).to_string(), "... 3 more\n");
+    }
+}
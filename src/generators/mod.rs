@@ -0,0 +1,113 @@
+//! Constructors for common kinds of graph, mainly for benchmarks and tests.
+//! Every generated node holds the default value and every edge the default
+//! weight.
+
+#[cfg(feature = "rand")]
+mod random;
+
+use crate::{Edge, EdgeType, Graph, NodeIndex};
+
+impl<T: Default, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Builds a path through `n` nodes, with an edge from every node to the
+    /// one at the next index.
+    pub fn path_graph(n: usize) -> Self {
+        Self::from_generated_edges(n, (1..n).map(|to| (to - 1, to)))
+    }
+
+    /// Builds a path through `n` nodes, like [`Graph::path_graph`], closed by
+    /// an edge from the last node back to node 0. With fewer than three nodes
+    /// the closing edge would duplicate an edge or be a self-loop, so it is
+    /// left out.
+    pub fn cycle_graph(n: usize) -> Self {
+        let closing = (n > 2).then(|| (n - 1, 0));
+        Self::from_generated_edges(n, (1..n).map(|to| (to - 1, to)).chain(closing))
+    }
+
+    /// Builds a graph with an edge between every pair of its `n` nodes. A
+    /// directed graph gets an edge each way, while an undirected graph gets a
+    /// single edge from the lower index to the higher one.
+    pub fn complete_graph(n: usize) -> Self {
+        let edges = (0..n).flat_map(|from| {
+            let targets = if Ty::is_directed() { 0 } else { from + 1 };
+            (targets..n)
+                .filter(move |&to| to != from)
+                .map(move |to| (from, to))
+        });
+        Self::from_generated_edges(n, edges)
+    }
+
+    /// Builds a star of `n` nodes, with an edge from the center, node 0, to
+    /// every other node.
+    pub fn star_graph(n: usize) -> Self {
+        Self::from_generated_edges(n, (1..n).map(|leaf| (0, leaf)))
+    }
+
+    /// Builds a `width` by `height` grid, laid out row by row, so the node in
+    /// column `x` of row `y` is at index `y * width + x`. Every node has an
+    /// edge to its right and lower neighbors.
+    pub fn grid_graph(width: usize, height: usize) -> Self {
+        let edges = (0..width * height).flat_map(|node| {
+            let right = (node % width + 1 < width).then_some((node, node + 1));
+            let down = (node + width < width * height).then_some((node, node + width));
+            right.into_iter().chain(down)
+        });
+        Self::from_generated_edges(width * height, edges)
+    }
+
+    /// Builds a graph with `n` default nodes and an edge for each pair, which
+    /// must not contain duplicates or self-loops.
+    fn from_generated_edges(n: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut graph = Self::with_capacity(n, 0);
+        graph.extend((0..n).map(|_| T::default()));
+        for (from, to) in edges {
+            graph.edges[from].push(Edge {
+                target: NodeIndex::new(to),
+                weight: E::default(),
+            });
+        }
+        graph.recount_edges();
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn structured_graphs() {
+        assert_eq!(DiGraph::<()>::path_graph(0).node_count(), 0);
+        let path = DiGraph::<()>::path_graph(3);
+        let next: Vec<_> = path.neighbors(NodeIndex::new(1)).collect();
+        assert_eq!(next, vec![NodeIndex::new(2)]);
+        assert_eq!(path.find_cycle(), None);
+
+        assert_eq!(DiGraph::<()>::cycle_graph(5).edge_count(), 5);
+        assert_eq!(UnGraph::<()>::cycle_graph(2).edge_count(), 1);
+        assert!(DiGraph::<()>::cycle_graph(4).is_cyclic());
+
+        assert_eq!(DiGraph::<()>::complete_graph(5).edge_count(), 20);
+        let complete = UnGraph::<()>::complete_graph(5);
+        assert_eq!(complete.edge_count(), 10);
+        assert_eq!(complete.degree(NodeIndex::new(3)), 4);
+
+        let star = UnGraph::<()>::star_graph(4);
+        assert_eq!(star.degree(NodeIndex::new(0)), 3);
+        assert_eq!(star.degree(NodeIndex::new(2)), 1);
+    }
+
+    #[test]
+    fn grid_layout() {
+        let grid = DiGraph::<()>::grid_graph(3, 2);
+        assert_eq!(grid.node_count(), 6);
+        assert_eq!(grid.edge_count(), 7);
+        let edges: Vec<_> = grid
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![(0, 1), (0, 3), (1, 2), (1, 4), (2, 5), (3, 4), (4, 5)]
+        );
+    }
+}
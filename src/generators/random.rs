@@ -0,0 +1,140 @@
+use rand::Rng;
+
+use crate::{Directed, EdgeType, Graph};
+
+impl<T: Default, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Builds a G(n, p) random graph, where each of the possible edges between
+    /// `n` nodes is added independently with probability `p`. Directed graphs
+    /// consider both directions of every pair separately. Self-loops are never
+    /// added.
+    ///
+    /// Panics if `p` is not between 0 and 1.
+    pub fn erdos_renyi<R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> Self {
+        check_probability(p);
+        let mut edges = Vec::new();
+        for from in 0..n {
+            let targets = if Ty::is_directed() { 0 } else { from + 1 };
+            for to in targets..n {
+                if to != from && rng.gen_bool(p) {
+                    edges.push((from, to));
+                }
+            }
+        }
+        Self::from_generated_edges(n, edges)
+    }
+
+    /// Builds a scale-free graph by preferential attachment. The graph starts
+    /// as a star of `m + 1` nodes centered on node 0, and every later node gets
+    /// edges to `m` distinct earlier nodes, picked with probability
+    /// proportional to their degree. Edges point from the newer node to the
+    /// older one.
+    ///
+    /// Panics unless `1 <= m < n`.
+    pub fn barabasi_albert<R: Rng + ?Sized>(n: usize, m: usize, rng: &mut R) -> Self {
+        if m == 0 || m >= n {
+            panic!(
+                "m out of range: m is {}, but must be at least 1 and less than n, which is {}",
+                m, n
+            );
+        }
+
+        let mut edges: Vec<_> = (1..=m).map(|leaf| (leaf, 0)).collect();
+        // Every node appears once per edge it touches, so a uniform pick from
+        // this list is a pick weighted by degree.
+        let mut endpoints: Vec<_> = edges.iter().flat_map(|&(from, to)| [from, to]).collect();
+        let mut targets = Vec::with_capacity(m);
+        for node in m + 1..n {
+            targets.clear();
+            while targets.len() < m {
+                let target = endpoints[rng.gen_range(0..endpoints.len())];
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+            for &target in targets.iter() {
+                edges.push((node, target));
+                endpoints.push(node);
+                endpoints.push(target);
+            }
+        }
+        Self::from_generated_edges(n, edges)
+    }
+}
+
+impl<T: Default, E: Default> Graph<T, E, Directed> {
+    /// Builds a random directed acyclic graph, adding each edge from a lower
+    /// node index to a higher one independently with probability `p`. Node
+    /// indices are therefore always in topological order.
+    ///
+    /// Panics if `p` is not between 0 and 1.
+    pub fn random_dag<R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> Self {
+        check_probability(p);
+        let mut edges = Vec::new();
+        for from in 0..n {
+            for to in from + 1..n {
+                if rng.gen_bool(p) {
+                    edges.push((from, to));
+                }
+            }
+        }
+        Self::from_generated_edges(n, edges)
+    }
+}
+
+fn check_probability(p: f64) {
+    if !(0.0..=1.0).contains(&p) {
+        panic!(
+            "probability out of range: p is {}, but must be between 0 and 1",
+            p
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn erdos_renyi() {
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(DiGraph::<()>::erdos_renyi(6, 0.0, &mut rng).edge_count(), 0);
+        assert_eq!(
+            DiGraph::<()>::erdos_renyi(6, 1.0, &mut rng).edge_count(),
+            30
+        );
+        assert_eq!(
+            UnGraph::<()>::erdos_renyi(6, 1.0, &mut rng).edge_count(),
+            15
+        );
+
+        let graph = UnGraph::<()>::erdos_renyi(40, 0.5, &mut rng);
+        assert_eq!(graph.node_count(), 40);
+        assert!(graph.edge_count() > 0 && graph.edge_count() < 780);
+    }
+
+    #[test]
+    fn preferential_attachment() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let graph = UnGraph::<()>::barabasi_albert(50, 3, &mut rng);
+        assert_eq!(graph.edge_count(), 3 + 46 * 3);
+        assert_eq!(graph.connected_components().len(), 1);
+        for node in (0..graph.node_count()).map(NodeIndex::new) {
+            let mut neighbors: Vec<_> = graph.neighbors(node).collect();
+            let count = neighbors.len();
+            neighbors.sort();
+            neighbors.dedup();
+            assert_eq!(neighbors.len(), count);
+        }
+    }
+
+    #[test]
+    fn random_dag() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let graph = DiGraph::<()>::random_dag(30, 0.3, &mut rng);
+        assert!(!graph.is_cyclic());
+        assert!(graph.edges().all(|edge| edge.source() < edge.target()));
+    }
+}
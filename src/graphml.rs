@@ -0,0 +1,230 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph};
+
+mod parse;
+
+pub use parse::{Attributes, GraphMlParseError};
+
+type Attribute<'a, X> = (String, Box<dyn Fn(&X) -> String + 'a>);
+
+/// Formats a graph as GraphML, which Gephi, yEd and most other graph tools
+/// can open.
+///
+/// Nodes are identified as `n0`, `n1`, ... by their index. Each requested
+/// attribute becomes a string-typed `<key>` and is written as a `<data>`
+/// element on every node or edge.
+pub struct GraphMl<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    node_attributes: Vec<Attribute<'a, T>>,
+    edge_attributes: Vec<Attribute<'a, E>>,
+}
+
+impl<'a, T, E, Ty: EdgeType> GraphMl<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>) -> Self {
+        Self {
+            graph,
+            node_attributes: Vec::new(),
+            edge_attributes: Vec::new(),
+        }
+    }
+
+    pub fn node_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Fn(&T) -> String + 'a,
+    ) -> Self {
+        self.node_attributes.push((name.into(), Box::new(value)));
+        self
+    }
+
+    pub fn edge_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Fn(&E) -> String + 'a,
+    ) -> Self {
+        self.edge_attributes.push((name.into(), Box::new(value)));
+        self
+    }
+
+    /// Writes the payload of every node as its `label` attribute.
+    pub fn node_labels(self) -> Self
+    where
+        T: Display,
+    {
+        self.node_attribute("label", |node| node.to_string())
+    }
+
+    /// Writes the weight of every edge as its `weight` attribute.
+    pub fn edge_weights(self) -> Self
+    where
+        E: Display,
+    {
+        self.edge_attribute("weight", |weight| weight.to_string())
+    }
+}
+
+impl<T, E, Ty: EdgeType> Display for GraphMl<'_, T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        let node_keys = self.node_attributes.iter().map(|(name, _)| ("node", name));
+        let edge_keys = self.edge_attributes.iter().map(|(name, _)| ("edge", name));
+        for (key, (domain, name)) in node_keys.chain(edge_keys).enumerate() {
+            writeln!(
+                f,
+                r#"  <key id="d{}" for="{}" attr.name="{}" attr.type="string"/>"#,
+                key,
+                domain,
+                Escaped(name)
+            )?;
+        }
+
+        let edge_default = if Ty::is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(f, r#"  <graph edgedefault="{}">"#, edge_default)?;
+        for (idx, node) in self.graph.nodes.iter().enumerate() {
+            if self.node_attributes.is_empty() {
+                writeln!(f, r#"    <node id="n{}"/>"#, idx)?;
+                continue;
+            }
+
+            writeln!(f, r#"    <node id="n{}">"#, idx)?;
+            for (key, (_, value)) in self.node_attributes.iter().enumerate() {
+                writeln!(
+                    f,
+                    r#"      <data key="d{}">{}</data>"#,
+                    key,
+                    Escaped(&value(node))
+                )?;
+            }
+            writeln!(f, "    </node>")?;
+        }
+
+        let first_key = self.node_attributes.len();
+        for edge in self.graph.edges() {
+            let (source, target) = (edge.source(), edge.target());
+            if self.edge_attributes.is_empty() {
+                writeln!(
+                    f,
+                    r#"    <edge source="n{}" target="n{}"/>"#,
+                    source, target
+                )?;
+                continue;
+            }
+
+            writeln!(f, r#"    <edge source="n{}" target="n{}">"#, source, target)?;
+            for (key, (_, value)) in self.edge_attributes.iter().enumerate() {
+                writeln!(
+                    f,
+                    r#"      <data key="d{}">{}</data>"#,
+                    first_key + key,
+                    Escaped(&value(edge.weight()))
+                )?;
+            }
+            writeln!(f, "    </edge>")?;
+        }
+        writeln!(f, "  </graph>")?;
+        writeln!(f, "</graphml>")
+    }
+}
+
+struct Escaped<'a>(&'a str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => write!(f, "&amp;")?,
+                '<' => write!(f, "&lt;")?,
+                '>' => write!(f, "&gt;")?,
+                '"' => write!(f, "&quot;")?,
+                '\'' => write!(f, "&apos;")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Writes the graph as GraphML with every node labeled with its payload.
+    /// Use [`GraphMl`] to write edge weights or other attributes.
+    pub fn to_graphml(&self) -> String {
+        GraphMl::new(self).node_labels().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphMl;
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_graphml() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a & b");
+        let b = graph.add_node("<c>");
+        graph.add_edge(a, b, 1.5).unwrap();
+
+        assert_eq!(
+            graph.to_graphml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="label" attr.type="string"/>
+  <graph edgedefault="directed">
+    <node id="n0">
+      <data key="d0">a &amp; b</data>
+    </node>
+    <node id="n1">
+      <data key="d0">&lt;c&gt;</data>
+    </node>
+    <edge source="n0" target="n1"/>
+  </graph>
+</graphml>
+"#
+        );
+    }
+
+    #[test]
+    fn undirected_custom_attributes() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node(vec![1, 2]);
+        let b = graph.add_node(vec![]);
+        graph.add_edge(a, b, 3).unwrap();
+
+        let graphml = GraphMl::new(&graph)
+            .node_attribute("size", |node| node.len().to_string())
+            .edge_weights()
+            .to_string();
+        assert_eq!(
+            graphml,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="size" attr.type="string"/>
+  <key id="d1" for="edge" attr.name="weight" attr.type="string"/>
+  <graph edgedefault="undirected">
+    <node id="n0">
+      <data key="d0">2</data>
+    </node>
+    <node id="n1">
+      <data key="d0">0</data>
+    </node>
+    <edge source="n0" target="n1">
+      <data key="d1">3</data>
+    </edge>
+  </graph>
+</graphml>
+"#
+        );
+    }
+}
@@ -0,0 +1,514 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::iter::Peekable;
+use core::str::Chars;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// The `<data>` values of a node or edge, under the `attr.name` of their key.
+pub type Attributes = BTreeMap<String, String>;
+
+#[derive(Debug, PartialEq)]
+pub struct GraphMlParseError(pub String);
+
+impl Display for GraphMlParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid GraphML: {}", self.0)
+    }
+}
+
+impl core::error::Error for GraphMlParseError {}
+
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Content>,
+    line: usize,
+}
+
+enum Content {
+    Element(Element),
+    Text(String),
+}
+
+enum Markup {
+    Start(Element, bool),
+    End(String),
+    Text(String),
+    Skipped,
+}
+
+impl Element {
+    fn error(&self, message: &str) -> GraphMlParseError {
+        GraphMlParseError(format!("{} on line {}", message, self.line))
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn required(&self, name: &str) -> Result<&str, GraphMlParseError> {
+        self.attribute(name).ok_or_else(|| {
+            self.error(&format!(
+                "<{}> is missing its {} attribute",
+                self.name, name
+            ))
+        })
+    }
+
+    fn elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter_map(move |child| match child {
+            Content::Element(element) if element.name == name => Some(element),
+            _ => None,
+        })
+    }
+
+    /// Returns `None` if the element holds other elements instead of text.
+    fn text(&self) -> Option<String> {
+        let mut text = String::new();
+        for child in self.children.iter() {
+            match child {
+                Content::Element(_) => return None,
+                Content::Text(chunk) => text.push_str(chunk),
+            }
+        }
+        Some(text)
+    }
+}
+
+/// Reads just enough XML for GraphML: elements, attributes, text, entities
+/// and CDATA sections. Declarations, comments and processing instructions
+/// are skipped.
+struct Reader<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, message: &str) -> GraphMlParseError {
+        GraphMlParseError(format!("{} on line {}", message, self.line))
+    }
+
+    fn next(&mut self) -> Option<char> {
+        self.next_if(|_| true)
+    }
+
+    fn next_if(&mut self, accept: impl FnOnce(char) -> bool) -> Option<char> {
+        let c = self.chars.next_if(|&c| accept(c))?;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn next_if_eq(&mut self, expected: char) -> bool {
+        self.next_if(|c| c == expected).is_some()
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), GraphMlParseError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(&format!("expected '{}', found '{}'", expected, c))),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.next_if(char::is_whitespace).is_some() {}
+    }
+
+    fn read_until(&mut self, end: &str, what: &str) -> Result<String, GraphMlParseError> {
+        let start = self.line;
+        let mut read = String::new();
+        while !read.ends_with(end) {
+            match self.next() {
+                Some(c) => read.push(c),
+                None => {
+                    return Err(GraphMlParseError(format!(
+                        "unterminated {} starting on line {}",
+                        what, start
+                    )))
+                }
+            }
+        }
+        read.truncate(read.len() - end.len());
+        Ok(read)
+    }
+
+    fn name(&mut self) -> Result<String, GraphMlParseError> {
+        let mut name = String::new();
+        while let Some(c) = self.next_if(|c| c.is_alphanumeric() || "_:-.".contains(c)) {
+            name.push(c);
+        }
+        if name.is_empty() {
+            return Err(self.error("expected a name"));
+        }
+        Ok(name)
+    }
+
+    fn document(mut self) -> Result<Element, GraphMlParseError> {
+        let mut root = None;
+        loop {
+            self.skip_whitespace();
+            match self.next() {
+                None => break,
+                Some('<') => match self.markup()? {
+                    Markup::Start(element, _) if root.is_some() => {
+                        return Err(element.error("unexpected second root element"))
+                    }
+                    Markup::Start(element, true) => root = Some(element),
+                    Markup::Start(element, false) => root = Some(self.content(element)?),
+                    Markup::Skipped => {}
+                    Markup::End(_) | Markup::Text(_) => {
+                        return Err(self.error("unexpected content outside the root element"))
+                    }
+                },
+                Some(_) => return Err(self.error("unexpected text outside the root element")),
+            }
+        }
+
+        root.ok_or_else(|| self.error("missing root element"))
+    }
+
+    /// Reads the markup following a `<`.
+    fn markup(&mut self) -> Result<Markup, GraphMlParseError> {
+        if self.next_if_eq('?') {
+            self.read_until("?>", "processing instruction")?;
+            return Ok(Markup::Skipped);
+        }
+        if self.next_if_eq('!') {
+            if self.next_if_eq('-') {
+                self.expect('-')?;
+                self.read_until("-->", "comment")?;
+                return Ok(Markup::Skipped);
+            }
+            if self.next_if_eq('[') {
+                let section = self.read_until("]]>", "CDATA section")?;
+                return match section.strip_prefix("CDATA[") {
+                    Some(text) => Ok(Markup::Text(text.to_string())),
+                    None => Err(self.error("expected a CDATA section")),
+                };
+            }
+            self.read_until(">", "declaration")?;
+            return Ok(Markup::Skipped);
+        }
+        if self.next_if_eq('/') {
+            let name = self.name()?;
+            self.skip_whitespace();
+            self.expect('>')?;
+            return Ok(Markup::End(name));
+        }
+
+        let mut element = Element {
+            line: self.line,
+            name: self.name()?,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        };
+        loop {
+            self.skip_whitespace();
+            if self.next_if_eq('>') {
+                return Ok(Markup::Start(element, false));
+            }
+            if self.next_if_eq('/') {
+                self.expect('>')?;
+                return Ok(Markup::Start(element, true));
+            }
+
+            let key = self.name()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            self.skip_whitespace();
+            let quote = match self.next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            let value = self.read_until(&quote.to_string(), "attribute value")?;
+            element.attributes.push((key, self.unescape(&value)?));
+        }
+    }
+
+    fn content(&mut self, mut element: Element) -> Result<Element, GraphMlParseError> {
+        loop {
+            let mut text = String::new();
+            while let Some(c) = self.next_if(|c| c != '<') {
+                text.push(c);
+            }
+            if !text.is_empty() {
+                element.children.push(Content::Text(self.unescape(&text)?));
+            }
+            if self.next().is_none() {
+                return Err(element.error(&format!("unclosed <{}>", element.name)));
+            }
+
+            match self.markup()? {
+                Markup::Start(child, true) => element.children.push(Content::Element(child)),
+                Markup::Start(child, false) => {
+                    let child = self.content(child)?;
+                    element.children.push(Content::Element(child));
+                }
+                Markup::End(name) if name == element.name => return Ok(element),
+                Markup::End(name) => {
+                    return Err(
+                        self.error(&format!("expected </{}>, found </{}>", element.name, name))
+                    )
+                }
+                Markup::Text(text) => element.children.push(Content::Text(text)),
+                Markup::Skipped => {}
+            }
+        }
+    }
+
+    fn unescape(&self, text: &str) -> Result<String, GraphMlParseError> {
+        let mut unescaped = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find('&') {
+            unescaped.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find(';')
+                .ok_or_else(|| self.error("unterminated entity"))?;
+            let entity = &rest[start + 1..start + end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => match entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                }
+                .and_then(char::from_u32),
+            };
+            unescaped.push(c.ok_or_else(|| self.error(&format!("unknown entity &{};", entity)))?);
+            rest = &rest[start + end + 1..];
+        }
+        unescaped.push_str(rest);
+        Ok(unescaped)
+    }
+}
+
+struct Key {
+    name: String,
+    domain: String,
+    default: Option<String>,
+}
+
+impl<Ty: EdgeType> Graph<Attributes, Attributes, Ty> {
+    /// Parses a graph written in GraphML.
+    ///
+    /// Nodes and edges hold their `<data>` values under the `attr.name` of
+    /// their key, or the key id if it has no name, with the `<default>` of
+    /// every key they leave out. Data holding nested markup, such as yEd's
+    /// graphics, is skipped, as are hyperedges and nested graphs. The
+    /// `edgedefault` of the graph must match the edge type being parsed into.
+    /// The parsed graph allows self-loops if the input has any.
+    pub fn from_graphml(input: &str) -> Result<Self, GraphMlParseError> {
+        let root = Reader {
+            chars: input.chars().peekable(),
+            line: 1,
+        }
+        .document()?;
+        if root.name != "graphml" {
+            return Err(root.error(&format!("expected <graphml>, found <{}>", root.name)));
+        }
+
+        let mut keys = BTreeMap::new();
+        for key in root.elements("key") {
+            let id = key.required("id")?;
+            keys.insert(
+                id,
+                Key {
+                    name: key.attribute("attr.name").unwrap_or(id).to_string(),
+                    domain: key.attribute("for").unwrap_or("all").to_string(),
+                    default: key.elements("default").next().and_then(Element::text),
+                },
+            );
+        }
+        let attributes = |element: &Element| {
+            let mut attributes: Attributes = keys
+                .values()
+                .filter(|key| key.domain == element.name || key.domain == "all")
+                .filter_map(|key| Some((key.name.clone(), key.default.clone()?)))
+                .collect();
+            for data in element.elements("data") {
+                let key = data.required("key")?;
+                let name = keys.get(key).map_or(key, |key| key.name.as_str());
+                if let Some(value) = data.text() {
+                    attributes.insert(name.to_string(), value);
+                }
+            }
+            Ok(attributes)
+        };
+
+        let graph_element = root
+            .elements("graph")
+            .next()
+            .ok_or_else(|| root.error("missing <graph> element"))?;
+        let expected = if Ty::is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        match graph_element.attribute("edgedefault") {
+            Some(found) if found != expected => {
+                return Err(graph_element.error(&format!(
+                    "expected edgedefault=\"{}\", found \"{}\"",
+                    expected, found
+                )))
+            }
+            _ => {}
+        }
+
+        let mut graph = Graph::default();
+        let mut ids = BTreeMap::new();
+        for node in graph_element.elements("node") {
+            let id = node.required("id")?;
+            let idx = graph.add_node(attributes(node)?);
+            if ids.insert(id, idx).is_some() {
+                return Err(node.error(&format!("duplicate node id '{}'", id)));
+            }
+        }
+        for edge in graph_element.elements("edge") {
+            let node = |attribute| -> Result<NodeIndex, GraphMlParseError> {
+                let id = edge.required(attribute)?;
+                ids.get(id)
+                    .copied()
+                    .ok_or_else(|| edge.error(&format!("unknown node '{}'", id)))
+            };
+            let (source, target) = (node("source")?, node("target")?);
+            if source == target {
+                graph.allows_self_loops = true;
+            }
+            graph
+                .add_edge(source, target, attributes(edge)?)
+                .map_err(|err| edge.error(&err.to_string()))?;
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphMlParseError;
+    use crate::graphml::GraphMl;
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn parses_attributes() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <!-- exported from Gephi -->
+            <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+              <key id="label" for="node" attr.name="label" attr.type="string"/>
+              <key id="color" for="node" attr.name="color" attr.type="string">
+                <default>gray</default>
+              </key>
+              <key id="w" for="edge" attr.name="weight" attr.type="double"/>
+              <graph id="G" edgedefault="directed">
+                <node id="core">
+                  <data key="label">the &lt;core&gt;</data>
+                  <data key="color">red</data>
+                </node>
+                <node id="std"><data key="label"><![CDATA[a & b]]></data></node>
+                <node id="app"/>
+                <edge source="core" target="std"><data key="w">2.5</data></edge>
+                <edge source='app' target='std'/>
+              </graph>
+            </graphml>"#;
+
+        let graph = DiGraph::from_graphml(input).unwrap();
+        let attribute = |idx: usize, name| graph[idx].get(name).map(String::as_str);
+        assert_eq!(attribute(0, "label"), Some("the <core>"));
+        assert_eq!(attribute(0, "color"), Some("red"));
+        assert_eq!(attribute(1, "label"), Some("a & b"));
+        assert_eq!(attribute(1, "color"), Some("gray"));
+        assert_eq!(attribute(2, "label"), None);
+
+        let weight = |from, to| {
+            graph
+                .edge_weight(NodeIndex::new(from), NodeIndex::new(to))
+                .map(|attributes| attributes.get("weight").map(String::as_str))
+        };
+        assert_eq!(weight(0, 1), Some(Some("2.5")));
+        assert_eq!(weight(2, 1), Some(None));
+    }
+
+    #[test]
+    fn round_trips_export() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node("say \"hi\"");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b, 3).unwrap();
+
+        let graphml = GraphMl::new(&graph)
+            .node_labels()
+            .edge_weights()
+            .to_string();
+        let parsed = UnGraph::from_graphml(&graphml).unwrap();
+        assert_eq!(parsed[a]["label"], "say \"hi\"");
+        assert_eq!(parsed[b]["label"], "b");
+        assert_eq!(parsed.edge_weight(b, a).unwrap()["weight"], "3");
+    }
+
+    #[test]
+    fn round_trips_self_loops() {
+        let mut graph = DiGraph::new().allow_self_loops(true);
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, b, ()).unwrap();
+
+        let parsed = DiGraph::from_graphml(&GraphMl::new(&graph).to_string()).unwrap();
+        assert!(parsed.allows_self_loops());
+        assert_eq!(parsed.edge_count(), 2);
+        assert!(parsed.contains_edge(b, b));
+
+        let without =
+            DiGraph::from_graphml(&GraphMl::new(&DiGraph::<u8>::path_graph(2)).to_string());
+        assert!(!without.unwrap().allows_self_loops());
+    }
+
+    #[test]
+    fn reports_errors() {
+        let graphml = |body: &str| {
+            format!(
+                "<graphml>\n<graph edgedefault=\"directed\">{}</graph></graphml>",
+                body
+            )
+        };
+
+        assert_eq!(
+            UnGraph::from_graphml(&graphml("")).err(),
+            Some(GraphMlParseError(
+                "expected edgedefault=\"undirected\", found \"directed\" on line 2".to_string()
+            ))
+        );
+        assert_eq!(
+            DiGraph::from_graphml(&graphml(r#"<node id="a"/><edge source="a" target="b"/>"#)).err(),
+            Some(GraphMlParseError("unknown node 'b' on line 2".to_string()))
+        );
+        assert_eq!(
+            DiGraph::from_graphml(&graphml(r#"<node id="a"/><edge source="a" target="b"/>"#))
+                .unwrap_err()
+                .to_string(),
+            "invalid GraphML: unknown node 'b' on line 2"
+        );
+        assert_eq!(
+            DiGraph::from_graphml("<graphml>\n<graph>\n<node>").err(),
+            Some(GraphMlParseError("unclosed <node> on line 3".to_string()))
+        );
+        assert!(DiGraph::from_graphml(&graphml(r#"<node id="a"/><node id="a"/>"#)).is_err());
+        assert!(DiGraph::from_graphml("<graphml><graph></node></graphml>").is_err());
+        assert!(DiGraph::from_graphml("<graph/>").is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{BipartiteGraph, EdgeIndex, GraphError, LeftIndex, NodeIndex, RightIndex};
+
+#[derive(Debug, Clone)]
+struct Hyperedge<E> {
+    /// Sorted and without repeats.
+    nodes: Vec<NodeIndex>,
+    weight: E,
+}
+
+/// A graph whose edges each connect any number of nodes, to model relations
+/// between more than two things at once.
+///
+/// Every node keeps the edges it belongs to, so both the nodes of an edge
+/// and the edges of a node can be listed directly.
+#[derive(Debug, Clone)]
+pub struct Hypergraph<T, E = ()> {
+    nodes: Vec<T>,
+    edges: Vec<Hyperedge<E>>,
+    /// The edges of every node, in the order they were added.
+    memberships: Vec<Vec<EdgeIndex>>,
+}
+
+impl<T, E> Default for Hypergraph<T, E> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            memberships: Vec::new(),
+        }
+    }
+}
+
+impl<T, E> Hypergraph<T, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, weight: T) -> NodeIndex {
+        self.nodes.push(weight);
+        self.memberships.push(Vec::new());
+        NodeIndex::new(self.nodes.len() - 1)
+    }
+
+    /// Adds an edge connecting all of `nodes`, listed in any order. Repeated
+    /// nodes count once.
+    pub fn add_edge(
+        &mut self,
+        nodes: impl IntoIterator<Item = NodeIndex>,
+        weight: E,
+    ) -> Result<EdgeIndex, GraphError> {
+        let mut nodes: Vec<_> = nodes.into_iter().collect();
+        for &node in nodes.iter() {
+            if node.index() >= self.nodes.len() {
+                return Err(GraphError::NodeOutOfBounds {
+                    index: node,
+                    len: self.nodes.len(),
+                });
+            }
+        }
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        let idx = EdgeIndex::new(self.edges.len());
+        for node in nodes.iter() {
+            self.memberships[node.index()].push(idx);
+        }
+        self.edges.push(Hyperedge { nodes, weight });
+        Ok(idx)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn get_node(&self, idx: NodeIndex) -> Option<&T> {
+        self.nodes.get(idx.index())
+    }
+
+    pub fn get_node_mut(&mut self, idx: NodeIndex) -> Option<&mut T> {
+        self.nodes.get_mut(idx.index())
+    }
+
+    pub fn edge_weight(&self, idx: EdgeIndex) -> Option<&E> {
+        self.edges.get(idx.index()).map(|edge| &edge.weight)
+    }
+
+    /// The nodes of an edge, sorted by index.
+    pub fn edge_nodes(&self, idx: EdgeIndex) -> Option<&[NodeIndex]> {
+        self.edges
+            .get(idx.index())
+            .map(|edge| edge.nodes.as_slice())
+    }
+
+    /// The edges `idx` belongs to, in the order they were added.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn edges_of(&self, idx: NodeIndex) -> &[EdgeIndex] {
+        self.check_node(idx);
+        &self.memberships[idx.index()]
+    }
+
+    /// The number of edges `idx` belongs to.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn degree(&self, idx: NodeIndex) -> usize {
+        self.edges_of(idx).len()
+    }
+
+    /// The nodes sharing at least one edge with `idx`, sorted by index and
+    /// without `idx` itself.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors(&self, idx: NodeIndex) -> Vec<NodeIndex> {
+        let mut neighbors: Vec<_> = self
+            .edges_of(idx)
+            .iter()
+            .flat_map(|edge| self.edges[edge.index()].nodes.iter().copied())
+            .filter(|&node| node != idx)
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Visits the nodes reachable from `start` in breadth-first order, going
+    /// from a node to every other node of each of its edges. Every edge is
+    /// only expanded once.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn bfs(&self, start: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.check_node(start);
+        let mut discovered = vec![false; self.nodes.len()];
+        let mut expanded = vec![false; self.edges.len()];
+        let mut queue = VecDeque::from([start]);
+        discovered[start.index()] = true;
+        core::iter::from_fn(move || {
+            let node = queue.pop_front()?;
+            for &edge in self.memberships[node.index()].iter() {
+                if core::mem::replace(&mut expanded[edge.index()], true) {
+                    continue;
+                }
+                for &next in self.edges[edge.index()].nodes.iter() {
+                    if !core::mem::replace(&mut discovered[next.index()], true) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            Some(node)
+        })
+    }
+
+    /// Turns the graph into its incidence graph, a [`BipartiteGraph`] with
+    /// the nodes on the left, the edges on the right, and an edge from every
+    /// node to each edge it belongs to. Indices carry over on both sides.
+    pub fn into_incidence(self) -> BipartiteGraph<T, E> {
+        let mut incidence = BipartiteGraph::new();
+        for weight in self.nodes {
+            incidence.add_left(weight);
+        }
+        let mut members = Vec::with_capacity(self.edges.len());
+        for edge in self.edges {
+            incidence.add_right(edge.weight);
+            members.push(edge.nodes);
+        }
+        for (edge, nodes) in members.into_iter().enumerate() {
+            for node in nodes {
+                incidence
+                    .add_edge(LeftIndex::new(node.index()), RightIndex::new(edge), ())
+                    .expect("every member and edge is in the incidence graph");
+            }
+        }
+        incidence
+    }
+
+    fn check_node(&self, idx: NodeIndex) {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.nodes.len(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hypergraph;
+    use crate::{EdgeIndex, GraphError, LeftIndex, NodeIndex, RightIndex};
+
+    #[test]
+    fn meetings() {
+        let mut graph = Hypergraph::<&str, &str>::new();
+        let n: Vec<_> = ["ada", "bob", "cy", "dee", "eve"]
+            .into_iter()
+            .map(|name| graph.add_node(name))
+            .collect();
+        let standup = graph.add_edge([n[2], n[0], n[1], n[0]], "standup").unwrap();
+        let review = graph.add_edge([n[2], n[3]], "review").unwrap();
+
+        assert_eq!(graph.edge_nodes(standup), Some(&[n[0], n[1], n[2]][..]));
+        assert_eq!(graph.edges_of(n[2]), &[standup, review]);
+        assert_eq!(graph.degree(n[4]), 0);
+        assert_eq!(graph.neighbors(n[2]), vec![n[0], n[1], n[3]]);
+        assert_eq!(
+            graph.bfs(n[1]).collect::<Vec<_>>(),
+            vec![n[1], n[0], n[2], n[3]]
+        );
+        assert_eq!(
+            graph.add_edge([n[0], NodeIndex::new(9)], "lunch"),
+            Err(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(9),
+                len: 5
+            })
+        );
+        assert_eq!(graph.edge_count(), 2);
+
+        let incidence = graph.into_incidence();
+        assert_eq!((incidence.left_count(), incidence.right_count()), (5, 2));
+        assert_eq!(incidence.edge_count(), 5);
+        assert_eq!(incidence.right(RightIndex::new(1)), Some(&"review"));
+        assert_eq!(
+            incidence
+                .neighbors_of_left(LeftIndex::new(2))
+                .collect::<Vec<_>>(),
+            vec![RightIndex::new(0), RightIndex::new(1)]
+        );
+        assert_eq!(review, EdgeIndex::new(1));
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt::{self, Display, Formatter};
+
+/// The position of a node in a [`Graph`](crate::Graph).
+///
+/// Like the position of an item in a `Vec`, it shifts down by one for every
+/// node removed before it.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct NodeIndex(usize);
+
+/// The position of an edge in the order [`Graph`](crate::Graph) stores its
+/// edges: grouped by source node, then in insertion order.
+///
+/// Adding or removing edges shifts the index of every edge after it.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct EdgeIndex(usize);
+
+impl NodeIndex {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl EdgeIndex {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl From<usize> for NodeIndex {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<NodeIndex> for usize {
+    fn from(index: NodeIndex) -> Self {
+        index.0
+    }
+}
+
+impl From<usize> for EdgeIndex {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<EdgeIndex> for usize {
+    fn from(index: EdgeIndex) -> Self {
+        index.0
+    }
+}
+
+impl Display for NodeIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for EdgeIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
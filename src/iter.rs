@@ -0,0 +1,176 @@
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use crate::{EdgeData, EdgeType, Graph, GraphError, NodeIndex};
+
+impl<T, E, Ty: EdgeType> FromIterator<T> for Graph<T, E, Ty> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut graph = Self::default();
+        graph.extend(iter);
+        graph
+    }
+}
+
+impl<T, E, Ty: EdgeType> Extend<T> for Graph<T, E, Ty> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve_nodes(iter.size_hint().0);
+        for node in iter {
+            self.add_node(node);
+        }
+    }
+}
+
+/// Yields the node payloads in index order, dropping the edges.
+impl<T, E, Ty: EdgeType> IntoIterator for Graph<T, E, Ty> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'a, T, E, Ty: EdgeType> IntoIterator for &'a Graph<T, E, Ty> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.iter()
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Same as [`Graph::into_iter`].
+    pub fn into_nodes(self) -> alloc::vec::IntoIter<T> {
+        self.into_iter()
+    }
+
+    /// Yields `(source, target, weight)` for every edge, in [`EdgeIndex`]
+    /// order, dropping the nodes.
+    ///
+    /// [`EdgeIndex`]: crate::EdgeIndex
+    pub fn into_edges(self) -> impl Iterator<Item = (NodeIndex, NodeIndex, E)> {
+        self.edges
+            .into_iter()
+            .enumerate()
+            .flat_map(|(source, edge_data)| {
+                edge_data
+                    .into_iter()
+                    .map(move |edge| (NodeIndex::new(source), edge.target, edge.weight))
+            })
+    }
+
+    /// Splits the graph into its node payloads and the adjacency list of every
+    /// node, which share indices.
+    pub fn into_parts(self) -> (Vec<T>, Vec<EdgeData<E>>) {
+        (self.nodes, self.edges)
+    }
+}
+
+impl<T, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Adds an edge with the default weight for every pair of indices. This is
+    /// a method rather than an `Extend` impl, as that would conflict with
+    /// `Extend<T>` for graphs whose nodes are pairs.
+    ///
+    /// Stops at the first edge that cannot be added.
+    pub fn extend_with_edges<N: Into<NodeIndex>>(
+        &mut self,
+        edges: impl IntoIterator<Item = (N, N)>,
+    ) -> Result<(), GraphError> {
+        for (from, to) in edges {
+            self.add_edge(from.into(), to.into(), E::default())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Default, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Builds a graph from a list of edges, adding default nodes up to the
+    /// largest index mentioned.
+    pub fn from_edges<N: Into<NodeIndex>>(
+        edges: impl IntoIterator<Item = (N, N)>,
+    ) -> Result<Self, GraphError> {
+        let mut graph = Self::default();
+        for (from, to) in edges {
+            let (from, to) = (from.into(), to.into());
+            while graph.nodes.len() <= from.index().max(to.index()) {
+                graph.add_node(T::default());
+            }
+            graph.add_edge(from, to, E::default())?;
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, Edge, Graph, UnGraph};
+
+    #[test]
+    fn collecting_nodes() {
+        let mut graph: Graph<char> = "abc".chars().collect();
+        graph.extend(['d', 'e']);
+        graph.extend_with_edges([(0, 4), (3, 1)]).unwrap();
+
+        assert_eq!(graph.to_string(), "a -> e\nd -> b\n");
+        assert!(graph.extend_with_edges([(1, 5)]).is_err());
+    }
+
+    #[test]
+    fn building_from_edges() {
+        let graph = DiGraph::<u8>::from_edges([(0, 1), (1, 2), (4, 0)]).unwrap();
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 3);
+        let edges: Vec<_> = graph
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect();
+        assert_eq!(edges, vec![(0, 1), (1, 2), (4, 0)]);
+
+        let undirected = UnGraph::<u8>::from_edges([(0, 1)]).unwrap();
+        assert!(undirected.contains_edge(1.into(), 0.into()));
+        assert!(DiGraph::<u8>::from_edges([(2, 2)]).is_err());
+    }
+
+    #[test]
+    fn consuming_graphs() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(c, a, 1).unwrap();
+        graph.add_edge(a, b, 2).unwrap();
+
+        assert_eq!(
+            (&graph).into_iter().map(String::as_str).collect::<String>(),
+            "abc"
+        );
+        assert_eq!(
+            graph.clone().into_iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(graph.clone().into_nodes().len(), 3);
+        assert_eq!(
+            graph.clone().into_edges().collect::<Vec<_>>(),
+            vec![(a, b, 2), (c, a, 1)]
+        );
+
+        let (nodes, edges) = graph.into_parts();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(
+            edges,
+            vec![
+                vec![Edge {
+                    target: b,
+                    weight: 2
+                }],
+                vec![],
+                vec![Edge {
+                    target: a,
+                    weight: 1
+                }],
+            ]
+        );
+    }
+}
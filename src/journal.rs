@@ -0,0 +1,416 @@
+//! Recording the changes made to a graph, to undo, redo and replay them.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+use crate::{Directed, Edge, EdgeData, EdgeType, Graph, GraphError, NodeIndex};
+
+/// A change to a graph, as recorded by a [`JournaledGraph`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Change<T, E> {
+    AddNode(T),
+    RemoveNode(NodeIndex),
+    AddEdge(NodeIndex, NodeIndex, E),
+    RemoveEdge(NodeIndex, NodeIndex),
+}
+
+/// What it takes to put the graph back the way it was before a change,
+/// down to the order of every adjacency list.
+#[derive(Debug, Clone)]
+enum Inverse<T, E> {
+    PopNode,
+    InsertNode {
+        index: NodeIndex,
+        node: T,
+        outgoing: EdgeData<E>,
+        /// The edges from other nodes, with their position in the list of
+        /// their source, in the order they are put back.
+        incoming: Vec<(NodeIndex, usize, E)>,
+    },
+    RemoveEdgeAt(NodeIndex, usize),
+    InsertEdgeAt(NodeIndex, usize, Edge<E>),
+}
+
+#[derive(Debug, Clone)]
+struct Record<T, E> {
+    change: Change<T, E>,
+    inverse: Inverse<T, E>,
+}
+
+/// A graph that records every node and edge it adds or removes, so the
+/// changes can be undone, redone and replayed onto another graph.
+///
+/// Payloads and weights are cloned into the journal, which keeps the changes
+/// that were undone until a new change is made. Changes made to the
+/// underlying graph directly are not recorded, so it is only available
+/// read-only.
+pub struct JournaledGraph<T, E = (), Ty = Directed> {
+    graph: Graph<T, E, Ty>,
+    done: Vec<Record<T, E>>,
+    undone: Vec<Change<T, E>>,
+}
+
+impl<T: Clone, E: Clone, Ty: EdgeType + Clone> Clone for JournaledGraph<T, E, Ty> {
+    fn clone(&self) -> Self {
+        Self {
+            graph: self.graph.clone(),
+            done: self.done.clone(),
+            undone: self.undone.clone(),
+        }
+    }
+}
+
+impl<T: Debug, E: Debug, Ty: EdgeType> Debug for JournaledGraph<T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JournaledGraph")
+            .field("graph", &self.graph)
+            .field("history", &self.history().collect::<Vec<_>>())
+            .field("undone", &self.undone)
+            .finish()
+    }
+}
+
+impl<T, E, Ty: EdgeType> Default for JournaledGraph<T, E, Ty> {
+    fn default() -> Self {
+        Self::new(Graph::default())
+    }
+}
+
+impl<T, E, Ty: EdgeType> From<Graph<T, E, Ty>> for JournaledGraph<T, E, Ty> {
+    fn from(graph: Graph<T, E, Ty>) -> Self {
+        Self::new(graph)
+    }
+}
+
+impl<T, E, Ty: EdgeType> JournaledGraph<T, E, Ty> {
+    /// Starts an empty journal for `graph`, whose current state can't be
+    /// undone.
+    pub fn new(graph: Graph<T, E, Ty>) -> Self {
+        Self {
+            graph,
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    pub fn graph(&self) -> &Graph<T, E, Ty> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<T, E, Ty> {
+        self.graph
+    }
+
+    /// Iterates over the changes that can be undone, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = &Change<T, E>> {
+        self.done.iter().map(|record| &record.change)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Forgets every recorded change, keeping the graph as it is.
+    pub fn clear_history(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+    }
+
+    /// Reverts the last change, or returns `false` if there is none.
+    pub fn undo(&mut self) -> bool {
+        let Some(Record { change, inverse }) = self.done.pop() else {
+            return false;
+        };
+
+        match inverse {
+            Inverse::PopNode => {
+                self.graph.pop();
+            }
+            Inverse::InsertNode {
+                index,
+                node,
+                outgoing,
+                incoming,
+            } => {
+                self.graph.insert_node_at(index, node);
+                for (position, edge) in outgoing.into_iter().enumerate() {
+                    self.graph.insert_edge_at(index, position, edge);
+                }
+                for (source, position, weight) in incoming {
+                    let edge = Edge {
+                        target: index,
+                        weight,
+                    };
+                    self.graph.insert_edge_at(source, position, edge);
+                }
+            }
+            Inverse::RemoveEdgeAt(source, position) => {
+                self.graph.remove_edge_at(source, position);
+            }
+            Inverse::InsertEdgeAt(source, position, edge) => {
+                self.graph.insert_edge_at(source, position, edge);
+            }
+        }
+        self.undone.push(change);
+        true
+    }
+}
+
+impl<T: Clone, E: Clone, Ty: EdgeType> JournaledGraph<T, E, Ty> {
+    pub fn add_node(&mut self, node: T) -> NodeIndex {
+        self.record(Change::AddNode(node))
+            .expect("adding a node can't fail");
+        NodeIndex::new(self.graph.node_count() - 1)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        self.record(Change::AddEdge(from, to, weight))
+    }
+
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> Result<E, GraphError> {
+        self.record(Change::RemoveEdge(from, to))?;
+        match &self.done.last().expect("the change was recorded").inverse {
+            Inverse::InsertEdgeAt(_, _, edge) => Ok(edge.weight.clone()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Removes the node like [`Graph::remove_node`], shifting the index of
+    /// every node after it down by one.
+    pub fn remove_node(&mut self, idx: NodeIndex) -> Result<T, GraphError> {
+        self.record(Change::RemoveNode(idx))?;
+        match &self.done.last().expect("the change was recorded").inverse {
+            Inverse::InsertNode { node, .. } => Ok(node.clone()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Makes the last undone change again, or returns `false` if there is
+    /// none.
+    pub fn redo(&mut self) -> bool {
+        let Some(change) = self.undone.pop() else {
+            return false;
+        };
+
+        let record = apply(&mut self.graph, change).expect("undone changes apply again");
+        self.done.push(record);
+        true
+    }
+
+    /// Makes the changes in [`JournaledGraph::history`] to `onto`, stopping at
+    /// the first one that fails.
+    pub fn replay(&self, onto: &mut Graph<T, E, Ty>) -> Result<(), GraphError> {
+        for change in self.history() {
+            apply(onto, change.clone())?;
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, change: Change<T, E>) -> Result<(), GraphError> {
+        let record = apply(&mut self.graph, change)?;
+        self.done.push(record);
+        self.undone.clear();
+        Ok(())
+    }
+}
+
+fn apply<T: Clone, E: Clone, Ty: EdgeType>(
+    graph: &mut Graph<T, E, Ty>,
+    change: Change<T, E>,
+) -> Result<Record<T, E>, GraphError> {
+    let inverse = match &change {
+        Change::AddNode(node) => {
+            graph.add_node(node.clone());
+            Inverse::PopNode
+        }
+        Change::AddEdge(from, to, weight) => {
+            let position = graph.edges.get(from.index()).map_or(0, Vec::len);
+            graph.add_edge(*from, *to, weight.clone())?;
+            Inverse::RemoveEdgeAt(*from, position)
+        }
+        Change::RemoveEdge(from, to) => {
+            graph.check_node(*from)?;
+            graph.check_node(*to)?;
+            let (source, position) =
+                graph
+                    .edge_position(*from, *to)
+                    .ok_or(GraphError::EdgeNotFound {
+                        from: *from,
+                        to: *to,
+                    })?;
+            let edge = graph.remove_edge_at(source, position);
+            Inverse::InsertEdgeAt(source, position, edge)
+        }
+        Change::RemoveNode(idx) => {
+            graph.check_node(*idx)?;
+            let mut incoming = Vec::new();
+            for source in 0..graph.nodes.len() {
+                if source == idx.index() {
+                    continue;
+                }
+                // Taken from the back so the positions stay valid, and put
+                // back from the front.
+                let source = NodeIndex::new(source);
+                for position in (0..graph.edges[source.index()].len()).rev() {
+                    if graph.edges[source.index()][position].target == *idx {
+                        let edge = graph.remove_edge_at(source, position);
+                        incoming.push((source, position, edge.weight));
+                    }
+                }
+            }
+            incoming.reverse();
+
+            let mut outgoing = Vec::new();
+            while !graph.edges[idx.index()].is_empty() {
+                let last = graph.edges[idx.index()].len() - 1;
+                outgoing.push(graph.remove_edge_at(*idx, last));
+            }
+            outgoing.reverse();
+
+            Inverse::InsertNode {
+                index: *idx,
+                node: graph.remove_node(*idx),
+                outgoing,
+                incoming,
+            }
+        }
+    };
+    Ok(Record { change, inverse })
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    fn remove_edge_at(&mut self, source: NodeIndex, position: usize) -> Edge<E> {
+        let edge = self.edges[source.index()].remove(position);
+        self.edge_count -= 1;
+        self.edge_offsets.shrink(source.index(), 1);
+        self.in_degrees[edge.target.index()] -= 1;
+        self.unindex_incoming(source, edge.target);
+        edge
+    }
+
+    fn insert_edge_at(&mut self, source: NodeIndex, position: usize, edge: Edge<E>) {
+        let target = edge.target;
+        self.edges[source.index()].insert(position, edge);
+        self.edge_count += 1;
+        self.edge_offsets.grow(source.index(), 1);
+        self.in_degrees[target.index()] += 1;
+        self.index_incoming(source, target);
+    }
+
+    /// Inserts a node without edges at `index`, shifting the index of every
+    /// node from there on up by one.
+    fn insert_node_at(&mut self, index: NodeIndex, node: T) {
+        for edge_data in self.edges.iter_mut() {
+            for edge in edge_data.iter_mut() {
+                if edge.target >= index {
+                    edge.target = NodeIndex::new(edge.target.index() + 1);
+                }
+            }
+        }
+        self.nodes.insert(index.index(), node);
+        self.edges.insert(index.index(), EdgeData::new());
+        self.rebuild_edge_offsets();
+        self.in_degrees.insert(index.index(), 0);
+        if self.incoming.is_some() {
+            self.rebuild_incoming();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, JournaledGraph};
+    use crate::{DiGraph, GraphError, NodeIndex, UnGraph};
+
+    #[test]
+    fn undo_restores_every_state() {
+        let mut journal = JournaledGraph::new(DiGraph::new().allow_self_loops(true));
+        let mut states = vec![journal.graph().clone()];
+        let a = journal.add_node('a');
+        states.push(journal.graph().clone());
+        let b = journal.add_node('b');
+        states.push(journal.graph().clone());
+        let c = journal.add_node('c');
+        states.push(journal.graph().clone());
+        for (from, to, weight) in [(a, b, 1), (c, b, 2), (b, b, 3), (c, b, 4), (b, c, 5)] {
+            journal.add_edge(from, to, weight).unwrap();
+            states.push(journal.graph().clone());
+        }
+        assert_eq!(journal.remove_edge(c, b), Ok(2));
+        states.push(journal.graph().clone());
+        assert_eq!(journal.remove_node(b), Ok('b'));
+        states.push(journal.graph().clone());
+        assert_eq!(journal.graph().edge_count(), 0);
+
+        let end = states.pop().unwrap();
+        while let Some(state) = states.pop() {
+            assert!(journal.undo());
+            assert!(*journal.graph() == state);
+        }
+        assert!(!journal.undo());
+
+        while journal.redo() {}
+        assert!(*journal.graph() == end);
+    }
+
+    #[test]
+    fn new_changes_drop_redo() {
+        let mut journal = JournaledGraph::new(UnGraph::new_undirected().track_incoming(true));
+        let a = journal.add_node(1);
+        let b = journal.add_node(2);
+        journal.add_edge(b, a, ()).unwrap();
+        assert_eq!(
+            journal.add_edge(a, NodeIndex::new(5), ()),
+            Err(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(5),
+                len: 2
+            })
+        );
+        assert_eq!(journal.history().count(), 3);
+
+        journal.remove_edge(a, b).unwrap();
+        journal.undo();
+        assert!(journal.can_redo());
+        journal.add_node(3);
+        assert!(!journal.can_redo());
+        journal.remove_node(a).unwrap();
+        journal.undo();
+        assert!(journal.graph().tracks_incoming());
+        assert_eq!(
+            journal.graph().neighbors_incoming(a).collect::<Vec<_>>(),
+            vec![b]
+        );
+    }
+
+    #[test]
+    fn replay_onto_another_graph() {
+        let mut journal = JournaledGraph::default();
+        let a = journal.add_node("a");
+        let b = journal.add_node("b");
+        journal.add_edge(a, b, 1.5).unwrap();
+        journal.remove_node(a).unwrap();
+        assert_eq!(journal.history().last(), Some(&Change::RemoveNode(a)));
+
+        let mut copy = DiGraph::new();
+        journal.replay(&mut copy).unwrap();
+        assert!(copy == *journal.graph());
+
+        let mut base = DiGraph::new();
+        base.add_node("z");
+        journal.replay(&mut base).unwrap();
+        assert_eq!(base.nodes().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(base.edge_count(), 0);
+
+        journal.clear_history();
+        assert!(!journal.can_undo());
+    }
+}
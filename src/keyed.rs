@@ -0,0 +1,251 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::{Directed, EdgeType, Graph, GraphError, NodeIndex, Undirected};
+
+/// A graph whose nodes are addressed by a unique key, such as a name, instead
+/// of by index.
+///
+/// Adding an edge between keys that are not in the graph yet adds nodes for
+/// them with the default payload. The underlying [`Graph`] is available
+/// through [`KeyedGraph::graph`] to run algorithms, whose results can be
+/// mapped back with [`KeyedGraph::key`].
+#[derive(Clone)]
+pub struct KeyedGraph<K, T = (), E = (), Ty = Directed> {
+    graph: Graph<T, E, Ty>,
+    indices: HashMap<K, NodeIndex>,
+    keys: Vec<K>,
+}
+
+impl<K, T, E, Ty: EdgeType> Default for KeyedGraph<K, T, E, Ty> {
+    fn default() -> Self {
+        Self {
+            graph: Graph::default(),
+            indices: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<K: Debug, T: Debug, E: Debug, Ty: EdgeType> Debug for KeyedGraph<K, T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyedGraph")
+            .field("keys", &self.keys)
+            .field("graph", &self.graph)
+            .finish()
+    }
+}
+
+impl<K, T, E> KeyedGraph<K, T, E, Directed> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, T, E> KeyedGraph<K, T, E, Undirected> {
+    pub fn new_undirected() -> Self {
+        Self::default()
+    }
+}
+
+impl<K: Hash + Eq + Clone, T, E, Ty: EdgeType> KeyedGraph<K, T, E, Ty> {
+    pub fn graph(&self) -> &Graph<T, E, Ty> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<T, E, Ty> {
+        self.graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Adds a node under `key`, or replaces the payload of the node already
+    /// there, returning the old payload.
+    pub fn insert_node(&mut self, key: K, node: T) -> (NodeIndex, Option<T>) {
+        match self.indices.get(&key) {
+            Some(&idx) => (idx, Some(std::mem::replace(&mut self.graph[idx], node))),
+            None => {
+                let idx = self.graph.add_node(node);
+                self.indices.insert(key.clone(), idx);
+                self.keys.push(key);
+                (idx, None)
+            }
+        }
+    }
+
+    pub fn node_index<Q>(&self, key: &Q) -> Option<NodeIndex>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(key).copied()
+    }
+
+    pub fn key(&self, idx: NodeIndex) -> Option<&K> {
+        self.keys.get(idx.index())
+    }
+
+    pub fn contains_node<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.contains_key(key)
+    }
+
+    pub fn get_node<Q>(&self, key: &Q) -> Option<&T>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.graph.get_node(self.node_index(key)?)
+    }
+
+    pub fn get_node_mut<Q>(&mut self, key: &Q) -> Option<&mut T>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.node_index(key)?;
+        self.graph.get_node_mut(idx)
+    }
+
+    /// Iterates over the keys in index order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.keys.iter()
+    }
+
+    /// Adds an edge between the nodes under `from` and `to`, first adding
+    /// nodes with the default payload for keys that are not in the graph.
+    pub fn add_edge(&mut self, from: K, to: K, weight: E) -> Result<(), GraphError>
+    where
+        T: Default,
+    {
+        let from = self.node_or_default(from);
+        let to = self.node_or_default(to);
+        self.graph.add_edge(from, to, weight)
+    }
+
+    fn node_or_default(&mut self, key: K) -> NodeIndex
+    where
+        T: Default,
+    {
+        match self.node_index(&key) {
+            Some(idx) => idx,
+            None => self.insert_node(key, T::default()).0,
+        }
+    }
+
+    pub fn edge_weight<Q>(&self, from: &Q, to: &Q) -> Option<&E>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.graph
+            .edge_weight(self.node_index(from)?, self.node_index(to)?)
+    }
+
+    pub fn contains_edge<Q>(&self, from: &Q, to: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.edge_weight(from, to).is_some()
+    }
+
+    pub fn remove_edge<Q>(&mut self, from: &Q, to: &Q) -> Option<E>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (from, to) = (self.node_index(from)?, self.node_index(to)?);
+        self.graph.remove_edge(from, to).ok()
+    }
+
+    /// Iterates over the keys of the nodes the node under `key` has an edge
+    /// to, like [`Graph::neighbors`].
+    pub fn neighbors<Q>(&self, key: &Q) -> impl Iterator<Item = &K> + '_
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.node_index(key);
+        idx.into_iter()
+            .flat_map(|idx| self.graph.neighbors(idx))
+            .map(|idx| &self.keys[idx.index()])
+    }
+
+    /// Removes the node under `key` and every edge touching it, with
+    /// [`Graph::swap_remove_node`], so only the last node changes index.
+    pub fn remove_node<Q>(&mut self, key: &Q) -> Option<T>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.indices.remove(key)?;
+        let (node, moved) = self.graph.swap_remove_node(idx);
+        self.keys.swap_remove(idx.index());
+        if moved.is_some() {
+            self.indices.insert(self.keys[idx.index()].clone(), idx);
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedGraph;
+    use crate::Undirected;
+
+    #[test]
+    fn edges_add_missing_nodes() {
+        let mut graph = KeyedGraph::<&str, u32, f64>::new();
+        graph.add_edge("a", "b", 1.5).unwrap();
+        graph.add_edge("b", "c", 2.0).unwrap();
+        graph.add_edge("a", "c", 3.0).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_weight("a", "c"), Some(&3.0));
+        assert!(!graph.contains_edge("c", "a"));
+        assert_eq!(graph.neighbors("a").collect::<Vec<_>>(), vec![&"b", &"c"]);
+        assert_eq!(graph.neighbors("d").count(), 0);
+        assert!(graph.add_edge("d", "d", 0.0).is_err());
+        assert!(graph.contains_node("d"));
+
+        *graph.get_node_mut("b").unwrap() += 7;
+        assert_eq!(
+            graph.insert_node("b", 1),
+            (graph.node_index("b").unwrap(), Some(7))
+        );
+        assert_eq!(graph.get_node("b"), Some(&1));
+        assert_eq!(graph.remove_edge("a", "b"), Some(1.5));
+    }
+
+    #[test]
+    fn owned_keys_and_removal() {
+        let mut graph = KeyedGraph::<String, (), (), Undirected>::new_undirected();
+        graph
+            .add_edge("x".to_string(), "y".to_string(), ())
+            .unwrap();
+        graph
+            .add_edge("y".to_string(), "z".to_string(), ())
+            .unwrap();
+        let z = graph.node_index("z").unwrap();
+        assert_eq!(graph.key(z).map(String::as_str), Some("z"));
+
+        assert_eq!(graph.remove_node("x"), Some(()));
+        assert_eq!(graph.remove_node("x"), None);
+        assert_eq!(graph.keys().collect::<Vec<_>>(), vec!["z", "y"]);
+        assert_eq!(graph.node_index("z"), Some(0.into()));
+        assert!(graph.contains_edge("z", "y"));
+        assert_eq!(graph.graph().edge_count(), 1);
+    }
+}
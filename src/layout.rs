@@ -0,0 +1,185 @@
+//! Positions for drawing a graph in two dimensions.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph};
+
+/// Computes a force-directed layout with the Fruchterman-Reingold algorithm:
+/// every pair of nodes pushes apart, every edge pulls its endpoints
+/// together, and the nodes move less and less each iteration until they
+/// settle.
+///
+/// Nodes start out evenly spaced on a circle, so the result only depends on
+/// the graph and the settings. Positions are `(x, y)` pairs within a
+/// rectangle from `(0, 0)` to the configured size, which is 1 by 1 unless
+/// set otherwise. Edge direction, weights and self-loops are ignored, and
+/// parallel edges pull harder.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForceLayout {
+    width: f32,
+    height: f32,
+    iterations: usize,
+}
+
+impl Default for ForceLayout {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            height: 1.0,
+            iterations: 100,
+        }
+    }
+}
+
+impl ForceLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of the rectangle the nodes are placed in.
+    ///
+    /// Panics unless both are positive.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        if !(width > 0.0 && height > 0.0) {
+            panic!(
+                "size out of range: size is {} by {}, but both must be positive",
+                width, height
+            );
+        }
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets how many times every node is moved, 100 by default.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Returns the position of every node, by node index.
+    pub fn compute<T, E, Ty: EdgeType>(&self, graph: &Graph<T, E, Ty>) -> Vec<(f32, f32)> {
+        let n = graph.node_count();
+        let (width, height) = (self.width, self.height);
+        let center = (width / 2.0, height / 2.0);
+        let radius = width.min(height) * 0.45;
+        let mut positions: Vec<_> = (0..n)
+            .map(|node| {
+                let angle = core::f32::consts::TAU * node as f32 / n as f32;
+                if n == 1 {
+                    center
+                } else {
+                    (
+                        center.0 + radius * angle.cos(),
+                        center.1 + radius * angle.sin(),
+                    )
+                }
+            })
+            .collect();
+        let edges: Vec<_> = graph
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .filter(|(source, target)| source != target)
+            .collect();
+
+        // The distance at which the pull of an edge and the push between
+        // its endpoints cancel out.
+        let ideal = (width * height / n.max(1) as f32).sqrt();
+        let mut displacements = vec![(0.0, 0.0); n];
+        for iteration in 0..self.iterations {
+            let temperature =
+                width.max(height) / 10.0 * (1.0 - iteration as f32 / self.iterations as f32);
+            displacements.fill((0.0, 0.0));
+
+            for a in 0..n {
+                for b in a + 1..n {
+                    let (dx, dy, distance) = offset(positions[a], positions[b]);
+                    let push = ideal * ideal / distance;
+                    let (fx, fy) = (dx / distance * push, dy / distance * push);
+                    displacements[a].0 += fx;
+                    displacements[a].1 += fy;
+                    displacements[b].0 -= fx;
+                    displacements[b].1 -= fy;
+                }
+            }
+            for &(a, b) in edges.iter() {
+                let (dx, dy, distance) = offset(positions[a], positions[b]);
+                let pull = distance * distance / ideal;
+                let (fx, fy) = (dx / distance * pull, dy / distance * pull);
+                displacements[a].0 -= fx;
+                displacements[a].1 -= fy;
+                displacements[b].0 += fx;
+                displacements[b].1 += fy;
+            }
+
+            for (position, &(dx, dy)) in positions.iter_mut().zip(displacements.iter()) {
+                let length = (dx * dx + dy * dy).sqrt();
+                if length > 0.0 {
+                    let step = length.min(temperature) / length;
+                    position.0 = (position.0 + dx * step).clamp(0.0, width);
+                    position.1 = (position.1 + dy * step).clamp(0.0, height);
+                }
+            }
+        }
+        positions
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Lays the graph out with the default [`ForceLayout`].
+    pub fn force_layout(&self) -> Vec<(f32, f32)> {
+        ForceLayout::new().compute(self)
+    }
+}
+
+/// The vector from `b` to `a` and its length, kept away from zero so that
+/// nodes on top of each other still push apart.
+fn offset(a: (f32, f32), b: (f32, f32)) -> (f32, f32, f32) {
+    let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance < 1e-4 {
+        (1e-4, 0.0, 1e-4)
+    } else {
+        (dx, dy, distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForceLayout;
+    use crate::{DiGraph, UnGraph};
+
+    fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+        ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn connected_nodes_end_up_closer() {
+        // Two triangles joined by a single edge, 2-3.
+        let graph =
+            UnGraph::<()>::from_edges([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+                .unwrap();
+        let layout = ForceLayout::new().size(200.0, 100.0);
+        let positions = layout.compute(&graph);
+
+        assert_eq!(positions.len(), 6);
+        assert!(positions
+            .iter()
+            .all(|&(x, y)| (0.0..=200.0).contains(&x) && (0.0..=100.0).contains(&y)));
+        assert!(distance(positions[0], positions[1]) < distance(positions[0], positions[4]));
+        assert!(distance(positions[2], positions[3]) < distance(positions[0], positions[5]));
+        assert_eq!(layout.compute(&graph), positions);
+    }
+
+    #[test]
+    fn small_graphs() {
+        assert!(DiGraph::<()>::default().force_layout().is_empty());
+        assert_eq!(
+            DiGraph::<()>::path_graph(1).force_layout(),
+            vec![(0.5, 0.5)]
+        );
+        let positions = DiGraph::<()>::path_graph(2).force_layout();
+        assert!(distance(positions[0], positions[1]) > 0.1);
+    }
+}
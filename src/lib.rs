@@ -1,122 +1,893 @@
-use std::fmt::{self, Display, Formatter};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-pub type EdgeData = Vec<usize>;
+extern crate alloc;
 
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+use offsets::EdgeOffsets;
+
+pub mod acyclic;
+pub mod algo;
+#[cfg(any(feature = "quickcheck", feature = "proptest"))]
+mod arbitrary;
+mod ascii;
+pub mod bipartite;
+pub mod csr;
+pub mod diff;
+pub mod dot;
+#[cfg(feature = "std")]
+pub mod edge_list;
+pub mod format;
+pub mod generators;
+pub mod graphml;
+pub mod hypergraph;
+mod index;
+mod iter;
+pub mod journal;
+#[cfg(feature = "std")]
+pub mod keyed;
+#[cfg(feature = "std")]
+pub mod layout;
+mod macros;
+pub mod matrix;
+pub mod mermaid;
+pub mod nested;
+#[cfg(feature = "serde")]
+mod node_link;
+pub mod observe;
+mod offsets;
+mod ops;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "petgraph")]
+mod petgraph_impl;
+#[cfg(feature = "rand")]
+pub mod random_walk;
+pub mod reversed;
+#[cfg(feature = "rand")]
+mod sampling;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod snapshot;
+pub mod stable;
+#[cfg(feature = "std")]
+pub mod sync;
+pub mod temporal;
+pub mod traits;
+pub mod unionfind;
+pub mod visit;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+pub use acyclic::{AcyclicError, AcyclicGraph};
+pub use bipartite::{BipartiteGraph, LeftIndex, RightIndex};
+pub use csr::CsrGraph;
+pub use diff::GraphDiff;
+pub use dot::{Dot, DotParseError};
+#[cfg(feature = "std")]
+pub use edge_list::{EdgeList, EdgeListError};
+pub use format::GraphFormatter;
+pub use graphml::{GraphMl, GraphMlParseError};
+pub use hypergraph::Hypergraph;
+pub use index::{EdgeIndex, NodeIndex};
+pub use journal::{Change, JournaledGraph};
+#[cfg(feature = "std")]
+pub use keyed::KeyedGraph;
+#[cfg(feature = "std")]
+pub use layout::ForceLayout;
+pub use matrix::AdjacencyMatrix;
+pub use mermaid::Mermaid;
+pub use nested::{ClusterId, NestedGraph};
+pub use observe::{GraphEvent, ObservedGraph, ObserverId};
+#[cfg(feature = "rand")]
+pub use random_walk::RandomWalk;
+pub use reversed::Reversed;
+pub use stable::{NodeId, StableGraph};
+#[cfg(feature = "std")]
+pub use sync::SyncGraph;
+pub use temporal::TemporalGraph;
+pub use traits::{GraphBase, Neighbors, NodeCount};
+pub use unionfind::UnionFind;
+pub use visit::{
+    depth_first_search, Bfs, BfsWalker, Dfs, DfsEvent, DfsWalker, TopoWalker, Walker, WalkerIter,
+};
+#[cfg(feature = "wasm-bindgen")]
+pub use wasm::JsGraph;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edge<E> {
+    pub target: NodeIndex,
+    pub weight: E,
+}
+
+pub type EdgeData<E> = Vec<Edge<E>>;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EdgeRef<'a, E> {
+    index: EdgeIndex,
+    source: NodeIndex,
+    target: NodeIndex,
+    weight: &'a E,
+}
+
+impl<'a, E> EdgeRef<'a, E> {
+    pub fn index(&self) -> EdgeIndex {
+        self.index
+    }
+
+    pub fn source(&self) -> NodeIndex {
+        self.source
+    }
+
+    pub fn target(&self) -> NodeIndex {
+        self.target
+    }
+
+    pub fn weight(&self) -> &'a E {
+        self.weight
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GraphError {
+    NodeOutOfBounds {
+        index: NodeIndex,
+        len: usize,
+    },
+    /// The node was removed from a [`StableGraph`], or never existed.
+    MissingNode(NodeId),
+    SelfLoop(NodeIndex),
+    /// Like [`GraphError::SelfLoop`], for a node of a [`StableGraph`].
+    StableSelfLoop(NodeId),
+    EdgeNotFound {
+        from: NodeIndex,
+        to: NodeIndex,
+    },
+    DuplicateEdge {
+        from: NodeIndex,
+        to: NodeIndex,
+    },
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::NodeOutOfBounds { index, len } => write!(
+                f,
+                "node index out of bounds: index is {}, but len is {}",
+                index, len
+            ),
+            GraphError::MissingNode(id) => write!(
+                f,
+                "node is not in the graph: index is {}, generation is {}",
+                id.index(),
+                id.generation()
+            ),
+            GraphError::SelfLoop(index) => write!(f, "node {} cannot point to itself", index),
+            GraphError::StableSelfLoop(id) => write!(
+                f,
+                "node cannot point to itself: index is {}, generation is {}",
+                id.index(),
+                id.generation()
+            ),
+            GraphError::EdgeNotFound { from, to } => {
+                write!(f, "no edge between nodes: from is {}, to is {}", from, to)
+            }
+            GraphError::DuplicateEdge { from, to } => write!(
+                f,
+                "edge already exists between nodes: from is {}, to is {}",
+                from, to
+            ),
+        }
+    }
+}
+
+impl core::error::Error for GraphError {}
+
+/// Holds the nodes of a cycle in order, each one pointing to the next and the
+/// last one pointing back to the first.
 #[derive(Debug, PartialEq)]
-pub struct EdgeAdditionError(pub String);
+pub struct CycleError(pub Vec<NodeIndex>);
 
+/// Holds the nodes of a cycle whose edge weights add up to less than zero, in
+/// the same order as [`CycleError`].
 #[derive(Debug, PartialEq)]
-pub struct EdgeGetError(pub String);
+pub struct NegativeCycleError(pub Vec<NodeIndex>);
+
+pub trait EdgeType {
+    fn is_directed() -> bool;
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Directed {}
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Undirected {}
+
+impl EdgeType for Directed {
+    fn is_directed() -> bool {
+        true
+    }
+}
+
+impl EdgeType for Undirected {
+    fn is_directed() -> bool {
+        false
+    }
+}
+
+pub type DiGraph<T, E = ()> = Graph<T, E, Directed>;
+pub type UnGraph<T, E = ()> = Graph<T, E, Undirected>;
+
+/// In undirected graphs every edge is stored once, in the `EdgeData` of the
+/// node passed as `from` to [`Graph::add_edge`], but is reported from both ends.
+///
+/// A graph holds no shared or interior mutable state, so it is `Send` and
+/// `Sync` whenever its payloads are. See [`SyncGraph`] to share one between
+/// threads while it keeps changing.
 #[derive(PartialEq, Clone)]
-pub struct Graph<T> {
+pub struct Graph<T, E = (), Ty = Directed> {
     nodes: Vec<T>,
-    edges: Vec<EdgeData>,
+    edges: Vec<EdgeData<E>>,
+    edge_count: usize,
+    edge_offsets: EdgeOffsets,
+    edge_reserve: EdgeReserve,
+    /// The number of stored edges pointing at each node.
+    in_degrees: Vec<usize>,
+    /// The distinct sources of the edges pointing at each node, in ascending
+    /// order, if enabled with [`Graph::track_incoming`].
+    incoming: Option<Vec<Vec<NodeIndex>>>,
+    allows_self_loops: bool,
+    edge_type: PhantomData<Ty>,
 }
 
-impl<T> Default for Graph<T> {
+impl<T, E, Ty: EdgeType> Default for Graph<T, E, Ty> {
     fn default() -> Self {
-        Self::new()
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            edge_count: 0,
+            edge_offsets: EdgeOffsets::default(),
+            edge_reserve: EdgeReserve::default(),
+            in_degrees: Vec::new(),
+            incoming: None,
+            allows_self_loops: false,
+            edge_type: PhantomData,
+        }
     }
 }
 
-impl<T> Graph<T> {
-    pub fn new() -> Self {
+/// Room for the edges of nodes that are yet to be added, which have no
+/// adjacency list to reserve it in. Only a capacity, so it never makes two
+/// graphs unequal.
+#[derive(Debug, Clone, Copy, Default)]
+struct EdgeReserve {
+    /// How many more of the nodes added get a list with room for `per_node`
+    /// edges.
+    nodes: usize,
+    per_node: usize,
+}
+
+impl PartialEq for EdgeReserve {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl EdgeReserve {
+    fn next_list<E>(&mut self) -> EdgeData<E> {
+        if self.nodes == 0 {
+            return EdgeData::new();
+        }
+        self.nodes -= 1;
+        EdgeData::with_capacity(self.per_node)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Reserves room for `nodes` nodes and `edges` edges. Edges are stored in
+    /// an adjacency list per node, so the room for them is spread evenly over
+    /// the lists of the first `nodes` nodes added.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
         Self {
-            nodes: Vec::new(),
-            edges: Vec::new(),
+            nodes: Vec::with_capacity(nodes),
+            edges: Vec::with_capacity(nodes),
+            edge_count: 0,
+            edge_offsets: EdgeOffsets::with_capacity(nodes),
+            edge_reserve: EdgeReserve {
+                nodes,
+                per_node: if nodes == 0 { 0 } else { edges.div_ceil(nodes) },
+            },
+            in_degrees: Vec::with_capacity(nodes),
+            incoming: None,
+            allows_self_loops: false,
+            edge_type: PhantomData,
+        }
+    }
+
+    pub fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+        self.edges.reserve(additional);
+        self.edge_offsets.reserve(additional);
+        self.in_degrees.reserve(additional);
+    }
+
+    /// Edges are stored in an adjacency list per node, so capacity for them is
+    /// reserved per node as well.
+    ///
+    /// Panics if `node` is out of bounds.
+    pub fn reserve_edges(&mut self, node: NodeIndex, additional: usize) {
+        if node.index() >= self.nodes.len() {
+            panic!(
+                "index node out of range: node is {}, but len is {}",
+                node,
+                self.nodes.len(),
+            );
+        }
+
+        self.edges[node.index()].reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        self.edge_offsets.shrink_to_fit();
+        self.edge_reserve = EdgeReserve::default();
+        self.in_degrees.shrink_to_fit();
+        for sources in self.incoming.iter_mut().flatten() {
+            sources.shrink_to_fit();
+        }
+        for edge_data in self.edges.iter_mut() {
+            edge_data.shrink_to_fit();
         }
     }
+}
 
-    pub fn add_node(&mut self, node: T) -> usize {
-        let index = self.nodes.len();
+impl<T, E> Graph<T, E, Directed> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, E> Graph<T, E, Undirected> {
+    pub fn new_undirected() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Self-loops are rejected by [`Graph::add_edge`] unless allowed here.
+    /// Forbidding them again keeps the ones already in the graph.
+    pub fn allow_self_loops(mut self, allow: bool) -> Self {
+        self.allows_self_loops = allow;
+        self
+    }
+
+    pub fn allows_self_loops(&self) -> bool {
+        self.allows_self_loops
+    }
+
+    /// Keeps an index of incoming edges, so that [`Graph::get_edges_to`] and
+    /// [`Graph::neighbors_incoming`] only look at the adjacency lists of the
+    /// node's sources instead of every list in the graph. The index is kept in
+    /// sync as edges are added and removed, at some cost to both.
+    pub fn track_incoming(mut self, enabled: bool) -> Self {
+        self.incoming = None;
+        if enabled {
+            self.rebuild_incoming();
+        }
+        self
+    }
+
+    pub fn tracks_incoming(&self) -> bool {
+        self.incoming.is_some()
+    }
+
+    pub fn add_node(&mut self, node: T) -> NodeIndex {
+        let index = NodeIndex::new(self.nodes.len());
         self.nodes.push(node);
-        self.edges.push(EdgeData::new());
+        self.edges.push(self.edge_reserve.next_list());
+        self.edge_offsets.push();
+        self.in_degrees.push(0);
+        if let Some(incoming) = &mut self.incoming {
+            incoming.push(Vec::new());
+        }
         index
     }
 
-    pub fn get_node(&self, node_idx: usize) -> Option<&T> {
-        self.nodes.get(node_idx)
+    pub fn get_node(&self, node_idx: NodeIndex) -> Option<&T> {
+        self.nodes.get(node_idx.index())
+    }
+
+    pub fn get_node_mut(&mut self, node_idx: NodeIndex) -> Option<&mut T> {
+        self.nodes.get_mut(node_idx.index())
     }
 
     pub fn nodes(&self) -> impl Iterator<Item = &T> {
         self.nodes.iter()
     }
 
-    pub fn add_edge(&mut self, from: usize, to: usize) -> Result<(), EdgeAdditionError> {
-        if to >= self.nodes.len() {
-            return Err(EdgeAdditionError(format!(
-                "parameter to out of range, to: {}, length: {}",
-                to,
-                self.edges.len()
-            )));
+    pub fn nodes_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.nodes.iter_mut()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Iterates over every edge once, in [`EdgeIndex`] order. Undirected edges
+    /// are reported in the direction they were added.
+    pub fn edges(&self) -> impl Iterator<Item = EdgeRef<'_, E>> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(|(source, edge_data)| {
+                edge_data
+                    .iter()
+                    .map(move |edge| (NodeIndex::new(source), edge))
+            })
+            .enumerate()
+            .map(|(index, (source, edge))| EdgeRef {
+                index: EdgeIndex::new(index),
+                source,
+                target: edge.target,
+                weight: &edge.weight,
+            })
+    }
+
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+
+        if from == to && !self.allows_self_loops {
+            return Err(GraphError::SelfLoop(from));
+        }
+
+        self.edges[from.index()].push(Edge { target: to, weight });
+        self.edge_count += 1;
+        self.edge_offsets.grow(from.index(), 1);
+        self.in_degrees[to.index()] += 1;
+        self.index_incoming(from, to);
+        Ok(())
+    }
+
+    pub fn edge_weight(&self, from: NodeIndex, to: NodeIndex) -> Option<&E> {
+        let find = |from: NodeIndex, to: NodeIndex| {
+            self.edges
+                .get(from.index())?
+                .iter()
+                .find(|edge| edge.target == to)
+                .map(|edge| &edge.weight)
+        };
+
+        if Ty::is_directed() {
+            find(from, to)
+        } else {
+            find(from, to).or_else(|| find(to, from))
+        }
+    }
+
+    /// Like [`Graph::edge_weight`], for the same edge.
+    pub fn edge_weight_mut(&mut self, from: NodeIndex, to: NodeIndex) -> Option<&mut E> {
+        let (source, position) = self.edge_position(from, to)?;
+        Some(&mut self.edges[source.index()][position].weight)
+    }
+
+    /// Iterates over the weight of every edge, in [`EdgeIndex`] order.
+    pub fn edge_weights_mut(&mut self) -> impl Iterator<Item = &mut E> {
+        self.edges.iter_mut().flatten().map(|edge| &mut edge.weight)
+    }
+
+    pub fn has_self_loop(&self, idx: NodeIndex) -> bool {
+        self.edges
+            .get(idx.index())
+            .is_some_and(|edge_data| edge_data.iter().any(|edge| edge.target == idx))
+    }
+
+    pub fn self_loops(&self) -> impl Iterator<Item = EdgeRef<'_, E>> {
+        self.edges().filter(|edge| edge.source == edge.target)
+    }
+
+    /// With [`Graph::track_incoming`] enabled this is a binary search over
+    /// the sources of `to` (and of `from`, for undirected graphs). Otherwise
+    /// the adjacency list of `from` (and of `to`) is scanned, so the cost
+    /// grows with their degree.
+    pub fn contains_edge(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        let Some(incoming) = &self.incoming else {
+            return self.edge_weight(from, to).is_some();
+        };
+
+        let indexed = |from: NodeIndex, to: NodeIndex| {
+            incoming
+                .get(to.index())
+                .is_some_and(|sources| sources.binary_search(&from).is_ok())
+        };
+        indexed(from, to) || (!Ty::is_directed() && indexed(to, from))
+    }
+
+    /// Returns the index of the first edge from `from` to `to`, or for
+    /// undirected graphs the first edge between them in either direction.
+    ///
+    /// The edge is looked for in the adjacency list of its source, as in
+    /// [`Graph::edge_weight`], after a [`Graph::contains_edge`] check. Its
+    /// index is then found in time logarithmic in the number of nodes.
+    pub fn find_edge(&self, from: NodeIndex, to: NodeIndex) -> Option<EdgeIndex> {
+        if self.incoming.is_some() && !self.contains_edge(from, to) {
+            return None;
+        }
+
+        let (source, position) = self.edge_position(from, to)?;
+        Some(EdgeIndex::new(
+            self.edge_offsets.offset(source.index()) + position,
+        ))
+    }
+
+    /// Like [`Graph::add_edge`], but fails with [`GraphError::DuplicateEdge`]
+    /// instead of adding a parallel edge, for callers that want simple-graph
+    /// semantics.
+    pub fn add_edge_unique(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        if self.contains_edge(from, to) {
+            return Err(GraphError::DuplicateEdge { from, to });
+        }
+
+        self.add_edge(from, to, weight)
+    }
+
+    /// Replaces the weight of the first edge between the nodes, returning the
+    /// old weight, or adds a new edge if there is none.
+    pub fn update_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<Option<E>, GraphError> {
+        match self.edge_position(from, to) {
+            Some((source, position)) => Ok(Some(core::mem::replace(
+                &mut self.edges[source.index()][position].weight,
+                weight,
+            ))),
+            None => self.add_edge(from, to, weight).map(|()| None),
+        }
+    }
+
+    pub fn get_weighted_edges_from(
+        &self,
+        idx: NodeIndex,
+    ) -> Result<Vec<(NodeIndex, &E)>, GraphError> {
+        self.check_node(idx)?;
+
+        Ok(self
+            .weighted_successors(idx.index())
+            .map(|(target, weight)| (NodeIndex::new(target), weight))
+            .collect())
+    }
+
+    pub fn get_edges_from(&self, idx: NodeIndex) -> Result<Vec<NodeIndex>, GraphError> {
+        Ok(self
+            .get_weighted_edges_from(idx)?
+            .into_iter()
+            .map(|(target, _)| target)
+            .collect())
+    }
+
+    pub fn get_edges_to(&self, idx: NodeIndex) -> Result<Vec<NodeIndex>, GraphError> {
+        if !Ty::is_directed() {
+            return self.get_edges_from(idx);
+        }
+
+        self.check_node(idx)?;
+
+        Ok(self
+            .incoming_edges(idx.index())
+            .map(|(source, _)| NodeIndex::new(source))
+            .collect())
+    }
+
+    pub fn get_edges(&self, idx: NodeIndex) -> Result<Vec<NodeIndex>, GraphError> {
+        self.check_node(idx)?;
+
+        if !Ty::is_directed() {
+            return self.get_edges_from(idx);
+        }
+
+        let mut result = Vec::new();
+        result.append(&mut self.get_edges_from(idx)?);
+        result.append(&mut self.get_edges_to(idx)?);
+        Ok(result)
+    }
+
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> Result<E, GraphError> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+
+        match self.edge_position(from, to) {
+            Some((source, pos)) => {
+                let edge = self.edges[source.index()].remove(pos);
+                self.edge_count -= 1;
+                self.edge_offsets.shrink(source.index(), 1);
+                self.in_degrees[edge.target.index()] -= 1;
+                self.unindex_incoming(source, edge.target);
+                Ok(edge.weight)
+            }
+            None => Err(GraphError::EdgeNotFound { from, to }),
+        }
+    }
+
+    pub fn remove_all_edges_between(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+    ) -> Result<usize, GraphError> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+
+        let mut removed = 0;
+        let mut remove = |from: NodeIndex, to: NodeIndex| {
+            let edge_data = &mut self.edges[from.index()];
+            let len = edge_data.len();
+            edge_data.retain(|edge| edge.target != to);
+            self.edge_offsets
+                .shrink(from.index(), len - edge_data.len());
+            self.in_degrees[to.index()] -= len - edge_data.len();
+            removed += len - edge_data.len();
+        };
+        remove(from, to);
+        if !Ty::is_directed() && from != to {
+            remove(to, from);
+            self.unindex_incoming(to, from);
         }
+        self.unindex_incoming(from, to);
 
-        if from >= self.nodes.len() {
-            return Err(EdgeAdditionError(format!(
-                "parameter from out of range, from: {}, length: {}",
-                from,
-                self.edges.len()
-            )));
+        self.edge_count -= removed;
+        Ok(removed)
+    }
+
+    /// Finds the first edge between the nodes, as its source and its position
+    /// in the source's `EdgeData`.
+    fn edge_position(&self, from: NodeIndex, to: NodeIndex) -> Option<(NodeIndex, usize)> {
+        let position = |from: NodeIndex, to: NodeIndex| {
+            self.edges
+                .get(from.index())?
+                .iter()
+                .position(|edge| edge.target == to)
+                .map(|pos| (from, pos))
+        };
+
+        if Ty::is_directed() {
+            position(from, to)
+        } else {
+            position(from, to).or_else(|| position(to, from))
         }
+    }
 
-        if from == to {
-            return Err(EdgeAdditionError("Node cannot point to itself".to_string()));
+    fn check_node(&self, idx: NodeIndex) -> Result<(), GraphError> {
+        if idx.index() >= self.nodes.len() {
+            return Err(GraphError::NodeOutOfBounds {
+                index: idx,
+                len: self.nodes.len(),
+            });
         }
 
-        self.edges[from].push(to);
         Ok(())
     }
 
-    pub fn get_edges_from(&self, idx: usize) -> Result<EdgeData, EdgeGetError> {
-        if idx >= self.nodes.len() {
-            return Err(EdgeGetError(format!(
-                "index is out of bounds: index: {}, length: {}",
+    /// Iterates over the nodes `idx` has an edge to, borrowing the adjacency
+    /// list instead of cloning it. In undirected graphs this includes every
+    /// node connected to `idx`.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors(&self, idx: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.successors(idx.index()).map(NodeIndex::new)
+    }
+
+    /// Iterates over the nodes that have an edge to `idx`. In undirected graphs
+    /// this is the same as [`Graph::neighbors`].
+    ///
+    /// Unlike `neighbors` this scans every adjacency list in directed graphs,
+    /// unless incoming edges are tracked with [`Graph::track_incoming`].
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors_incoming(&self, idx: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
                 idx,
-                self.edges.len()
-            )));
+                self.nodes.len(),
+            );
+        }
+
+        let incoming = self.incoming_edges(idx.index()).map(|(source, _)| source);
+        let outgoing = if Ty::is_directed() {
+            None
+        } else {
+            Some(
+                self.edges[idx.index()]
+                    .iter()
+                    .map(|edge| edge.target.index()),
+            )
+        };
+
+        outgoing
+            .into_iter()
+            .flatten()
+            .chain(incoming)
+            .map(NodeIndex::new)
+    }
+
+    /// For undirected graphs this is the same as [`Graph::degree`].
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn out_degree(&self, idx: NodeIndex) -> usize {
+        if Ty::is_directed() {
+            self.check_degree_index(idx);
+            self.edges[idx.index()].len()
+        } else {
+            self.degree(idx)
         }
+    }
 
-        Ok(self.edges[idx].clone())
+    /// Runs in constant time, as in-degrees are kept up to date as edges are
+    /// added and removed. For undirected graphs this is the same as
+    /// [`Graph::degree`].
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn in_degree(&self, idx: NodeIndex) -> usize {
+        if Ty::is_directed() {
+            self.check_degree_index(idx);
+            self.in_degrees[idx.index()]
+        } else {
+            self.degree(idx)
+        }
     }
 
-    pub fn get_edges_to(&self, idx: usize) -> Result<EdgeData, EdgeGetError> {
-        if idx >= self.nodes.len() {
-            return Err(EdgeGetError(format!(
-                "index is out of bounds: index: {}, length: {}",
+    /// The number of edges touching the node, where a self-loop counts twice.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn degree(&self, idx: NodeIndex) -> usize {
+        self.check_degree_index(idx);
+        self.edges[idx.index()].len() + self.in_degrees[idx.index()]
+    }
+
+    /// Returns how many nodes there are of every [`Graph::degree`], indexed by
+    /// degree, up to the largest one.
+    pub fn degree_distribution(&self) -> Vec<usize> {
+        let mut distribution = Vec::new();
+        for idx in (0..self.nodes.len()).map(NodeIndex::new) {
+            let degree = self.degree(idx);
+            if degree >= distribution.len() {
+                distribution.resize(degree + 1, 0);
+            }
+            distribution[degree] += 1;
+        }
+        distribution
+    }
+
+    fn check_degree_index(&self, idx: NodeIndex) {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
                 idx,
-                self.edges.len()
-            )));
+                self.nodes.len(),
+            );
         }
+    }
+
+    pub fn bfs(&self, start: NodeIndex) -> Bfs<'_, T, E, Ty> {
+        Bfs::new(self, start)
+    }
 
-        let mut edges = Vec::new();
-        for (edge_idx, edge_data) in self.edges.iter().enumerate() {
-            for &edge in edge_data.iter() {
-                if edge == idx {
-                    edges.push(edge_idx)
+    pub fn dfs(&self, start: NodeIndex) -> Dfs<'_, T, E, Ty> {
+        Dfs::new(self, start)
+    }
+
+    pub(crate) fn successors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.weighted_successors(idx).map(|(target, _)| target)
+    }
+
+    pub(crate) fn weighted_successors(&self, idx: usize) -> impl Iterator<Item = (usize, &E)> {
+        let incoming = if Ty::is_directed() {
+            None
+        } else {
+            Some(self.incoming_edges(idx))
+        };
+
+        self.edges[idx]
+            .iter()
+            .map(|edge| (edge.target.index(), &edge.weight))
+            .chain(incoming.into_iter().flatten())
+    }
+
+    fn incoming_edges(&self, idx: usize) -> impl Iterator<Item = (usize, &E)> {
+        let indexed = self
+            .incoming
+            .as_ref()
+            .map(|incoming| incoming[idx].iter().map(|source| source.index()));
+        let scanned = match indexed {
+            Some(_) => None,
+            None => Some(0..self.edges.len()),
+        };
+
+        indexed
+            .into_iter()
+            .flatten()
+            .chain(scanned.into_iter().flatten())
+            .flat_map(move |source| {
+                self.edges[source]
+                    .iter()
+                    .filter(move |edge| edge.target.index() == idx)
+                    .map(move |edge| (source, &edge.weight))
+            })
+    }
+
+    pub(crate) fn rebuild_edge_offsets(&mut self) {
+        self.edge_offsets = EdgeOffsets::from_lens(self.edges.iter().map(Vec::len));
+    }
+
+    pub(crate) fn rebuild_incoming(&mut self) {
+        let mut incoming = vec![Vec::new(); self.edges.len()];
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            for edge in edge_data {
+                let sources: &mut Vec<NodeIndex> = &mut incoming[edge.target.index()];
+                if sources.last() != Some(&NodeIndex::new(source)) {
+                    sources.push(NodeIndex::new(source));
                 }
             }
         }
-        Ok(edges)
+        self.incoming = Some(incoming);
     }
 
-    pub fn get_edges(&self, idx: usize) -> Result<EdgeData, EdgeGetError> {
-        if idx >= self.nodes.len() {
-            return Err(EdgeGetError(format!(
-                "index is out of bounds: index: {}, length: {}",
-                idx,
-                self.edges.len()
-            )));
+    pub(crate) fn index_incoming(&mut self, from: NodeIndex, to: NodeIndex) {
+        if let Some(incoming) = &mut self.incoming {
+            let sources = &mut incoming[to.index()];
+            if let Err(pos) = sources.binary_search(&from) {
+                sources.insert(pos, from);
+            }
         }
+    }
 
-        let mut result = Vec::new();
-        result.append(&mut self.get_edges_from(idx)?);
-        result.append(&mut self.get_edges_to(idx)?);
-        Ok(result)
+    /// Drops `from` from the sources of `to` once no edge between them is
+    /// left.
+    fn unindex_incoming(&mut self, from: NodeIndex, to: NodeIndex) {
+        let still_connected = self.edges[from.index()]
+            .iter()
+            .any(|edge| edge.target == to);
+        if let (Some(incoming), false) = (&mut self.incoming, still_connected) {
+            let sources = &mut incoming[to.index()];
+            if let Ok(pos) = sources.binary_search(&from) {
+                sources.remove(pos);
+            }
+        }
     }
 
-    pub fn remove_node(&mut self, idx: usize) -> T {
-        if idx >= self.nodes.len() {
+    pub fn remove_node(&mut self, idx: NodeIndex) -> T {
+        if idx.index() >= self.nodes.len() {
             panic!(
                 "index index out of range: index is {}, but len is {}",
                 idx,
@@ -124,15 +895,112 @@ impl<T> Graph<T> {
             );
         }
 
-        self.edges.remove(idx);
+        let removed = self.edges.remove(idx.index());
+        self.edge_count -= removed.len();
+        for edge in removed {
+            self.in_degrees[edge.target.index()] -= 1;
+        }
+        self.in_degrees.remove(idx.index());
         for edge_data in self.edges.iter_mut() {
-            *edge_data = edge_data
+            let len = edge_data.len();
+            edge_data.retain(|edge| edge.target != idx);
+            self.edge_count -= len - edge_data.len();
+            for edge in edge_data.iter_mut() {
+                if edge.target > idx {
+                    edge.target = NodeIndex::new(edge.target.index() - 1);
+                }
+            }
+        }
+        self.rebuild_edge_offsets();
+        if self.incoming.is_some() {
+            self.rebuild_incoming();
+        }
+        self.nodes.remove(idx.index())
+    }
+
+    /// Removes a node by moving the last node into its place, so that no other
+    /// node changes index. Returns the payload along with the old index of
+    /// the moved node, which is now `idx`, or `None` if `idx` was the last
+    /// node.
+    ///
+    /// Only the adjacency lists that point at the two nodes are touched when
+    /// [`Graph::track_incoming`] is enabled. Otherwise every list is scanned,
+    /// but nothing is shifted.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn swap_remove_node(&mut self, idx: NodeIndex) -> (T, Option<NodeIndex>) {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.nodes.len(),
+            );
+        }
+
+        let last = NodeIndex::new(self.nodes.len() - 1);
+        let mut sources: Vec<NodeIndex> = match &self.incoming {
+            Some(incoming) => incoming[idx.index()]
                 .iter()
-                .filter_map(|&edge| if edge != idx { Some(edge) } else { None })
-                .map(|edge| if edge > idx { edge - 1 } else { edge })
-                .collect();
+                .chain(incoming[last.index()].iter())
+                .copied()
+                .collect(),
+            None => (0..self.nodes.len()).map(NodeIndex::new).collect(),
+        };
+        sources.sort_unstable();
+        sources.dedup();
+
+        if let Some(incoming) = &mut self.incoming {
+            for edge in self.edges[idx.index()].iter() {
+                let sources = &mut incoming[edge.target.index()];
+                if let Ok(pos) = sources.binary_search(&idx) {
+                    sources.remove(pos);
+                }
+            }
+            if last != idx {
+                for edge in self.edges[last.index()].iter() {
+                    if edge.target == idx {
+                        continue;
+                    }
+                    let sources = &mut incoming[edge.target.index()];
+                    if let Ok(pos) = sources.binary_search(&last) {
+                        sources.remove(pos);
+                        if let Err(pos) = sources.binary_search(&idx) {
+                            sources.insert(pos, idx);
+                        }
+                    }
+                }
+            }
+        }
+
+        // The edges out of `idx` are dropped below, with their original
+        // targets, so that the in-degree of `last` is fixed up before it moves.
+        for source in sources {
+            if source == idx {
+                continue;
+            }
+            let edge_data = &mut self.edges[source.index()];
+            let len = edge_data.len();
+            edge_data.retain(|edge| edge.target != idx);
+            self.edge_count -= len - edge_data.len();
+            for edge in edge_data.iter_mut() {
+                if edge.target == last {
+                    edge.target = idx;
+                }
+            }
+        }
+        for edge in self.edges[idx.index()].iter() {
+            self.in_degrees[edge.target.index()] -= 1;
+        }
+        self.edge_count -= self.edges[idx.index()].len();
+
+        self.edges.swap_remove(idx.index());
+        self.rebuild_edge_offsets();
+        self.in_degrees.swap_remove(idx.index());
+        if let Some(incoming) = &mut self.incoming {
+            incoming.swap_remove(idx.index());
         }
-        self.nodes.remove(idx)
+        let node = self.nodes.swap_remove(idx.index());
+        (node, (last != idx).then_some(last))
     }
 
     pub fn pop(&mut self) -> Option<T> {
@@ -140,25 +1008,84 @@ impl<T> Graph<T> {
             return None;
         }
 
-        Some(self.remove_node(self.nodes.len() - 1))
+        Some(self.remove_node(NodeIndex::new(self.nodes.len() - 1)))
     }
 }
 
-impl<T: Display> Display for Graph<T> {
+impl<T, E, Ty: EdgeType> Index<NodeIndex> for Graph<T, E, Ty> {
+    type Output = T;
+
+    fn index(&self, index: NodeIndex) -> &T {
+        &self.nodes[index.index()]
+    }
+}
+
+impl<T, E, Ty: EdgeType> IndexMut<NodeIndex> for Graph<T, E, Ty> {
+    fn index_mut(&mut self, index: NodeIndex) -> &mut T {
+        &mut self.nodes[index.index()]
+    }
+}
+
+impl<T, E, Ty: EdgeType> Index<usize> for Graph<T, E, Ty> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.nodes[index]
+    }
+}
+
+impl<T, E, Ty: EdgeType> IndexMut<usize> for Graph<T, E, Ty> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.nodes[index]
+    }
+}
+
+/// Writes every node on a line of its own, with its index, its payload and
+/// the targets and weights of its edges:
+///
+/// ```text
+/// DiGraph {
+///     0: "a" -> [1: 2.5]
+///     1: "b" -> []
+/// }
+/// ```
+impl<T: Debug, E: Debug, Ty: EdgeType> Debug for Graph<T, E, Ty> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for (node, targets) in self.nodes.iter().zip(self.edges.iter()) {
-            for target in targets.iter() {
-                writeln!(f, "{} -> {}", node, self.nodes[*target])?;
+        let (kind, arrow) = if Ty::is_directed() {
+            ("DiGraph", "->")
+        } else {
+            ("UnGraph", "--")
+        };
+
+        if self.nodes.is_empty() {
+            return write!(f, "{} {{}}", kind);
+        }
+        writeln!(f, "{} {{", kind)?;
+        for (idx, (node, edge_data)) in self.nodes.iter().zip(self.edges.iter()).enumerate() {
+            write!(f, "    {}: {:?} {} [", idx, node, arrow)?;
+            for (position, edge) in edge_data.iter().enumerate() {
+                if position > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: {:?}", edge.target, edge.weight)?;
             }
+            writeln!(f, "]")?;
         }
+        write!(f, "}}")
+    }
+}
 
-        Ok(())
+impl<T: Display, E, Ty: EdgeType> Display for Graph<T, E, Ty> {
+    /// Writes one `source -> target` line per edge, or `source -- target` for
+    /// undirected graphs. Use [`Graph::formatter`] to change the format.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.formatter().fmt(f)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Graph;
+    use super::{EdgeIndex, Graph, GraphError, NodeIndex, UnGraph};
 
     fn get_test_graph_without_edges() -> Graph<i32> {
         let mut graph = Graph::new();
@@ -173,20 +1100,63 @@ mod tests {
 
     fn get_test_graph_with_edges() -> Graph<i32> {
         let mut graph = get_test_graph_without_edges();
-        graph.add_edge(1, 2).unwrap();
-        graph.add_edge(2, 1).unwrap();
-        graph.add_edge(0, 3).unwrap();
+        graph.add_edge(n(1), n(2), ()).unwrap();
+        graph.add_edge(n(2), n(1), ()).unwrap();
+        graph.add_edge(n(0), n(3), ()).unwrap();
 
         graph
     }
 
+    fn n(index: usize) -> NodeIndex {
+        NodeIndex::new(index)
+    }
+
     #[test]
     fn edge_connection() {
         let mut graph = get_test_graph_without_edges();
-        assert!(graph.add_edge(0, 1).is_ok());
-        assert!(graph.add_edge(0, 2).is_ok());
-        assert!(graph.add_edge(2, 3).is_ok());
-        assert!(graph.add_edge(12, 0).is_err());
+        assert!(graph.add_edge(n(0), n(1), ()).is_ok());
+        assert!(graph.add_edge(n(0), n(2), ()).is_ok());
+        assert!(graph.add_edge(n(2), n(3), ()).is_ok());
+        assert!(graph.add_edge(n(12), n(0), ()).is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let mut graph = get_test_graph_with_edges();
+
+        let err = graph.add_edge(n(0), n(9), ()).unwrap_err();
+        assert_eq!(
+            err,
+            GraphError::NodeOutOfBounds {
+                index: n(9),
+                len: 4
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "node index out of bounds: index is 9, but len is 4"
+        );
+        assert_eq!(
+            graph.add_edge(n(1), n(1), ()),
+            Err(GraphError::SelfLoop(n(1)))
+        );
+        assert_eq!(
+            graph.remove_edge(n(3), n(0)),
+            Err(GraphError::EdgeNotFound {
+                from: n(3),
+                to: n(0)
+            })
+        );
+        assert_eq!(
+            graph.get_edges_to(n(4)),
+            Err(GraphError::NodeOutOfBounds {
+                index: n(4),
+                len: 4
+            })
+        );
+
+        let boxed: Box<dyn core::error::Error> = Box::new(GraphError::SelfLoop(n(0)));
+        assert_eq!(boxed.to_string(), "node 0 cannot point to itself");
     }
 
     #[test]
@@ -197,13 +1167,33 @@ mod tests {
         assert_eq!(graph.to_string(), graph_str);
     }
 
+    #[test]
+    fn debug_graph() {
+        let mut graph = get_test_graph_with_edges();
+        graph.add_edge(n(1), n(3), ()).unwrap();
+        assert_eq!(
+            format!("{:?}", graph),
+            "DiGraph {\n    0: 5 -> [3: ()]\n    1: 1 -> [2: (), 3: ()]\n    2: 12 -> [1: ()]\n    3: 100 -> []\n}"
+        );
+
+        let mut undirected = UnGraph::new_undirected();
+        let a = undirected.add_node("a");
+        let b = undirected.add_node("b");
+        undirected.add_edge(b, a, 2.5).unwrap();
+        assert_eq!(
+            format!("{:?}", undirected),
+            "UnGraph {\n    0: \"a\" -- []\n    1: \"b\" -- [0: 2.5]\n}"
+        );
+        assert_eq!(format!("{:?}", Graph::<i32>::new()), "DiGraph {}");
+    }
+
     #[test]
     fn node_removal() {
         let mut graph = get_test_graph_with_edges();
 
-        assert_eq!(graph.remove_node(1), 1);
-        assert_eq!(graph.remove_node(1), 12);
-        assert!(std::panic::catch_unwind(|| graph.clone().remove_node(6)).is_err());
+        assert_eq!(graph.remove_node(n(1)), 1);
+        assert_eq!(graph.remove_node(n(1)), 12);
+        assert!(std::panic::catch_unwind(|| graph.clone().remove_node(n(6))).is_err());
         assert_eq!(graph.to_string(), "5 -> 100\n");
         assert_eq!(graph.pop(), Some(100));
         assert_eq!(graph.nodes().collect::<Vec<_>>(), vec![&5]);
@@ -216,13 +1206,402 @@ mod tests {
         let graph_with_edges = get_test_graph_with_edges();
         let graph_without_edges = get_test_graph_without_edges();
 
-        assert_eq!(graph_with_edges.get_edges_from(1), Ok(vec![2]));
-        assert_eq!(graph_with_edges.get_edges_to(1), Ok(vec![2]));
-        assert_eq!(graph_with_edges.get_edges(1), Ok(vec![2, 2]));
-        assert_eq!(graph_with_edges.get_edges_from(3), Ok(vec![]));
+        assert_eq!(graph_with_edges.get_edges_from(n(1)), Ok(vec![n(2)]));
+        assert_eq!(graph_with_edges.get_edges_to(n(1)), Ok(vec![n(2)]));
+        assert_eq!(graph_with_edges.get_edges(n(1)), Ok(vec![n(2), n(2)]));
+        assert_eq!(graph_with_edges.get_edges_from(n(3)), Ok(vec![]));
+
+        assert!(graph_without_edges.get_edges_from(n(5)).is_err());
+        assert!(graph_without_edges.get_edges_to(n(5)).is_err());
+        assert!(graph_without_edges.get_edges(n(5)).is_err());
+    }
+
+    #[test]
+    fn edge_weights() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, 4).unwrap();
+        graph.add_edge(a, c, 7).unwrap();
+
+        assert_eq!(graph.edge_weight(a, c), Some(&7));
+        assert_eq!(graph.edge_weight(c, a), None);
+        assert_eq!(graph.get_weighted_edges_from(a), Ok(vec![(b, &4), (c, &7)]));
+    }
+
+    #[test]
+    fn edge_payloads() {
+        let mut graph = UnGraph::new_undirected();
+        let main = graph.add_node("main.rs");
+        let lib = graph.add_node("lib.rs");
+        let io = graph.add_node("io.rs");
+        graph.add_edge(main, lib, "imports").unwrap();
+        graph.add_edge(lib, io, "calls").unwrap();
+
+        *graph.edge_weight_mut(io, lib).unwrap() = "imports";
+        assert_eq!(graph.edge_weight(lib, io), Some(&"imports"));
+        assert_eq!(graph.edge_weight_mut(main, io), None);
+
+        for kind in graph.edge_weights_mut() {
+            *kind = "uses";
+        }
+        let edges: Vec<_> = graph
+            .edges()
+            .map(|edge| (edge.source(), edge.target(), *edge.weight()))
+            .collect();
+        assert_eq!(edges, vec![(main, lib, "uses"), (lib, io, "uses")]);
+    }
+
+    #[test]
+    fn capacity() {
+        let mut graph = Graph::<i32>::with_capacity(8, 20);
+        assert!(graph.nodes.capacity() >= 8);
+        let a = graph.add_node(0);
+        let b = graph.add_node(1);
+        assert!(graph.edges[b.index()].capacity() >= 3);
+        assert!(graph == Graph::from_iter([0, 1]));
+        graph.reserve_edges(a, 16);
+        assert!(graph.edges[a.index()].capacity() >= 16);
+        graph.add_edge(a, b, ()).unwrap();
+
+        graph.reserve_nodes(100);
+        assert!(graph.edges.capacity() >= 102);
+        graph.shrink_to_fit();
+        assert!(graph.edges[a.index()].capacity() < 16);
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn degrees() {
+        let mut graph = get_test_graph_with_edges();
+        graph.add_edge(n(3), n(1), ()).unwrap();
+
+        assert_eq!(graph.out_degree(n(1)), 1);
+        assert_eq!(graph.in_degree(n(1)), 2);
+        assert_eq!(graph.degree(n(1)), 3);
+        assert_eq!(graph.degree_distribution(), vec![0, 1, 2, 1]);
+
+        graph.remove_edge(n(2), n(1)).unwrap();
+        graph.remove_node(n(0));
+        assert_eq!(graph.in_degree(n(0)), 1);
+        assert_eq!(graph.in_degree(n(2)), 0);
+        assert_eq!(graph.out_degree(n(2)), 1);
+
+        let mut undirected = UnGraph::<i32>::new_undirected().allow_self_loops(true);
+        let a = undirected.add_node(0);
+        let b = undirected.add_node(1);
+        undirected.add_edge(a, b, ()).unwrap();
+        undirected.add_edge(b, b, ()).unwrap();
+        assert_eq!(undirected.in_degree(a), 1);
+        assert_eq!(undirected.out_degree(b), 3);
+        assert_eq!(undirected.remove_all_edges_between(b, b), Ok(1));
+        assert_eq!(undirected.degree(b), 1);
+    }
+
+    #[test]
+    fn tracking_incoming_edges() {
+        let mut graph = get_test_graph_with_edges().track_incoming(true);
+        let mut untracked = get_test_graph_with_edges();
+        for graph in [&mut graph, &mut untracked] {
+            graph.add_edge(n(3), n(1), ()).unwrap();
+            graph.add_edge(n(0), n(1), ()).unwrap();
+            graph.add_edge(n(0), n(1), ()).unwrap();
+            graph.remove_edge(n(0), n(1)).unwrap();
+        }
+
+        assert!(graph.tracks_incoming());
+        for idx in (0..4).map(n) {
+            assert_eq!(graph.get_edges_to(idx), untracked.get_edges_to(idx));
+        }
+        assert_eq!(graph.get_edges_to(n(1)), Ok(vec![n(0), n(2), n(3)]));
+
+        graph.remove_all_edges_between(n(0), n(1)).unwrap();
+        graph.remove_node(n(2));
+        assert_eq!(graph.get_edges_to(n(1)), Ok(vec![n(2)]));
+        assert_eq!(graph.get_edges_to(n(2)), Ok(vec![n(0)]));
+        assert_eq!(
+            graph.neighbors_incoming(n(2)).collect::<Vec<_>>(),
+            vec![n(0)]
+        );
+        let mut recounted = graph.clone();
+        recounted.recount_edges();
+        assert!(graph == recounted);
+        assert!(!graph.track_incoming(false).tracks_incoming());
+    }
+
+    #[test]
+    fn swap_removing_nodes() {
+        for tracked in [false, true] {
+            let mut graph = get_test_graph_with_edges()
+                .allow_self_loops(true)
+                .track_incoming(tracked);
+            graph.add_edge(n(3), n(1), ()).unwrap();
+            graph.add_edge(n(3), n(3), ()).unwrap();
+            graph.add_edge(n(1), n(3), ()).unwrap();
+
+            assert_eq!(graph.swap_remove_node(n(1)), (1, Some(n(3))));
+            assert_eq!(graph.to_string(), "5 -> 100\n100 -> 100\n");
+            assert_eq!(graph.edge_count(), 2);
+            assert_eq!(graph.in_degree(n(1)), 2);
+            assert_eq!(graph.in_degree(n(2)), 0);
+            assert_eq!(graph.get_edges_to(n(1)), Ok(vec![n(0), n(1)]));
+            assert_eq!(graph.get_edges_to(n(2)), Ok(vec![]));
+
+            assert_eq!(graph.swap_remove_node(n(2)), (12, None));
+            assert_eq!(graph.swap_remove_node(n(0)), (5, Some(n(1))));
+            assert_eq!(graph.to_string(), "100 -> 100\n");
+            assert_eq!(graph.get_edges_to(n(0)), Ok(vec![n(0)]));
+            let mut recounted = graph.clone();
+            recounted.recount_edges();
+            assert!(graph == recounted);
+        }
+    }
+
+    #[test]
+    fn self_loops() {
+        let mut graph = get_test_graph_with_edges().allow_self_loops(true);
+        graph.add_edge(n(2), n(2), ()).unwrap();
+        graph.add_edge(n(3), n(3), ()).unwrap();
+
+        assert!(graph.has_self_loop(n(2)));
+        assert!(!graph.has_self_loop(n(1)));
+        assert!(!graph.has_self_loop(n(9)));
+        let loops: Vec<_> = graph.self_loops().map(|edge| edge.source()).collect();
+        assert_eq!(loops, vec![n(2), n(3)]);
+        assert!(graph.topological_sort().is_err());
+
+        let mut graph = graph.allow_self_loops(false);
+        assert!(graph.add_edge(n(0), n(0), ()).is_err());
+        assert_eq!(graph.self_loops().count(), 2);
+    }
+
+    #[test]
+    fn unique_edges() {
+        let mut graph = get_test_graph_with_edges();
+
+        assert_eq!(
+            graph.add_edge_unique(n(1), n(2), ()),
+            Err(GraphError::DuplicateEdge {
+                from: n(1),
+                to: n(2)
+            })
+        );
+        assert!(graph.add_edge_unique(n(3), n(0), ()).is_ok());
+        assert_eq!(graph.edge_count(), 4);
+
+        let mut weighted = Graph::new_undirected();
+        let a = weighted.add_node('a');
+        let b = weighted.add_node('b');
+        assert_eq!(weighted.update_edge(a, b, 1), Ok(None));
+        assert_eq!(weighted.update_edge(b, a, 2), Ok(Some(1)));
+        assert_eq!(weighted.edge_weight(a, b), Some(&2));
+        assert_eq!(weighted.edge_count(), 1);
+        assert!(weighted.add_edge_unique(b, a, 3).is_err());
+    }
+
+    #[test]
+    fn counting() {
+        let mut graph = get_test_graph_with_edges();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(!graph.is_empty());
+
+        graph.add_edge(n(1), n(2), ()).unwrap();
+        assert_eq!(graph.edge_count(), 4);
+        graph.remove_edge(n(0), n(3)).unwrap();
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.remove_all_edges_between(n(1), n(2)), Ok(2));
+        assert_eq!(graph.edge_count(), 1);
+        graph.remove_node(n(1));
+        assert_eq!(graph.edge_count(), 0);
+
+        while graph.pop().is_some() {}
+        assert!(graph.is_empty());
+        assert_eq!(Graph::<i32>::new().node_count(), 0);
+    }
+
+    #[test]
+    fn finding_edges() {
+        let graph = get_test_graph_with_edges();
+
+        assert!(graph.contains_edge(n(1), n(2)));
+        assert!(!graph.contains_edge(n(3), n(0)));
+        assert!(!graph.contains_edge(n(7), n(0)));
+        assert_eq!(graph.find_edge(n(0), n(3)), Some(EdgeIndex::new(0)));
+        assert_eq!(graph.find_edge(n(2), n(1)), Some(EdgeIndex::new(2)));
+        assert_eq!(graph.find_edge(n(1), n(0)), None);
 
-        assert!(graph_without_edges.get_edges_from(5).is_err());
-        assert!(graph_without_edges.get_edges_to(5).is_err());
-        assert!(graph_without_edges.get_edges(5).is_err());
+        for (index, edge) in graph.edges().enumerate() {
+            assert_eq!(
+                graph.find_edge(edge.source(), edge.target()),
+                Some(EdgeIndex::new(index))
+            );
+        }
+
+        let mut undirected = Graph::new_undirected();
+        let a = undirected.add_node('a');
+        let b = undirected.add_node('b');
+        undirected.add_edge(a, b, ()).unwrap();
+        assert!(undirected.contains_edge(b, a));
+        assert_eq!(undirected.find_edge(b, a), Some(EdgeIndex::new(0)));
+    }
+
+    #[test]
+    fn finding_edges_while_mutating() {
+        fn check(graph: &Graph<i32>) {
+            let mut recounted = graph.clone();
+            recounted.recount_edges();
+            assert!(*graph == recounted);
+            for from in (0..graph.node_count()).map(n) {
+                for to in (0..graph.node_count()).map(n) {
+                    let first = graph
+                        .edges()
+                        .find(|edge| (edge.source(), edge.target()) == (from, to))
+                        .map(|edge| edge.index());
+                    assert_eq!(graph.find_edge(from, to), first);
+                    assert_eq!(graph.contains_edge(from, to), first.is_some());
+                }
+            }
+        }
+
+        for tracked in [false, true] {
+            let mut graph = get_test_graph_with_edges().track_incoming(tracked);
+            graph.add_edge(n(3), n(0), ()).unwrap();
+            graph.add_edge(n(0), n(1), ()).unwrap();
+            check(&graph);
+            graph.remove_edge(n(0), n(3)).unwrap();
+            check(&graph);
+            graph.merge(get_test_graph_with_edges());
+            check(&graph);
+            graph.swap_remove_node(n(1));
+            check(&graph);
+            graph.remove_node(n(4));
+            check(&graph);
+            graph.remove_all_edges_between(n(2), n(0)).unwrap();
+            check(&graph);
+            graph.retain_edges(|edge| edge.index() != EdgeIndex::new(1));
+            graph.add_edge(n(2), n(0), ()).unwrap();
+            check(&graph);
+        }
+    }
+
+    #[test]
+    fn undirected_edges() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 3).unwrap();
+        graph.add_edge(c, a, 5).unwrap();
+
+        assert!(!graph.is_directed());
+        assert_eq!(graph.get_edges_from(a), Ok(vec![b, c]));
+        assert_eq!(graph.get_edges_to(a), Ok(vec![b, c]));
+        assert_eq!(graph.get_edges(b), Ok(vec![a]));
+        assert_eq!(graph.edge_weight(b, a), Some(&3));
+        assert_eq!(graph.edge_weight(a, c), Some(&5));
+        assert_eq!(graph.to_string(), "a -- b\nc -- a\n");
+
+        graph.remove_node(a);
+        assert_eq!(graph.get_edges(n(0)), Ok(vec![]));
+    }
+
+    #[test]
+    fn edge_removal() {
+        let mut graph = get_test_graph_with_edges();
+        graph.add_edge(n(1), n(2), ()).unwrap();
+
+        assert_eq!(graph.remove_edge(n(0), n(3)), Ok(()));
+        assert!(graph.remove_edge(n(0), n(3)).is_err());
+        assert!(graph.remove_edge(n(0), n(7)).is_err());
+        assert_eq!(graph.remove_all_edges_between(n(1), n(2)), Ok(2));
+        assert_eq!(graph.remove_all_edges_between(n(1), n(2)), Ok(0));
+        assert_eq!(graph.to_string(), "12 -> 1\n");
+
+        let mut undirected = UnGraph::new_undirected();
+        let a = undirected.add_node(0);
+        let b = undirected.add_node(1);
+        undirected.add_edge(a, b, 'x').unwrap();
+        undirected.add_edge(b, a, 'y').unwrap();
+        assert_eq!(undirected.remove_edge(b, a), Ok('y'));
+        assert_eq!(undirected.remove_edge(b, a), Ok('x'));
+        assert_eq!(undirected.get_edges(a), Ok(vec![]));
+    }
+
+    #[test]
+    fn iterating_edges() {
+        let mut graph = get_test_graph_without_edges();
+        graph.add_edge(n(2), n(0), ()).unwrap();
+        graph.add_edge(n(0), n(3), ()).unwrap();
+        graph.add_edge(n(2), n(1), ()).unwrap();
+
+        assert_eq!(graph.edge_count(), 3);
+        let edges: Vec<_> = graph
+            .edges()
+            .map(|edge| (edge.index().index(), edge.source(), edge.target()))
+            .collect();
+        assert_eq!(
+            edges,
+            vec![(0, n(0), n(3)), (1, n(2), n(0)), (2, n(2), n(1))]
+        );
+    }
+
+    #[test]
+    fn iterating_neighbors() {
+        let graph = get_test_graph_with_edges();
+        assert_eq!(graph.neighbors(n(1)).collect::<Vec<_>>(), vec![n(2)]);
+        assert_eq!(graph.neighbors(n(3)).count(), 0);
+        assert_eq!(
+            graph.neighbors_incoming(n(3)).collect::<Vec<_>>(),
+            vec![n(0)]
+        );
+
+        let mut undirected = UnGraph::new_undirected();
+        let a = undirected.add_node('a');
+        let b = undirected.add_node('b');
+        let c = undirected.add_node('c');
+        undirected.add_edge(a, b, ()).unwrap();
+        undirected.add_edge(c, a, ()).unwrap();
+        assert_eq!(undirected.neighbors(a).collect::<Vec<_>>(), vec![b, c]);
+        assert_eq!(
+            undirected.neighbors_incoming(a).collect::<Vec<_>>(),
+            vec![b, c]
+        );
+    }
+
+    #[test]
+    fn indexing_nodes() {
+        let mut graph = get_test_graph_without_edges();
+
+        assert_eq!(graph[n(2)], 12);
+        assert_eq!(graph[3], 100);
+        graph[n(0)] += 1;
+        graph[1] *= 7;
+        assert_eq!(
+            graph.nodes().copied().collect::<Vec<_>>(),
+            vec![6, 7, 12, 100]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn indexing_out_of_bounds() {
+        let graph = get_test_graph_without_edges();
+        let _ = graph[n(4)];
+    }
+
+    #[test]
+    fn mutating_nodes() {
+        let mut graph = get_test_graph_with_edges();
+
+        *graph.get_node_mut(n(1)).unwrap() += 1;
+        assert_eq!(graph.get_node(n(1)), Some(&2));
+        assert_eq!(graph.get_node_mut(n(4)), None);
+
+        for node in graph.nodes_mut() {
+            *node *= 10;
+        }
+        assert_eq!(graph.to_string(), "50 -> 1000\n20 -> 120\n120 -> 20\n");
     }
 }
@@ -0,0 +1,95 @@
+/// Builds a graph from named nodes and the edges between them, and returns it
+/// together with a struct holding the index of every node under its name.
+///
+/// Edges written with `->` make a directed graph and edges written with `--`
+/// an undirected one. Weights follow a colon and default to
+/// `Default::default()`.
+///
+/// ```
+/// let (graph, n) = vecgraph::graph! {
+///     a: 5, b: 1, c: 12;
+///     a -> b: 2.5, b -> c: 1.0,
+/// };
+///
+/// assert_eq!(graph[n.b], 1);
+/// assert_eq!(graph.edge_weight(n.a, n.b), Some(&2.5));
+/// ```
+///
+/// Panics if an edge cannot be added.
+#[macro_export]
+macro_rules! graph {
+    (@weight) => {
+        ::core::default::Default::default()
+    };
+    (@weight $weight:expr) => {
+        $weight
+    };
+    (@build $graph:expr;
+        $($node:ident : $payload:expr),*;
+        $($from:ident, $to:ident $(, $weight:expr)?);*
+    ) => {{
+        let mut graph = $graph;
+        #[derive(Debug, Clone, Copy)]
+        struct Nodes {
+            $($node: $crate::NodeIndex,)*
+        }
+        let nodes = Nodes {
+            $($node: graph.add_node($payload),)*
+        };
+        $(
+            graph
+                .add_edge(nodes.$from, nodes.$to, $crate::graph!(@weight $($weight)?))
+                .expect("invalid edge in graph!");
+        )*
+        (graph, nodes)
+    }};
+    (
+        $($node:ident : $payload:expr),* $(,)?
+        $(; $($from:ident -> $to:ident $(: $weight:expr)?),* $(,)?)?
+    ) => {
+        $crate::graph!(@build $crate::Graph::new();
+            $($node: $payload),*;
+            $($($from, $to $(, $weight)?);*)?
+        )
+    };
+    (
+        $($node:ident : $payload:expr),* $(,)?
+        ; $($from:ident -- $to:ident $(: $weight:expr)?),* $(,)?
+    ) => {
+        $crate::graph!(@build $crate::Graph::new_undirected();
+            $($node: $payload),*;
+            $($from, $to $(, $weight)?);*
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, UnGraph};
+
+    #[test]
+    fn directed_graph() {
+        let (graph, n) = graph! { a: 5, b: 1; a -> b, b -> a };
+        let graph: DiGraph<i32> = graph;
+
+        assert_eq!(graph.to_string(), "5 -> 1\n1 -> 5\n");
+        assert!(graph.contains_edge(n.b, n.a));
+
+        let (graph, n) = graph! { x: 'x' };
+        let graph: DiGraph<char> = graph;
+        assert_eq!(graph[n.x], 'x');
+        assert_eq!(graph.edge_count(), 0);
+    }
+
+    #[test]
+    fn undirected_weighted_graph() {
+        let (graph, n) = graph! {
+            a: "a", b: "b", c: "c";
+            a -- b: 3, c -- b: 4,
+        };
+        let graph: UnGraph<&str, i32> = graph;
+
+        assert_eq!(graph.edge_weight(n.b, n.c), Some(&4));
+        assert_eq!(graph.to_string(), "a -- b\nc -- b\n");
+    }
+}
@@ -0,0 +1,191 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{EdgeType, Graph, GraphError, NodeIndex};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A square matrix of booleans telling which nodes have an edge between them,
+/// packed 64 entries to a word.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct AdjacencyMatrix {
+    size: usize,
+    bits: Vec<u64>,
+}
+
+impl AdjacencyMatrix {
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            bits: vec![0; (size * size).div_ceil(BITS)],
+        }
+    }
+
+    /// Panics if the rows do not form a square matrix.
+    pub fn from_rows(rows: &[Vec<bool>]) -> Self {
+        let mut matrix = Self::new(rows.len());
+        for (from, row) in rows.iter().enumerate() {
+            if row.len() != rows.len() {
+                panic!(
+                    "matrix is not square: row {} has length {}, but there are {} rows",
+                    from,
+                    row.len(),
+                    rows.len(),
+                );
+            }
+            for (to, &connected) in row.iter().enumerate() {
+                if connected {
+                    matrix.insert(NodeIndex::new(from), NodeIndex::new(to));
+                }
+            }
+        }
+        matrix
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn contains(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        match self.bit(from, to) {
+            Some(bit) => self.bits[bit / BITS] & (1 << (bit % BITS)) != 0,
+            None => false,
+        }
+    }
+
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn insert(&mut self, from: NodeIndex, to: NodeIndex) {
+        let bit = self.bit(from, to).unwrap_or_else(|| {
+            panic!(
+                "index out of range: from is {}, to is {}, but size is {}",
+                from, to, self.size,
+            )
+        });
+        self.bits[bit / BITS] |= 1 << (bit % BITS);
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<bool>> {
+        (0..self.size)
+            .map(|from| {
+                (0..self.size)
+                    .map(|to| self.contains(NodeIndex::new(from), NodeIndex::new(to)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn bit(&self, from: NodeIndex, to: NodeIndex) -> Option<usize> {
+        if from.index() >= self.size || to.index() >= self.size {
+            return None;
+        }
+        Some(from.index() * self.size + to.index())
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Undirected edges are entered in both directions, so the matrix of an
+    /// undirected graph is symmetric.
+    pub fn to_adjacency_matrix(&self) -> AdjacencyMatrix {
+        let mut matrix = AdjacencyMatrix::new(self.nodes.len());
+        for edge in self.edges() {
+            matrix.insert(edge.source(), edge.target());
+            if !Ty::is_directed() {
+                matrix.insert(edge.target(), edge.source());
+            }
+        }
+        matrix
+    }
+}
+
+impl<T, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Builds a graph with the given nodes and an edge with the default weight
+    /// wherever the matrix has an entry. For undirected graphs an entry in
+    /// either direction adds a single edge.
+    ///
+    /// Fails if the matrix has an entry on its diagonal, as nodes cannot point
+    /// to themselves. Panics if the matrix size does not match the number of
+    /// nodes.
+    pub fn from_adjacency_matrix(
+        nodes: Vec<T>,
+        matrix: &AdjacencyMatrix,
+    ) -> Result<Self, GraphError> {
+        if nodes.len() != matrix.size() {
+            panic!(
+                "matrix size does not match node count: size is {}, but there are {} nodes",
+                matrix.size(),
+                nodes.len(),
+            );
+        }
+
+        let mut graph = Graph::default();
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for from in (0..matrix.size()).map(NodeIndex::new) {
+            for to in (0..matrix.size()).map(NodeIndex::new) {
+                let connected = if Ty::is_directed() {
+                    matrix.contains(from, to)
+                } else {
+                    to >= from && (matrix.contains(from, to) || matrix.contains(to, from))
+                };
+                if connected {
+                    graph.add_edge(from, to, E::default())?;
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdjacencyMatrix;
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn bit_packing() {
+        let mut matrix = AdjacencyMatrix::new(9);
+        matrix.insert(NodeIndex::new(7), NodeIndex::new(8));
+        matrix.insert(NodeIndex::new(0), NodeIndex::new(3));
+
+        assert!(matrix.contains(NodeIndex::new(7), NodeIndex::new(8)));
+        assert!(!matrix.contains(NodeIndex::new(8), NodeIndex::new(7)));
+        assert!(!matrix.contains(NodeIndex::new(9), NodeIndex::new(0)));
+        assert_eq!(AdjacencyMatrix::from_rows(&matrix.to_rows()), matrix);
+    }
+
+    #[test]
+    fn graph_round_trip() {
+        let mut graph = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, c, ()).unwrap();
+        graph.add_edge(c, b, ()).unwrap();
+
+        let matrix = graph.to_adjacency_matrix();
+        assert_eq!(
+            matrix.to_rows(),
+            vec![
+                vec![false, false, true],
+                vec![false, false, false],
+                vec![false, true, false],
+            ]
+        );
+        let rebuilt = Graph::from_adjacency_matrix(vec!['a', 'b', 'c'], &matrix).unwrap();
+        assert!(rebuilt == graph);
+
+        let undirected: UnGraph<char> =
+            UnGraph::from_adjacency_matrix(vec!['a', 'b', 'c'], &matrix).unwrap();
+        assert_eq!(undirected.to_string(), "a -- c\nb -- c\n");
+        assert_eq!(
+            undirected.to_adjacency_matrix().to_rows(),
+            vec![
+                vec![false, false, true],
+                vec![false, false, true],
+                vec![true, true, false],
+            ]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph};
+
+type Label<'a, X> = Box<dyn Fn(&X) -> String + 'a>;
+
+/// Formats a graph as a Mermaid flowchart, which GitHub and many Markdown
+/// renderers draw from a ` ```mermaid ` code block.
+///
+/// Nodes are identified as `n0`, `n1`, ... by their index and labeled with
+/// their payload. Edges are unlabeled unless a label is requested.
+pub struct Mermaid<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    node_label: Label<'a, T>,
+    edge_label: Option<Label<'a, E>>,
+}
+
+impl<'a, T: Display, E, Ty: EdgeType> Mermaid<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>) -> Self {
+        Self::with_node_label(graph, |node| node.to_string())
+    }
+}
+
+impl<'a, T, E, Ty: EdgeType> Mermaid<'a, T, E, Ty> {
+    pub fn with_node_label(graph: &'a Graph<T, E, Ty>, label: impl Fn(&T) -> String + 'a) -> Self {
+        Self {
+            graph,
+            node_label: Box::new(label),
+            edge_label: None,
+        }
+    }
+
+    pub fn edge_label(mut self, label: impl Fn(&E) -> String + 'a) -> Self {
+        self.edge_label = Some(Box::new(label));
+        self
+    }
+
+    pub fn edge_labels(self) -> Self
+    where
+        E: Display,
+    {
+        self.edge_label(|weight| weight.to_string())
+    }
+}
+
+impl<T, E, Ty: EdgeType> Display for Mermaid<'_, T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let arrow = if Ty::is_directed() { "-->" } else { "---" };
+
+        writeln!(f, "flowchart TD")?;
+        for (idx, node) in self.graph.nodes.iter().enumerate() {
+            writeln!(f, "    n{}[{}]", idx, Quoted(&(self.node_label)(node)))?;
+        }
+        for edge in self.graph.edges() {
+            write!(f, "    n{} {}", edge.source(), arrow)?;
+            if let Some(edge_label) = &self.edge_label {
+                write!(f, "|{}|", Quoted(&edge_label(edge.weight())))?;
+            }
+            writeln!(f, " n{}", edge.target())?;
+        }
+        Ok(())
+    }
+}
+
+/// Mermaid has no backslash escapes, so quotes are written as entity codes.
+struct Quoted<'a>(&'a str);
+
+impl Display for Quoted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "#quot;")?,
+                '#' => write!(f, "#35;")?,
+                '\n' => write!(f, "<br>")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+impl<T: Display, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn to_mermaid(&self) -> String {
+        Mermaid::new(self).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mermaid;
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn directed_mermaid() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("say \"hi\" #1");
+        graph.add_edge(a, b, 1.5).unwrap();
+        graph.add_edge(b, a, 2.0).unwrap();
+
+        assert_eq!(
+            graph.to_mermaid(),
+            "flowchart TD\n    n0[\"a\"]\n    n1[\"say #quot;hi#quot; #35;1\"]\n    n0 --> n1\n    n1 --> n0\n"
+        );
+        assert_eq!(
+            Mermaid::new(&graph).edge_labels().to_string(),
+            "flowchart TD\n    n0[\"a\"]\n    n1[\"say #quot;hi#quot; #35;1\"]\n    n0 -->|\"1.5\"| n1\n    n1 -->|\"2\"| n0\n"
+        );
+    }
+
+    #[test]
+    fn undirected_custom_labels() {
+        let mut graph = UnGraph::new_undirected();
+        let a = graph.add_node(vec![1, 2]);
+        let b = graph.add_node(vec![]);
+        graph.add_edge(a, b, "x").unwrap();
+
+        let mermaid = Mermaid::with_node_label(&graph, |node| format!("{} items", node.len()))
+            .edge_label(|weight| weight.to_uppercase());
+        assert_eq!(
+            mermaid.to_string(),
+            "flowchart TD\n    n0[\"2 items\"]\n    n1[\"0 items\"]\n    n0 ---|\"X\"| n1\n"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+use crate::dot::Quoted;
+use crate::{Directed, EdgeType, Graph, GraphError, NodeIndex};
+
+/// Identifies a cluster of a [`NestedGraph`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct ClusterId(usize);
+
+impl ClusterId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cluster<C> {
+    label: C,
+    parent: Option<ClusterId>,
+}
+
+/// A graph whose nodes are grouped into clusters that can hold clusters of
+/// their own, to model modules, packages and the like.
+///
+/// The nodes and edges live in a single flat [`Graph`], so edges can connect
+/// nodes in different clusters, and every node belongs to at most one
+/// cluster, along with every cluster that cluster is nested in. Clusters
+/// carry a label of type `C` and are never removed.
+#[derive(Clone)]
+pub struct NestedGraph<T, C, E = (), Ty = Directed> {
+    graph: Graph<T, E, Ty>,
+    clusters: Vec<Cluster<C>>,
+    /// The innermost cluster of every node, by node index.
+    membership: Vec<Option<ClusterId>>,
+}
+
+impl<T: Debug, C: Debug, E: Debug, Ty: EdgeType> Debug for NestedGraph<T, C, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NestedGraph")
+            .field("graph", &self.graph)
+            .field("clusters", &self.clusters)
+            .field("membership", &self.membership)
+            .finish()
+    }
+}
+
+impl<T, C, E, Ty: EdgeType> Default for NestedGraph<T, C, E, Ty> {
+    fn default() -> Self {
+        Self::from_graph(Graph::default())
+    }
+}
+
+impl<T, C, E, Ty: EdgeType> NestedGraph<T, C, E, Ty> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps `graph`, with none of its nodes in a cluster yet.
+    pub fn from_graph(graph: Graph<T, E, Ty>) -> Self {
+        let membership = vec![None; graph.node_count()];
+        Self {
+            graph,
+            clusters: Vec::new(),
+            membership,
+        }
+    }
+
+    pub fn graph(&self) -> &Graph<T, E, Ty> {
+        &self.graph
+    }
+
+    /// Flattens the graph, returning it along with the innermost cluster of
+    /// every node.
+    pub fn into_parts(self) -> (Graph<T, E, Ty>, Vec<Option<ClusterId>>) {
+        (self.graph, self.membership)
+    }
+
+    /// Adds a cluster inside `parent`, or at the top level.
+    ///
+    /// Panics if `parent` is not a cluster of this graph.
+    pub fn add_cluster(&mut self, label: C, parent: Option<ClusterId>) -> ClusterId {
+        if let Some(parent) = parent {
+            self.check_cluster(parent);
+        }
+        self.clusters.push(Cluster { label, parent });
+        ClusterId(self.clusters.len() - 1)
+    }
+
+    pub fn cluster(&self, cluster: ClusterId) -> Option<&C> {
+        self.clusters.get(cluster.0).map(|cluster| &cluster.label)
+    }
+
+    pub fn cluster_mut(&mut self, cluster: ClusterId) -> Option<&mut C> {
+        self.clusters
+            .get_mut(cluster.0)
+            .map(|cluster| &mut cluster.label)
+    }
+
+    pub fn cluster_count(&self) -> usize {
+        self.clusters.len()
+    }
+
+    pub fn clusters(&self) -> impl Iterator<Item = ClusterId> {
+        (0..self.clusters.len()).map(ClusterId)
+    }
+
+    /// The cluster `cluster` is nested in, or `None` for a top-level cluster.
+    ///
+    /// Panics if `cluster` is not a cluster of this graph.
+    pub fn parent(&self, cluster: ClusterId) -> Option<ClusterId> {
+        self.check_cluster(cluster);
+        self.clusters[cluster.0].parent
+    }
+
+    /// The clusters nested directly in `cluster`, or the top-level clusters.
+    pub fn child_clusters(
+        &self,
+        cluster: Option<ClusterId>,
+    ) -> impl Iterator<Item = ClusterId> + '_ {
+        self.clusters()
+            .filter(move |&child| self.clusters[child.0].parent == cluster)
+    }
+
+    /// Adds a node inside `cluster`, or outside of every cluster.
+    ///
+    /// Panics if `cluster` is not a cluster of this graph.
+    pub fn add_node(&mut self, weight: T, cluster: Option<ClusterId>) -> NodeIndex {
+        if let Some(cluster) = cluster {
+            self.check_cluster(cluster);
+        }
+        self.membership.push(cluster);
+        self.graph.add_node(weight)
+    }
+
+    /// Removes a node and its edges, shifting the nodes after it down by one
+    /// as [`Graph::remove_node`] does.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove_node(&mut self, idx: NodeIndex) -> T {
+        let weight = self.graph.remove_node(idx);
+        self.membership.remove(idx.index());
+        weight
+    }
+
+    pub fn get_node(&self, idx: NodeIndex) -> Option<&T> {
+        self.graph.get_node(idx)
+    }
+
+    pub fn get_node_mut(&mut self, idx: NodeIndex) -> Option<&mut T> {
+        self.graph.get_node_mut(idx)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        self.graph.add_edge(from, to, weight)
+    }
+
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> Result<E, GraphError> {
+        self.graph.remove_edge(from, to)
+    }
+
+    /// The innermost cluster holding `idx`, or `None` if it is in none.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn cluster_of(&self, idx: NodeIndex) -> Option<ClusterId> {
+        self.check_node(idx);
+        self.membership[idx.index()]
+    }
+
+    /// Moves `idx` into `cluster`, or out of every cluster.
+    ///
+    /// Panics if `idx` is out of bounds or `cluster` is not a cluster of this
+    /// graph.
+    pub fn set_cluster(&mut self, idx: NodeIndex, cluster: Option<ClusterId>) {
+        self.check_node(idx);
+        if let Some(cluster) = cluster {
+            self.check_cluster(cluster);
+        }
+        self.membership[idx.index()] = cluster;
+    }
+
+    /// The nodes directly in `cluster`, or in no cluster at all.
+    pub fn nodes_in(&self, cluster: Option<ClusterId>) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.membership
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &member)| member == cluster)
+            .map(|(idx, _)| NodeIndex::new(idx))
+    }
+
+    /// The nodes in `cluster` or in any cluster nested in it, however deep.
+    ///
+    /// Panics if `cluster` is not a cluster of this graph.
+    pub fn nodes_within(&self, cluster: ClusterId) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.check_cluster(cluster);
+        self.membership
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &member)| {
+                let mut current = member;
+                while let Some(inner) = current {
+                    if inner == cluster {
+                        return true;
+                    }
+                    current = self.clusters[inner.0].parent;
+                }
+                false
+            })
+            .map(|(idx, _)| NodeIndex::new(idx))
+    }
+
+    fn check_node(&self, idx: NodeIndex) {
+        if idx.index() >= self.membership.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.membership.len(),
+            );
+        }
+    }
+
+    fn check_cluster(&self, cluster: ClusterId) {
+        if cluster.0 >= self.clusters.len() {
+            panic!(
+                "cluster out of range: cluster is {}, but there are {} clusters",
+                cluster.0,
+                self.clusters.len(),
+            );
+        }
+    }
+}
+
+impl<T: Display, C: Display, E, Ty: EdgeType> NestedGraph<T, C, E, Ty> {
+    /// Formats the graph in the Graphviz DOT language, with every cluster as
+    /// a `subgraph cluster_N` block nested like the clusters themselves.
+    pub fn to_dot(&self) -> String {
+        let (kind, arrow) = if Ty::is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = String::new();
+        writeln!(dot, "{} {{", kind).unwrap();
+        self.write_level(&mut dot, None, 1);
+        for edge in self.graph.edges() {
+            writeln!(dot, "    {} {} {}", edge.source(), arrow, edge.target()).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_level(&self, dot: &mut String, cluster: Option<ClusterId>, depth: usize) {
+        let indent = "    ".repeat(depth);
+        for idx in self.nodes_in(cluster) {
+            let label = self.graph[idx].to_string();
+            writeln!(dot, "{}{} [ label = {} ]", indent, idx, Quoted(&label)).unwrap();
+        }
+        for child in self.child_clusters(cluster) {
+            let label = self.clusters[child.0].label.to_string();
+            writeln!(dot, "{}subgraph cluster_{} {{", indent, child.0).unwrap();
+            writeln!(dot, "{}    label = {}", indent, Quoted(&label)).unwrap();
+            self.write_level(dot, Some(child), depth + 1);
+            writeln!(dot, "{}}}", indent).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NestedGraph;
+    use crate::NodeIndex;
+
+    #[test]
+    fn clusters_and_dot() {
+        let mut graph = NestedGraph::<&str, &str>::new();
+        let core = graph.add_cluster("core", None);
+        let io = graph.add_cluster("io", Some(core));
+        let parse = graph.add_node("parse", Some(core));
+        let read = graph.add_node("read", Some(io));
+        let main = graph.add_node("main", None);
+        graph.add_edge(main, parse, ()).unwrap();
+        graph.add_edge(parse, read, ()).unwrap();
+
+        assert_eq!(graph.parent(io), Some(core));
+        assert_eq!(graph.child_clusters(None).collect::<Vec<_>>(), vec![core]);
+        assert_eq!(graph.nodes_in(Some(core)).collect::<Vec<_>>(), vec![parse]);
+        assert_eq!(
+            graph.nodes_within(core).collect::<Vec<_>>(),
+            vec![parse, read]
+        );
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    2 [ label = \"main\" ]\n    subgraph cluster_0 {\n        label = \"core\"\n        0 [ label = \"parse\" ]\n        subgraph cluster_1 {\n            label = \"io\"\n            1 [ label = \"read\" ]\n        }\n    }\n    0 -> 1\n    2 -> 0\n}\n"
+        );
+
+        graph.set_cluster(read, None);
+        assert_eq!(graph.remove_node(parse), "parse");
+        assert_eq!(graph.cluster_of(NodeIndex::new(0)), None);
+        let (flat, membership) = graph.into_parts();
+        assert_eq!(flat.node_count(), 2);
+        assert_eq!(membership, vec![None, None]);
+    }
+}
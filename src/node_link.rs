@@ -0,0 +1,197 @@
+//! The node-link JSON format read by D3 force layouts:
+//!
+//! ```json
+//! {"nodes":[{"id":0,"data":"a"},{"id":1,"data":"b"}],"links":[{"source":0,"target":1,"weight":2.5}]}
+//! ```
+//!
+//! Nodes are written with their index as id. When reading, ids can be any
+//! JSON value, such as the strings D3 examples use, as long as the links
+//! refer to them the same way. Nodes without a `data` field, such as
+//! `{"id":"Myriel","group":1}`, are read from the whole node object instead.
+
+use std::collections::BTreeMap;
+
+use serde::de::{Deserialize, DeserializeOwned, Error};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+#[derive(serde::Serialize)]
+struct NodeLinkRef<'a, T, E> {
+    nodes: Vec<NodeRef<'a, T>>,
+    links: Vec<LinkRef<'a, E>>,
+}
+
+#[derive(serde::Serialize)]
+struct NodeRef<'a, T> {
+    id: usize,
+    data: &'a T,
+}
+
+#[derive(serde::Serialize)]
+struct LinkRef<'a, E> {
+    source: usize,
+    target: usize,
+    weight: &'a E,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(bound(deserialize = "E: Deserialize<'de> + Default"))]
+struct NodeLinkOwned<E> {
+    nodes: Vec<Map<String, Value>>,
+    links: Vec<LinkOwned<E>>,
+}
+
+#[derive(serde::Deserialize)]
+struct LinkOwned<E> {
+    source: Value,
+    target: Value,
+    #[serde(default)]
+    weight: E,
+}
+
+impl<T: Serialize, E: Serialize, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn to_json_node_link(&self) -> Result<String, serde_json::Error> {
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, data)| NodeRef { id, data })
+            .collect();
+        let links = self
+            .edges()
+            .map(|edge| LinkRef {
+                source: edge.source().index(),
+                target: edge.target().index(),
+                weight: edge.weight(),
+            })
+            .collect();
+
+        serde_json::to_string(&NodeLinkRef { nodes, links })
+    }
+}
+
+impl<T: DeserializeOwned, E: DeserializeOwned + Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Links without a `weight` get the default weight. The graph allows
+    /// self-loops if any link has the same source and target.
+    pub fn from_json_node_link(json: &str) -> Result<Self, serde_json::Error> {
+        let NodeLinkOwned { nodes, links } = serde_json::from_str(json)?;
+
+        let mut graph = Graph::default();
+        let mut ids = BTreeMap::new();
+        for mut node in nodes {
+            let id = node
+                .get("id")
+                .cloned()
+                .ok_or_else(|| Error::missing_field("id"))?;
+            let data = match node.remove("data") {
+                Some(data) => data,
+                None => Value::Object(node),
+            };
+            let idx = graph.add_node(serde_json::from_value(data)?);
+            if ids.insert(id.to_string(), idx).is_some() {
+                return Err(Error::custom(format!("duplicate node id {}", id)));
+            }
+        }
+
+        let node = |id: Value| -> Result<NodeIndex, serde_json::Error> {
+            ids.get(&id.to_string())
+                .copied()
+                .ok_or_else(|| Error::custom(format!("unknown node id {}", id)))
+        };
+        for LinkOwned {
+            source,
+            target,
+            weight,
+        } in links
+        {
+            let (source, target) = (node(source)?, node(target)?);
+            if source == target {
+                graph.allows_self_loops = true;
+            }
+            graph
+                .add_edge(source, target, weight)
+                .map_err(Error::custom)?;
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn round_trip() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        graph.add_edge(a, b, 2.5).unwrap();
+        graph.add_edge(b, a, 1.0).unwrap();
+
+        let json = graph.to_json_node_link().unwrap();
+        assert_eq!(
+            json,
+            r#"{"nodes":[{"id":0,"data":"a"},{"id":1,"data":"b"}],"links":[{"source":0,"target":1,"weight":2.5},{"source":1,"target":0,"weight":1.0}]}"#
+        );
+        assert!(Graph::from_json_node_link(&json).unwrap() == graph);
+
+        let mut looped = Graph::new().allow_self_loops(true);
+        let a = looped.add_node(1);
+        looped.add_edge(a, a, ()).unwrap();
+        let parsed = Graph::from_json_node_link(&looped.to_json_node_link().unwrap()).unwrap();
+        assert!(parsed.allows_self_loops());
+        assert!(parsed == looped);
+    }
+
+    #[test]
+    fn reads_d3_ids() {
+        let json = r#"{
+            "nodes": [{"id": "Myriel", "data": 1}, {"id": "Napoleon", "data": 1}],
+            "links": [{"source": "Napoleon", "target": "Myriel"}]
+        }"#;
+
+        let graph = UnGraph::<u32>::from_json_node_link(json).unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(
+            graph
+                .edges()
+                .map(|edge| edge.source().index())
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+
+        let no_id = r#"{"nodes":[{"data":1}],"links":[]}"#;
+        let err = Graph::<u32>::from_json_node_link(no_id).unwrap_err();
+        assert_eq!(err.to_string(), "missing field `id`");
+
+        let unknown = r#"{"nodes":[{"id":0,"data":1}],"links":[{"source":0,"target":"0"}]}"#;
+        let err = Graph::<u32>::from_json_node_link(unknown).unwrap_err();
+        assert_eq!(err.to_string(), r#"unknown node id "0""#);
+        let duplicate = r#"{"nodes":[{"id":0,"data":1},{"id":0,"data":2}],"links":[]}"#;
+        assert!(Graph::<u32>::from_json_node_link(duplicate).is_err());
+    }
+
+    #[test]
+    fn reads_d3_nodes_without_data() {
+        #[derive(serde::Deserialize)]
+        struct Character {
+            id: String,
+            group: u32,
+        }
+
+        let json = r#"{
+            "nodes": [{"id": "Myriel", "group": 1}, {"id": "Napoleon", "group": 2}],
+            "links": [{"source": "Napoleon", "target": "Myriel", "value": 1}]
+        }"#;
+        let graph = UnGraph::<Character>::from_json_node_link(json).unwrap();
+        let napoleon = &graph[1];
+        assert_eq!((napoleon.id.as_str(), napoleon.group), ("Napoleon", 2));
+        assert_eq!(graph.edge_count(), 1);
+
+        let values = UnGraph::<serde_json::Value>::from_json_node_link(json).unwrap();
+        assert_eq!(values[0]["group"], 1);
+    }
+}
@@ -0,0 +1,196 @@
+//! Notifying callbacks of every change made to a graph.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::{Directed, EdgeType, Graph, GraphError, NodeIndex};
+
+/// A change made through an [`ObservedGraph`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GraphEvent {
+    NodeAdded(NodeIndex),
+    /// Every node after the removed one has moved down by one index.
+    NodeRemoved(NodeIndex),
+    EdgeAdded(NodeIndex, NodeIndex),
+    EdgeRemoved(NodeIndex, NodeIndex),
+}
+
+/// Identifies an observer registered with [`ObservedGraph::observe`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ObserverId(usize);
+
+type Observer<'a, T, E, Ty> = Box<dyn FnMut(&Graph<T, E, Ty>, GraphEvent) + 'a>;
+
+/// A graph that calls back its observers after every node and edge it adds
+/// or removes, so that caches and views built on it can be kept up to date.
+///
+/// Observers are called in the order they were registered, with the graph
+/// as it is after the change. Changes made to the underlying graph directly
+/// are not reported, so it is only available read-only.
+pub struct ObservedGraph<'a, T, E = (), Ty = Directed> {
+    graph: Graph<T, E, Ty>,
+    observers: Vec<(ObserverId, Observer<'a, T, E, Ty>)>,
+    next_id: usize,
+}
+
+impl<T, E, Ty: EdgeType> Default for ObservedGraph<'_, T, E, Ty> {
+    fn default() -> Self {
+        Self::new(Graph::default())
+    }
+}
+
+impl<T, E, Ty: EdgeType> From<Graph<T, E, Ty>> for ObservedGraph<'_, T, E, Ty> {
+    fn from(graph: Graph<T, E, Ty>) -> Self {
+        Self::new(graph)
+    }
+}
+
+impl<'a, T, E, Ty: EdgeType> ObservedGraph<'a, T, E, Ty> {
+    pub fn new(graph: Graph<T, E, Ty>) -> Self {
+        Self {
+            graph,
+            observers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn graph(&self) -> &Graph<T, E, Ty> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<T, E, Ty> {
+        self.graph
+    }
+
+    pub fn observe(
+        &mut self,
+        observer: impl FnMut(&Graph<T, E, Ty>, GraphEvent) + 'a,
+    ) -> ObserverId {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+        self.observers.push((id, Box::new(observer)));
+        id
+    }
+
+    /// Unregisters an observer, returning `false` if it was not registered.
+    pub fn unobserve(&mut self, id: ObserverId) -> bool {
+        let len = self.observers.len();
+        self.observers.retain(|(observer, _)| *observer != id);
+        self.observers.len() < len
+    }
+
+    pub fn add_node(&mut self, node: T) -> NodeIndex {
+        let idx = self.graph.add_node(node);
+        self.notify(GraphEvent::NodeAdded(idx));
+        idx
+    }
+
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        self.graph.add_edge(from, to, weight)?;
+        self.notify(GraphEvent::EdgeAdded(from, to));
+        Ok(())
+    }
+
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> Result<E, GraphError> {
+        let weight = self.graph.remove_edge(from, to)?;
+        self.notify(GraphEvent::EdgeRemoved(from, to));
+        Ok(weight)
+    }
+
+    /// Removes the node like [`Graph::remove_node`]. Observers first hear of
+    /// every edge removed with it, by the indices from before the removal,
+    /// then of the node.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove_node(&mut self, idx: NodeIndex) -> T {
+        let removed_edges: Vec<_> = self
+            .graph
+            .edges()
+            .filter(|edge| edge.source == idx || edge.target == idx)
+            .map(|edge| (edge.source, edge.target))
+            .collect();
+        let node = self.graph.remove_node(idx);
+
+        for (from, to) in removed_edges {
+            self.notify(GraphEvent::EdgeRemoved(from, to));
+        }
+        self.notify(GraphEvent::NodeRemoved(idx));
+        node
+    }
+
+    fn notify(&mut self, event: GraphEvent) {
+        for (_, observer) in self.observers.iter_mut() {
+            observer(&self.graph, event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+
+    use super::{GraphEvent, ObservedGraph};
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn observers_see_every_change() {
+        let events = RefCell::new(Vec::new());
+        let degree_sum = RefCell::new(0);
+        let mut graph = ObservedGraph::new(DiGraph::new());
+        let logger = graph.observe(|_, event| events.borrow_mut().push(event));
+        graph.observe(|graph, _| *degree_sum.borrow_mut() = graph.edge_count() * 2);
+
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(c, a, ()).unwrap();
+        assert!(graph.add_edge(a, a, ()).is_err());
+        assert_eq!(*degree_sum.borrow(), 4);
+        assert_eq!(graph.remove_node(a), "a");
+        assert_eq!(*degree_sum.borrow(), 0);
+
+        assert!(graph.unobserve(logger));
+        assert!(!graph.unobserve(logger));
+        graph.add_node("d");
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                GraphEvent::NodeAdded(a),
+                GraphEvent::NodeAdded(b),
+                GraphEvent::NodeAdded(c),
+                GraphEvent::EdgeAdded(a, b),
+                GraphEvent::EdgeAdded(c, a),
+                GraphEvent::EdgeRemoved(a, b),
+                GraphEvent::EdgeRemoved(c, a),
+                GraphEvent::NodeRemoved(a),
+            ]
+        );
+    }
+
+    #[test]
+    fn undirected_removals() {
+        let mut events = Vec::new();
+        let mut graph = ObservedGraph::new(UnGraph::<u8>::path_graph(3));
+        graph.observe(|_, event| events.push(event));
+        let n = NodeIndex::new;
+        assert_eq!(graph.remove_edge(n(1), n(0)), Ok(()));
+        graph.remove_node(n(2));
+        drop(graph);
+
+        assert_eq!(
+            events,
+            vec![
+                GraphEvent::EdgeRemoved(n(1), n(0)),
+                GraphEvent::EdgeRemoved(n(1), n(2)),
+                GraphEvent::NodeRemoved(n(2)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+
+/// The lengths of the adjacency lists as a Fenwick tree, so that the number
+/// of edges stored before a node's list, and so the [`crate::EdgeIndex`] of
+/// its first edge, is found without adding up every list before it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct EdgeOffsets {
+    /// The entry for node `i` holds the total length of the lists of the
+    /// `lowest_bit(i + 1)` nodes up to and including `i`.
+    tree: Vec<usize>,
+}
+
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl EdgeOffsets {
+    pub(crate) fn with_capacity(nodes: usize) -> Self {
+        Self {
+            tree: Vec::with_capacity(nodes),
+        }
+    }
+
+    pub(crate) fn from_lens(lens: impl IntoIterator<Item = usize>) -> Self {
+        let mut tree: Vec<_> = lens.into_iter().collect();
+        for i in 1..=tree.len() {
+            let parent = i + lowest_bit(i);
+            if parent <= tree.len() {
+                tree[parent - 1] += tree[i - 1];
+            }
+        }
+        Self { tree }
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.tree.reserve(additional);
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.tree.shrink_to_fit();
+    }
+
+    /// Adds a node with an empty adjacency list after every other node.
+    pub(crate) fn push(&mut self) {
+        let i = self.tree.len() + 1;
+        let covered = self.offset(i - 1) - self.offset(i - lowest_bit(i));
+        self.tree.push(covered);
+    }
+
+    pub(crate) fn grow(&mut self, node: usize, by: usize) {
+        let mut i = node + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] += by;
+            i += lowest_bit(i);
+        }
+    }
+
+    pub(crate) fn shrink(&mut self, node: usize, by: usize) {
+        let mut i = node + 1;
+        while i <= self.tree.len() {
+            self.tree[i - 1] -= by;
+            i += lowest_bit(i);
+        }
+    }
+
+    /// The total length of the lists of the nodes before `node`.
+    pub(crate) fn offset(&self, node: usize) -> usize {
+        let mut sum = 0;
+        let mut i = node;
+        while i > 0 {
+            sum += self.tree[i - 1];
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EdgeOffsets;
+
+    #[test]
+    fn prefix_sums() {
+        let lens = [3, 0, 2, 5, 1, 0, 4];
+        let built = EdgeOffsets::from_lens(lens);
+        let mut pushed = EdgeOffsets::default();
+        for (node, &len) in lens.iter().enumerate() {
+            pushed.push();
+            pushed.grow(node, len + 1);
+            pushed.shrink(node, 1);
+        }
+
+        assert!(built == pushed);
+        for node in 0..=lens.len() {
+            assert_eq!(built.offset(node), lens[..node].iter().sum::<usize>());
+        }
+    }
+}
@@ -0,0 +1,879 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::offsets::EdgeOffsets;
+use crate::{
+    Edge, EdgeData, EdgeIndex, EdgeRef, EdgeReserve, EdgeType, Graph, GraphError, NodeIndex,
+};
+
+impl<T: Clone, E: Clone, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Copies the given nodes and the edges between them into a new graph,
+    /// in the order they are listed. Repeated nodes are only copied once.
+    ///
+    /// Also returns the new index of every node, by old node index, or `None`
+    /// for nodes that were left out.
+    ///
+    /// Panics if any of the nodes is out of bounds.
+    pub fn subgraph(&self, nodes: &[NodeIndex]) -> (Self, Vec<Option<NodeIndex>>) {
+        let mut mapping = vec![None; self.nodes.len()];
+        let mut kept = Vec::with_capacity(nodes.len());
+        let mut subgraph = Self {
+            nodes: Vec::with_capacity(nodes.len()),
+            edges: Vec::with_capacity(nodes.len()),
+            edge_count: 0,
+            edge_offsets: EdgeOffsets::with_capacity(nodes.len()),
+            edge_reserve: EdgeReserve::default(),
+            in_degrees: Vec::with_capacity(nodes.len()),
+            incoming: None,
+            allows_self_loops: self.allows_self_loops,
+            edge_type: PhantomData,
+        };
+        for &node in nodes {
+            if node.index() >= self.nodes.len() {
+                panic!(
+                    "index node out of range: node is {}, but len is {}",
+                    node,
+                    self.nodes.len(),
+                );
+            }
+            if mapping[node.index()].is_none() {
+                mapping[node.index()] = Some(subgraph.add_node(self.nodes[node.index()].clone()));
+                kept.push(node);
+            }
+        }
+
+        for (source, node) in kept.into_iter().enumerate() {
+            let edge_data: EdgeData<E> = self.edges[node.index()]
+                .iter()
+                .filter_map(|edge| {
+                    Some(Edge {
+                        target: mapping[edge.target.index()]?,
+                        weight: edge.weight.clone(),
+                    })
+                })
+                .collect();
+            for edge in edge_data.iter() {
+                subgraph.in_degrees[edge.target.index()] += 1;
+            }
+            subgraph.edge_count += edge_data.len();
+            subgraph.edges[source] = edge_data;
+        }
+        subgraph.rebuild_edge_offsets();
+        if self.incoming.is_some() {
+            subgraph.rebuild_incoming();
+        }
+
+        (subgraph, mapping)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Appends the nodes and edges of `other`, returning the new index of
+    /// every node of `other`, by its old index. The self-loops of `other` are
+    /// dropped unless this graph allows them.
+    pub fn merge(&mut self, other: Self) -> Vec<NodeIndex> {
+        let offset = self.nodes.len();
+        let allows_self_loops = self.allows_self_loops;
+        self.nodes.extend(other.nodes);
+        self.edges.extend(
+            other
+                .edges
+                .into_iter()
+                .enumerate()
+                .map(|(source, edge_data)| {
+                    edge_data
+                        .into_iter()
+                        .filter(|edge| allows_self_loops || edge.target.index() != source)
+                        .map(|edge| Edge {
+                            target: NodeIndex::new(edge.target.index() + offset),
+                            weight: edge.weight,
+                        })
+                        .collect::<EdgeData<E>>()
+                }),
+        );
+        self.recount_edges();
+
+        (offset..self.nodes.len()).map(NodeIndex::new).collect()
+    }
+
+    /// Adds the nodes and edges of `other`, treating nodes with the same `key`
+    /// as one. Returns the new index of every node of `other`, by its old
+    /// index.
+    ///
+    /// The payload of a node of `other` is dropped if a node with its key is
+    /// already in the graph. Edges that are already in the graph, or that
+    /// would connect a node to itself, are dropped as well.
+    pub fn union<K: Ord>(&mut self, other: Self, mut key: impl FnMut(&T) -> K) -> Vec<NodeIndex> {
+        let mut indices = BTreeMap::new();
+        for (idx, node) in self.nodes.iter().enumerate() {
+            indices.entry(key(node)).or_insert(NodeIndex::new(idx));
+        }
+
+        let mapping: Vec<_> = other
+            .nodes
+            .into_iter()
+            .map(|node| {
+                let node_key = key(&node);
+                match indices.get(&node_key) {
+                    Some(&idx) => idx,
+                    None => {
+                        let idx = self.add_node(node);
+                        indices.insert(node_key, idx);
+                        idx
+                    }
+                }
+            })
+            .collect();
+
+        for (source, edge_data) in other.edges.into_iter().enumerate() {
+            let source = mapping[source];
+            for edge in edge_data {
+                let target = mapping[edge.target.index()];
+                if (source != target || self.allows_self_loops)
+                    && self.edge_weight(source, target).is_none()
+                {
+                    self.edges[source.index()].push(Edge {
+                        target,
+                        weight: edge.weight,
+                    });
+                    self.edge_count += 1;
+                    self.edge_offsets.grow(source.index(), 1);
+                    self.in_degrees[target.index()] += 1;
+                    self.index_incoming(source, target);
+                }
+            }
+        }
+
+        mapping
+    }
+}
+
+impl<T: Clone, E: Default, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Builds the graph with the same nodes and an edge of default weight
+    /// wherever this one has none, in either direction for undirected graphs.
+    /// Self-loops are never added.
+    pub fn complement(&self) -> Self {
+        let len = self.nodes.len();
+        let mut complement = Self::with_capacity(len, 0).allow_self_loops(self.allows_self_loops);
+        for node in self.nodes.iter() {
+            complement.add_node(node.clone());
+        }
+
+        let mut connected = vec![false; len];
+        for source in 0..len {
+            for target in self.successors(source) {
+                connected[target] = true;
+            }
+            let targets = if Ty::is_directed() { 0 } else { source + 1 };
+            for (target, &connected) in connected.iter().enumerate().skip(targets) {
+                if target != source && !connected {
+                    complement
+                        .add_edge(NodeIndex::new(source), NodeIndex::new(target), E::default())
+                        .expect("both nodes exist and differ");
+                }
+            }
+            connected.fill(false);
+        }
+        complement
+    }
+}
+
+impl<T: Clone, E: Clone, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Copies the graph, keeping only the edges whose endpoints `other` also
+    /// connects. Edges keep their weights from this graph, and are matched in
+    /// either direction in undirected graphs.
+    ///
+    /// Panics if the graphs do not have the same number of nodes.
+    pub fn intersection<U, F>(&self, other: &Graph<U, F, Ty>) -> Self {
+        self.filter_edges_by(other, true)
+    }
+
+    /// Copies the graph, keeping only the edges whose endpoints `other` does
+    /// not connect. Edges are matched in either direction in undirected
+    /// graphs.
+    ///
+    /// Panics if the graphs do not have the same number of nodes.
+    pub fn difference<U, F>(&self, other: &Graph<U, F, Ty>) -> Self {
+        self.filter_edges_by(other, false)
+    }
+
+    fn filter_edges_by<U, F>(&self, other: &Graph<U, F, Ty>, in_other: bool) -> Self {
+        if self.nodes.len() != other.nodes.len() {
+            panic!(
+                "graphs have different nodes: len is {}, but other len is {}",
+                self.nodes.len(),
+                other.nodes.len(),
+            );
+        }
+
+        let mut graph = self.map_ref(|_, node| node.clone());
+        graph.retain_edges(|edge| other.contains_edge(edge.source(), edge.target()) == in_other);
+        graph
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Replaces every node payload with `f(index, payload)`, keeping the
+    /// edges as they are.
+    pub fn map<U>(self, mut f: impl FnMut(NodeIndex, T) -> U) -> Graph<U, E, Ty> {
+        Graph {
+            nodes: self
+                .nodes
+                .into_iter()
+                .enumerate()
+                .map(|(index, node)| f(NodeIndex::new(index), node))
+                .collect(),
+            edges: self.edges,
+            edge_count: self.edge_count,
+            edge_offsets: self.edge_offsets,
+            edge_reserve: self.edge_reserve,
+            in_degrees: self.in_degrees,
+            incoming: self.incoming,
+            allows_self_loops: self.allows_self_loops,
+            edge_type: PhantomData,
+        }
+    }
+}
+
+impl<T, E: Clone, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Like [`Graph::map`], but borrows the graph and copies the edges.
+    pub fn map_ref<U>(&self, mut f: impl FnMut(NodeIndex, &T) -> U) -> Graph<U, E, Ty> {
+        Graph {
+            nodes: self
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| f(NodeIndex::new(index), node))
+                .collect(),
+            edges: self.edges.clone(),
+            edge_count: self.edge_count,
+            edge_offsets: self.edge_offsets.clone(),
+            edge_reserve: self.edge_reserve,
+            in_degrees: self.in_degrees.clone(),
+            incoming: self.incoming.clone(),
+            allows_self_loops: self.allows_self_loops,
+            edge_type: PhantomData,
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Removes every node for which `keep` returns false, along with its
+    /// edges, shifting the remaining nodes down in a single pass.
+    ///
+    /// Returns the new index of every node, by old node index, or `None` for
+    /// nodes that were removed.
+    pub fn retain_nodes(
+        &mut self,
+        mut keep: impl FnMut(NodeIndex, &T) -> bool,
+    ) -> Vec<Option<NodeIndex>> {
+        let mut mapping = Vec::with_capacity(self.nodes.len());
+        let mut kept = 0;
+        for (index, node) in self.nodes.iter().enumerate() {
+            if keep(NodeIndex::new(index), node) {
+                mapping.push(Some(NodeIndex::new(kept)));
+                kept += 1;
+            } else {
+                mapping.push(None);
+            }
+        }
+
+        let mut index = 0;
+        self.nodes.retain(|_| {
+            index += 1;
+            mapping[index - 1].is_some()
+        });
+        self.remap_edges(&mapping);
+        mapping
+    }
+
+    /// Removes every node in `indices`, along with its edges, shifting the
+    /// remaining nodes down in a single pass. Repeated indices are ignored.
+    ///
+    /// Returns the removed nodes in index order, and the new index of every
+    /// node by old node index, or `None` for nodes that were removed.
+    ///
+    /// Panics if any index is out of bounds.
+    pub fn remove_nodes<N: Into<NodeIndex>>(
+        &mut self,
+        indices: impl IntoIterator<Item = N>,
+    ) -> (Vec<T>, Vec<Option<NodeIndex>>) {
+        let len = self.nodes.len();
+        let mut removed = vec![false; len];
+        for index in indices {
+            let index = index.into().index();
+            assert!(
+                index < len,
+                "index index out of range: index is {}, but len is {}",
+                index,
+                len
+            );
+            removed[index] = true;
+        }
+
+        let mut mapping = Vec::with_capacity(len);
+        let mut kept = 0;
+        for &removed in &removed {
+            if removed {
+                mapping.push(None);
+            } else {
+                mapping.push(Some(NodeIndex::new(kept)));
+                kept += 1;
+            }
+        }
+
+        let (nodes, removed): (Vec<_>, Vec<_>) = core::mem::take(&mut self.nodes)
+            .into_iter()
+            .zip(removed)
+            .partition(|(_, removed)| !removed);
+        self.nodes = nodes.into_iter().map(|(node, _)| node).collect();
+        self.remap_edges(&mapping);
+        (removed.into_iter().map(|(node, _)| node).collect(), mapping)
+    }
+
+    /// Like [`Graph::map`], but drops the nodes for which `f` returns `None`,
+    /// along with their edges.
+    pub fn filter_map<U>(self, mut f: impl FnMut(NodeIndex, T) -> Option<U>) -> Graph<U, E, Ty> {
+        let mut mapping = Vec::with_capacity(self.nodes.len());
+        let mut nodes = Vec::new();
+        for (index, node) in self.nodes.into_iter().enumerate() {
+            match f(NodeIndex::new(index), node) {
+                Some(node) => {
+                    mapping.push(Some(NodeIndex::new(nodes.len())));
+                    nodes.push(node);
+                }
+                None => mapping.push(None),
+            }
+        }
+
+        let mut graph = Graph {
+            nodes,
+            edges: self.edges,
+            edge_count: 0,
+            edge_offsets: EdgeOffsets::default(),
+            edge_reserve: self.edge_reserve,
+            in_degrees: Vec::new(),
+            incoming: self.incoming,
+            allows_self_loops: self.allows_self_loops,
+            edge_type: PhantomData,
+        };
+        graph.remap_edges(&mapping);
+        graph
+    }
+
+    /// Removes every edge for which `keep` returns false. Edges are passed in
+    /// [`EdgeIndex`] order, with the index they had before any were removed.
+    pub fn retain_edges(&mut self, mut keep: impl FnMut(EdgeRef<'_, E>) -> bool) {
+        let mut index = 0;
+        for (source, edge_data) in self.edges.iter_mut().enumerate() {
+            edge_data.retain(|edge| {
+                index += 1;
+                keep(EdgeRef {
+                    index: EdgeIndex::new(index - 1),
+                    source: NodeIndex::new(source),
+                    target: edge.target,
+                    weight: &edge.weight,
+                })
+            });
+        }
+        self.recount_edges();
+    }
+
+    pub fn clear_edges(&mut self) {
+        for edge_data in self.edges.iter_mut() {
+            edge_data.clear();
+        }
+        self.recount_edges();
+    }
+
+    /// Fuses `b` into `a`, combining their payloads with `merge(a, b)`. Edges
+    /// to or from `b` are moved over to `a`, and edges between the two become
+    /// self-loops, or are dropped if self-loops are not allowed. Parallel
+    /// edges are kept, see [`Graph::dedup_edges`].
+    ///
+    /// As with [`Graph::remove_node`], the nodes after `b` shift down by one.
+    /// Returns the new index of the fused node.
+    ///
+    /// Panics if `a` or `b` is out of bounds, or if they are the same node.
+    pub fn merge_nodes(
+        &mut self,
+        a: NodeIndex,
+        b: NodeIndex,
+        merge: impl FnOnce(T, T) -> T,
+    ) -> NodeIndex {
+        let len = self.nodes.len();
+        for (name, idx) in [("a", a), ("b", b)] {
+            if idx.index() >= len {
+                panic!(
+                    "index {} out of range: {} is {}, but len is {}",
+                    name, name, idx, len,
+                );
+            }
+        }
+        if a == b {
+            panic!("cannot merge node {} with itself", a);
+        }
+
+        let moved = core::mem::take(&mut self.edges[b.index()]);
+        self.edges[a.index()].extend(moved);
+        for edge in self.edges.iter_mut().flatten() {
+            if edge.target == b {
+                edge.target = a;
+            }
+        }
+        if !self.allows_self_loops {
+            self.edges[a.index()].retain(|edge| edge.target != a);
+        }
+        self.recount_edges();
+
+        let payload = self.remove_node(b);
+        let a = if a > b {
+            NodeIndex::new(a.index() - 1)
+        } else {
+            a
+        };
+        let kept = self.nodes.remove(a.index());
+        self.nodes.insert(a.index(), merge(kept, payload));
+        a
+    }
+
+    /// Removes the edge from `from` to `to` and fuses its endpoints, like
+    /// [`Graph::merge_nodes`]. Any other edges between them become
+    /// self-loops, or are dropped if self-loops are not allowed.
+    ///
+    /// Returns the new index of the fused node, or fails with
+    /// [`GraphError::EdgeNotFound`] if there is no such edge.
+    pub fn contract_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        merge: impl FnOnce(T, T) -> T,
+    ) -> Result<NodeIndex, GraphError> {
+        self.remove_edge(from, to)?;
+        if from == to {
+            return Ok(from);
+        }
+        Ok(self.merge_nodes(from, to, merge))
+    }
+
+    /// Splits `idx` in two, adding a node with the payload returned by
+    /// `split`, which can take part of the payload of `idx` through the
+    /// mutable reference. Every edge to or from `idx` is passed to `moves`,
+    /// in [`EdgeIndex`] order, and the edges it returns true for move their
+    /// end at `idx` over to the new node. A self-loop that moves becomes a
+    /// self-loop of the new node.
+    ///
+    /// Edges are passed as they are stored, so in undirected graphs `idx` can
+    /// be either end. Returns the index of the new node, which comes after
+    /// every other node.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn split_node(
+        &mut self,
+        idx: NodeIndex,
+        split: impl FnOnce(&mut T) -> T,
+        mut moves: impl FnMut(EdgeRef<'_, E>) -> bool,
+    ) -> NodeIndex {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.nodes.len(),
+            );
+        }
+
+        let payload = split(&mut self.nodes[idx.index()]);
+        let new = self.add_node(payload);
+        let mut index = 0;
+        let mut moved = EdgeData::new();
+        for (source, edge_data) in self.edges.iter_mut().enumerate() {
+            let source = NodeIndex::new(source);
+            if source == idx {
+                for edge in core::mem::take(edge_data) {
+                    index += 1;
+                    let edge_ref = EdgeRef {
+                        index: EdgeIndex::new(index - 1),
+                        source,
+                        target: edge.target,
+                        weight: &edge.weight,
+                    };
+                    if moves(edge_ref) {
+                        moved.push(Edge {
+                            target: if edge.target == idx { new } else { edge.target },
+                            weight: edge.weight,
+                        });
+                    } else {
+                        edge_data.push(edge);
+                    }
+                }
+                continue;
+            }
+
+            for edge in edge_data.iter_mut() {
+                index += 1;
+                if edge.target != idx {
+                    continue;
+                }
+                let edge_ref = EdgeRef {
+                    index: EdgeIndex::new(index - 1),
+                    source,
+                    target: edge.target,
+                    weight: &edge.weight,
+                };
+                if moves(edge_ref) {
+                    edge.target = new;
+                }
+            }
+        }
+        self.edges[new.index()] = moved;
+        self.recount_edges();
+        new
+    }
+
+    /// Removes parallel edges, keeping the first edge between every pair of
+    /// nodes in [`EdgeIndex`] order. In undirected graphs, edges in either
+    /// direction count as parallel. Returns the number of edges removed.
+    pub fn dedup_edges(&mut self) -> usize {
+        let before = self.edge_count;
+        let mut seen = BTreeSet::new();
+        self.retain_edges(|edge| {
+            let (mut from, mut to) = (edge.source(), edge.target());
+            if !Ty::is_directed() && from > to {
+                (from, to) = (to, from);
+            }
+            seen.insert((from, to))
+        });
+        before - self.edge_count
+    }
+
+    /// Moves the edges over to the new node indices in `mapping`, dropping
+    /// those of removed nodes, once the nodes themselves have been removed.
+    pub(crate) fn remap_edges(&mut self, mapping: &[Option<NodeIndex>]) {
+        let edges = core::mem::take(&mut self.edges);
+        self.edges = edges
+            .into_iter()
+            .zip(mapping)
+            .filter(|(_, new)| new.is_some())
+            .map(|(edge_data, _)| {
+                edge_data
+                    .into_iter()
+                    .filter_map(|edge| {
+                        Some(Edge {
+                            target: mapping[edge.target.index()]?,
+                            weight: edge.weight,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        self.recount_edges();
+    }
+
+    /// Recomputes the edge count, the edge offsets, the in-degrees and the
+    /// incoming index from the adjacency lists, after they have been changed
+    /// in bulk.
+    pub(crate) fn recount_edges(&mut self) {
+        self.edge_count = self.edges.iter().map(Vec::len).sum();
+        self.rebuild_edge_offsets();
+        self.in_degrees = vec![0; self.nodes.len()];
+        for edge in self.edges.iter().flatten() {
+            self.in_degrees[edge.target.index()] += 1;
+        }
+        if self.incoming.is_some() {
+            self.rebuild_incoming();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Directed, EdgeIndex, EdgeType, Graph, NodeIndex, Undirected};
+
+    #[test]
+    fn subgraph() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 'a').unwrap();
+        graph.add_edge(n[1], n[2], 'b').unwrap();
+        graph.add_edge(n[2], n[0], 'c').unwrap();
+        graph.add_edge(n[3], n[2], 'd').unwrap();
+        graph.add_edge(n[3], n[4], 'e').unwrap();
+
+        let (subgraph, mapping) = graph.subgraph(&[n[3], n[2], n[0], n[3]]);
+        assert_eq!(subgraph.nodes().collect::<Vec<_>>(), vec![&3, &2, &0]);
+        assert_eq!(subgraph.to_string(), "3 -> 2\n2 -> 0\n");
+        assert_eq!(mapping[0], Some(n[2]));
+        assert_eq!(mapping[1], None);
+        assert_eq!(mapping[3], Some(n[0]));
+        assert_eq!(
+            subgraph.edge_weight(mapping[2].unwrap(), mapping[0].unwrap()),
+            Some(&'c')
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let mut first = Graph::new();
+        let a = first.add_node('a');
+        let b = first.add_node('b');
+        first.add_edge(a, b, ()).unwrap();
+
+        let mut second = Graph::new();
+        let c = second.add_node('c');
+        let d = second.add_node('d');
+        second.add_edge(d, c, ()).unwrap();
+
+        let mapping = first.merge(second);
+        assert_eq!(mapping, vec![NodeIndex::new(2), NodeIndex::new(3)]);
+        assert_eq!(first.to_string(), "a -> b\nd -> c\n");
+        assert_eq!(
+            first.get_edges_to(mapping[c.index()]),
+            Ok(vec![mapping[d.index()]])
+        );
+
+        let mut looped = Graph::new().allow_self_loops(true);
+        let e = looped.add_node('e');
+        looped.add_edge(e, e, ()).unwrap();
+        looped.add_edge(e, e, ()).unwrap();
+        let e = first.merge(looped.clone())[e.index()];
+        assert_eq!(first.self_loops().count(), 0);
+        assert_eq!((first.edge_count(), first.in_degree(e)), (2, 0));
+
+        let mapping = looped.merge(first);
+        assert_eq!(looped.self_loops().count(), 2);
+        assert_eq!(looped.edge_count(), 4);
+        assert_eq!(
+            looped.find_edge(mapping[3], mapping[2]),
+            Some(EdgeIndex::new(3))
+        );
+    }
+
+    #[test]
+    fn union() {
+        let mut first = Graph::new();
+        let a = first.add_node("a");
+        let b = first.add_node("b");
+        first.add_edge(a, b, 1).unwrap();
+
+        let mut second = Graph::new();
+        let b2 = second.add_node("B");
+        let c = second.add_node("c");
+        let a2 = second.add_node("A");
+        second.add_edge(a2, b2, 2).unwrap();
+        second.add_edge(b2, c, 3).unwrap();
+
+        let mapping = first.union(second, |name| name.to_lowercase());
+        assert_eq!(mapping, vec![b, NodeIndex::new(2), a]);
+        assert_eq!(first.to_string(), "a -> b\nb -> c\n");
+        assert_eq!(first.edge_weight(a, b), Some(&1));
+    }
+
+    #[test]
+    fn map() {
+        let mut graph = Graph::new();
+        let a = graph.add_node(2);
+        let b = graph.add_node(3);
+        graph.add_edge(a, b, "ab").unwrap();
+
+        let labels = graph.map_ref(|index, node| format!("{}:{}", index, node));
+        assert_eq!(labels.to_string(), "0:2 -> 1:3\n");
+        let squares = graph.map(|_, node| node * node);
+        assert_eq!(squares.nodes().copied().collect::<Vec<_>>(), vec![4, 9]);
+        assert_eq!(squares.edge_weight(a, b), Some(&"ab"));
+    }
+
+    #[test]
+    fn retain_nodes() {
+        let mut graph = Graph::new().track_incoming(true);
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 3)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        let odd = graph
+            .clone()
+            .filter_map(|_, node| (node % 2 == 1).then_some(node));
+        assert_eq!(odd.to_string(), "");
+        assert_eq!(odd.node_count(), 2);
+
+        let mapping = graph.retain_nodes(|index, _| index != n[1] && index != n[2]);
+        assert_eq!(
+            mapping,
+            vec![Some(n[0]), None, None, Some(n[1]), Some(n[2])]
+        );
+        assert_eq!(graph.to_string(), "0 -> 3\n3 -> 4\n4 -> 0\n");
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.in_degree(n[1]), 1);
+        assert_eq!(graph.get_edges_to(n[0]), Ok(vec![n[2]]));
+    }
+
+    #[test]
+    fn remove_nodes() {
+        let mut graph = Graph::new_undirected();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i * 10)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+
+        let (removed, mapping) = graph.remove_nodes([3, 1, 3]);
+        assert_eq!(removed, vec![10, 30]);
+        assert_eq!(
+            mapping,
+            vec![Some(n[0]), None, Some(n[1]), None, Some(n[2])]
+        );
+        assert_eq!(graph.to_string(), "40 -- 0\n");
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.degree(n[1]), 0);
+    }
+
+    #[test]
+    fn retain_edges() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 0.5).unwrap();
+        graph.add_edge(n[0], n[2], 2.0).unwrap();
+        graph.add_edge(n[2], n[1], 0.1).unwrap();
+
+        graph.retain_edges(|edge| *edge.weight() >= 0.5);
+        assert_eq!(graph.to_string(), "0 -> 1\n0 -> 2\n");
+        assert_eq!(graph.in_degree(n[1]), 1);
+        graph.retain_edges(|edge| edge.index().index() != 0);
+        assert_eq!(graph.to_string(), "0 -> 2\n");
+
+        graph.clear_edges();
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.in_degree(n[2]), 0);
+    }
+
+    fn labeled<Ty: EdgeType>(len: u8, edges: &[(usize, usize)]) -> Graph<u8, (), Ty> {
+        let mut graph: Graph<u8, (), Ty> = (0..len).collect();
+        for &(from, to) in edges {
+            graph
+                .add_edge(NodeIndex::new(from), NodeIndex::new(to), ())
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn complement() {
+        let graph = labeled::<Directed>(3, &[(0, 1), (1, 2)]);
+        assert_eq!(
+            graph.complement().to_string(),
+            "0 -> 2\n1 -> 0\n2 -> 0\n2 -> 1\n"
+        );
+
+        let undirected = labeled::<Undirected>(3, &[(0, 1), (2, 1)]);
+        let complement = undirected.complement();
+        assert_eq!(complement.to_string(), "0 -- 2\n");
+        assert_eq!(complement.complement().edge_count(), 2);
+    }
+
+    #[test]
+    fn intersection_and_difference() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], 'a').unwrap();
+        graph.add_edge(n[1], n[2], 'b').unwrap();
+        graph.add_edge(n[2], n[0], 'c').unwrap();
+        let other = Graph::<()>::from_edges([(0, 1), (0, 2)]).unwrap();
+
+        let both = graph.intersection(&other);
+        assert_eq!(both.to_string(), "0 -> 1\n");
+        assert_eq!(both.edge_weight(n[0], n[1]), Some(&'a'));
+        assert_eq!(graph.difference(&other).to_string(), "1 -> 2\n2 -> 0\n");
+
+        let undirected = labeled::<Undirected>(3, &[(0, 1), (1, 2)]);
+        let reversed = labeled::<Undirected>(3, &[(2, 1)]);
+        assert_eq!(undirected.intersection(&reversed).to_string(), "1 -- 2\n");
+        assert_eq!(undirected.difference(&reversed).to_string(), "0 -- 1\n");
+    }
+
+    #[test]
+    fn merge_nodes() {
+        let mut graph = labeled::<Directed>(4, &[(0, 1), (1, 2), (2, 0), (3, 2), (0, 2)]);
+        let merged = graph.merge_nodes(NodeIndex::new(2), NodeIndex::new(0), |a, b| a * 10 + b);
+        assert_eq!(merged, NodeIndex::new(1));
+        assert_eq!(graph.get_node(merged), Some(&20));
+        assert_eq!(graph.to_string(), "1 -> 20\n20 -> 1\n3 -> 20\n");
+        assert_eq!(graph.in_degree(merged), 2);
+
+        assert_eq!(graph.dedup_edges(), 0);
+        graph.add_edge(NodeIndex::new(2), merged, ()).unwrap();
+        assert_eq!(graph.dedup_edges(), 1);
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn contract_edge() {
+        let mut graph = labeled::<Undirected>(4, &[(0, 1), (1, 2), (2, 3), (3, 0), (1, 0)]);
+        graph = graph.allow_self_loops(true);
+        let merged = graph
+            .contract_edge(NodeIndex::new(1), NodeIndex::new(0), |a, b| a.max(b))
+            .unwrap();
+        assert_eq!(merged, NodeIndex::new(0));
+        assert_eq!(graph.to_string(), "1 -- 2\n1 -- 1\n2 -- 3\n3 -- 1\n");
+
+        // The triangle left has a parallel edge once 1 and 2 are contracted.
+        assert_eq!(graph.dedup_edges(), 0);
+        graph
+            .contract_edge(NodeIndex::new(1), NodeIndex::new(0), |a, _| a)
+            .unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.dedup_edges(), 1);
+        assert_eq!(
+            graph.contract_edge(NodeIndex::new(0), NodeIndex::new(0), |a, _| a),
+            Ok(NodeIndex::new(0))
+        );
+        assert!(graph
+            .contract_edge(NodeIndex::new(0), NodeIndex::new(0), |a, _| a)
+            .is_err());
+    }
+
+    #[test]
+    fn split_node() {
+        // Splitting a block of a control flow graph that loops on itself,
+        // so that the new half keeps the exit and the back edge.
+        let mut graph = labeled::<Directed>(4, &[(0, 1), (1, 2), (2, 1), (1, 3)]);
+        graph = graph.allow_self_loops(true);
+        graph
+            .add_edge(NodeIndex::new(1), NodeIndex::new(1), ())
+            .unwrap();
+        let mut seen = Vec::new();
+        let new = graph.split_node(
+            NodeIndex::new(1),
+            |payload| {
+                *payload += 10;
+                20
+            },
+            |edge| {
+                seen.push(edge.index().index());
+                edge.source().index() == 1 && edge.target().index() != 2
+            },
+        );
+
+        assert_eq!(new, NodeIndex::new(4));
+        assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+        assert_eq!(
+            graph.to_string(),
+            "0 -> 11\n11 -> 2\n2 -> 11\n20 -> 3\n20 -> 20\n"
+        );
+        assert_eq!(graph.in_degree(new), 1);
+        assert_eq!(graph.in_degree(NodeIndex::new(1)), 2);
+
+        let mut undirected = labeled::<Undirected>(3, &[(0, 1), (2, 1)]);
+        let new = undirected.split_node(
+            NodeIndex::new(1),
+            |&mut payload| payload,
+            |edge| edge.source().index() == 2,
+        );
+        assert_eq!(undirected.to_string(), "0 -- 1\n2 -- 1\n");
+        assert_eq!(
+            undirected.neighbors(new).collect::<Vec<_>>(),
+            vec![NodeIndex::new(2)]
+        );
+    }
+}
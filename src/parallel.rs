@@ -0,0 +1,219 @@
+//! Parallel iterators and algorithms, built on rayon.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::algo::pagerank::check_damping;
+use crate::{EdgeIndex, EdgeRef, EdgeType, Graph, NodeIndex};
+
+impl<T: Sync, E: Sync, Ty: EdgeType + Sync> Graph<T, E, Ty> {
+    pub fn par_nodes(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.nodes.par_iter()
+    }
+
+    /// Yields the same edges as [`Graph::edges`], in no particular order.
+    pub fn par_edges(&self) -> impl ParallelIterator<Item = EdgeRef<'_, E>> {
+        let mut offsets = Vec::with_capacity(self.edges.len());
+        let mut offset = 0;
+        for edge_data in self.edges.iter() {
+            offsets.push(offset);
+            offset += edge_data.len();
+        }
+
+        self.edges
+            .par_iter()
+            .zip(offsets)
+            .enumerate()
+            .flat_map_iter(|(source, (edge_data, offset))| {
+                edge_data
+                    .iter()
+                    .enumerate()
+                    .map(move |(position, edge)| EdgeRef {
+                        index: EdgeIndex::new(offset + position),
+                        source: NodeIndex::new(source),
+                        target: edge.target,
+                        weight: &edge.weight,
+                    })
+            })
+    }
+
+    /// Groups the nodes reachable from `start` by their distance from it,
+    /// expanding each level in parallel. Nodes within a level are sorted by
+    /// index.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn par_bfs_levels(&self, start: NodeIndex) -> Vec<Vec<NodeIndex>> {
+        if start.index() >= self.nodes.len() {
+            panic!(
+                "index start out of range: start is {}, but len is {}",
+                start,
+                self.nodes.len(),
+            );
+        }
+
+        let discovered: Vec<AtomicBool> = (0..self.nodes.len())
+            .map(|_| AtomicBool::new(false))
+            .collect();
+        discovered[start.index()].store(true, Ordering::Relaxed);
+
+        let mut levels = Vec::new();
+        let mut frontier = vec![start.index()];
+        while !frontier.is_empty() {
+            let mut next: Vec<usize> = frontier
+                .par_iter()
+                .flat_map_iter(|&node| self.successors(node))
+                .filter(|&next| !discovered[next].swap(true, Ordering::Relaxed))
+                .collect();
+            next.par_sort_unstable();
+            levels.push(frontier.into_iter().map(NodeIndex::new).collect());
+            frontier = next;
+        }
+        levels
+    }
+
+    /// Computes the same components as [`Graph::connected_components`] by
+    /// propagating the smallest node index across edges in parallel until
+    /// nothing changes.
+    pub fn par_connected_components(&self) -> Vec<Vec<NodeIndex>> {
+        let labels: Vec<AtomicUsize> = (0..self.nodes.len()).map(AtomicUsize::new).collect();
+
+        loop {
+            let changed = AtomicBool::new(false);
+            self.edges
+                .par_iter()
+                .enumerate()
+                .for_each(|(source, edge_data)| {
+                    for edge in edge_data {
+                        let target = edge.target.index();
+                        let source_label = labels[source].load(Ordering::Relaxed);
+                        let target_label = labels[target].load(Ordering::Relaxed);
+                        let (label, other) = if source_label < target_label {
+                            (source_label, target)
+                        } else {
+                            (target_label, source)
+                        };
+                        if labels[other].fetch_min(label, Ordering::Relaxed) > label {
+                            changed.store(true, Ordering::Relaxed);
+                        }
+                    }
+                });
+            if !changed.into_inner() {
+                break;
+            }
+        }
+
+        // Every component ends up labeled with its smallest node, so new
+        // labels show up in the same order as the components.
+        let mut component_of = vec![usize::MAX; self.nodes.len()];
+        let mut components: Vec<Vec<NodeIndex>> = Vec::new();
+        for (node, label) in labels.into_iter().enumerate() {
+            let label = label.into_inner();
+            if label == node {
+                component_of[node] = components.len();
+                components.push(Vec::new());
+            }
+            components[component_of[label]].push(NodeIndex::new(node));
+        }
+        components
+    }
+
+    /// Computes the same scores as [`Graph::pagerank`], updating the score of
+    /// every node in parallel.
+    pub fn par_pagerank(&self, damping: f64, tolerance: f64, max_iterations: usize) -> Vec<f64> {
+        check_damping(damping);
+        let len = self.nodes.len();
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let mut sources = vec![Vec::new(); len];
+        let mut out_degrees = vec![0; len];
+        for (node, out_degree) in out_degrees.iter_mut().enumerate() {
+            for target in self.successors(node) {
+                sources[target].push(node);
+                *out_degree += 1;
+            }
+        }
+
+        let mut ranks = vec![1.0 / len as f64; len];
+        for _ in 0..max_iterations {
+            let dangling: f64 = (0..len)
+                .into_par_iter()
+                .filter(|&node| out_degrees[node] == 0)
+                .map(|node| ranks[node])
+                .sum();
+            let base = (1.0 - damping + damping * dangling) / len as f64;
+            let next: Vec<f64> = sources
+                .par_iter()
+                .map(|sources| {
+                    let incoming: f64 = sources
+                        .iter()
+                        .map(|&source| ranks[source] / out_degrees[source] as f64)
+                        .sum();
+                    base + damping * incoming
+                })
+                .collect();
+
+            let change: f64 = next
+                .par_iter()
+                .zip(&ranks)
+                .map(|(a, b)| (a - b).abs())
+                .sum();
+            ranks = next;
+            if change < tolerance {
+                break;
+            }
+        }
+        ranks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::{Graph, NodeIndex};
+
+    fn get_test_graph() -> Graph<usize> {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..8).map(|i| graph.add_node(i)).collect();
+        for (from, to) in [(0, 3), (3, 1), (0, 5), (5, 1), (1, 6), (7, 2), (4, 2)] {
+            graph.add_edge(n[from], n[to], ()).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn parallel_iterators() {
+        let graph = get_test_graph();
+
+        assert_eq!(graph.par_nodes().sum::<usize>(), 28);
+        let mut edges: Vec<_> = graph.par_edges().collect();
+        edges.sort_by_key(|edge| edge.index());
+        assert_eq!(edges, graph.edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parallel_algorithms() {
+        let graph = get_test_graph();
+
+        let levels: Vec<Vec<usize>> = graph
+            .par_bfs_levels(NodeIndex::new(0))
+            .into_iter()
+            .map(|level| level.into_iter().map(NodeIndex::index).collect())
+            .collect();
+        assert_eq!(levels, vec![vec![0], vec![3, 5], vec![1], vec![6]]);
+
+        assert_eq!(
+            graph.par_connected_components(),
+            graph.connected_components()
+        );
+
+        let ranks = graph.pagerank(0.85, 1e-12, 100);
+        let par_ranks = graph.par_pagerank(0.85, 1e-12, 100);
+        for (rank, par_rank) in ranks.into_iter().zip(par_ranks) {
+            assert!((rank - par_rank).abs() < 1e-9);
+        }
+    }
+}
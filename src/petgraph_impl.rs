@@ -0,0 +1,100 @@
+//! Conversions to and from `petgraph::Graph`, which keep node indices and
+//! the order of the edges.
+
+use crate::{Directed, Graph, NodeIndex, Undirected};
+
+macro_rules! impl_petgraph_conversions {
+    ($ty:ident) => {
+        impl<T, E> From<Graph<T, E, $ty>> for petgraph::Graph<T, E, petgraph::$ty> {
+            fn from(graph: Graph<T, E, $ty>) -> Self {
+                let mut converted =
+                    petgraph::Graph::with_capacity(graph.nodes.len(), graph.edge_count);
+                for node in graph.nodes {
+                    converted.add_node(node);
+                }
+                for (source, edge_data) in graph.edges.into_iter().enumerate() {
+                    for edge in edge_data {
+                        converted.add_edge(
+                            petgraph::graph::NodeIndex::new(source),
+                            petgraph::graph::NodeIndex::new(edge.target.index()),
+                            edge.weight,
+                        );
+                    }
+                }
+                converted
+            }
+        }
+
+        /// The converted graph allows self-loops if `graph` has any.
+        impl<T, E> From<petgraph::Graph<T, E, petgraph::$ty>> for Graph<T, E, $ty> {
+            fn from(graph: petgraph::Graph<T, E, petgraph::$ty>) -> Self {
+                let (nodes, edges) = graph.into_nodes_edges();
+                let allows_self_loops = edges.iter().any(|edge| edge.source() == edge.target());
+
+                let mut converted = Graph::with_capacity(nodes.len(), edges.len())
+                    .allow_self_loops(allows_self_loops);
+                for node in nodes {
+                    converted.add_node(node.weight);
+                }
+                for edge in edges {
+                    let (source, target) = (edge.source().index(), edge.target().index());
+                    converted
+                        .add_edge(NodeIndex::new(source), NodeIndex::new(target), edge.weight)
+                        .expect("petgraph edges point at existing nodes");
+                }
+                converted
+            }
+        }
+    };
+}
+
+impl_petgraph_conversions!(Directed);
+impl_petgraph_conversions!(Undirected);
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn directed_round_trip() {
+        let mut graph = DiGraph::new().allow_self_loops(true);
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(c, a, 1).unwrap();
+        graph.add_edge(a, b, 2).unwrap();
+        graph.add_edge(b, b, 3).unwrap();
+
+        let converted = petgraph::Graph::from(graph.clone());
+        assert_eq!(converted.node_count(), 3);
+        assert_eq!(converted[petgraph::graph::NodeIndex::new(2)], 'c');
+        let edges: Vec<_> = converted
+            .raw_edges()
+            .iter()
+            .map(|edge| (edge.source().index(), edge.target().index(), edge.weight))
+            .collect();
+        assert_eq!(edges, vec![(0, 1, 2), (1, 1, 3), (2, 0, 1)]);
+        assert_eq!(
+            petgraph::algo::toposort(&converted, None).map_err(|cycle| cycle.node_id().index()),
+            Err(1)
+        );
+
+        let back = DiGraph::from(converted);
+        assert!(back == graph);
+    }
+
+    #[test]
+    fn undirected_conversion() {
+        let mut graph = petgraph::graph::UnGraph::<&str, ()>::new_undirected();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(b, a, ());
+
+        let converted = UnGraph::from(graph);
+        assert!(!converted.allows_self_loops());
+        assert_eq!(
+            converted.get_edges(NodeIndex::new(0)),
+            Ok(vec![NodeIndex::new(1)])
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+
+use rand::Rng;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+/// A random walk over a graph, yielding the start node and then one node per
+/// step, each picked among the successors of the one before. In undirected
+/// graphs every neighbor is a successor.
+///
+/// The walk goes on for as long as it is iterated, unless it reaches a node
+/// with no successor to pick, where it stops. Every edge is a separate
+/// choice, so parallel edges make their target more likely.
+pub struct RandomWalk<'a, T, E, Ty, R, W = fn(&E) -> f64> {
+    graph: &'a Graph<T, E, Ty>,
+    rng: R,
+    /// Picks uniformly when `None`.
+    weight: Option<W>,
+    /// The node yielded last, or the start before the first call.
+    current: Option<usize>,
+    started: bool,
+    weights: Vec<(usize, f64)>,
+}
+
+impl<'a, T, E, Ty: EdgeType, R: Rng> RandomWalk<'a, T, E, Ty, R> {
+    /// Starts a walk from `start` that picks every outgoing edge with the
+    /// same probability.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn new(graph: &'a Graph<T, E, Ty>, start: NodeIndex, rng: R) -> Self {
+        Self::with_weight(graph, start, rng, None)
+    }
+}
+
+impl<'a, T, E, Ty: EdgeType, R: Rng, W: FnMut(&E) -> f64> RandomWalk<'a, T, E, Ty, R, W> {
+    /// Starts a walk from `start` that picks every outgoing edge with a
+    /// probability proportional to `weight` of its payload. Edges weighing
+    /// zero, less or NaN are never picked.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn weighted(graph: &'a Graph<T, E, Ty>, start: NodeIndex, rng: R, weight: W) -> Self {
+        Self::with_weight(graph, start, rng, Some(weight))
+    }
+
+    fn with_weight(
+        graph: &'a Graph<T, E, Ty>,
+        start: NodeIndex,
+        rng: R,
+        weight: Option<W>,
+    ) -> Self {
+        if start.index() >= graph.nodes.len() {
+            panic!(
+                "index start out of range: start is {}, but len is {}",
+                start,
+                graph.nodes.len(),
+            );
+        }
+        Self {
+            graph,
+            rng,
+            weight,
+            current: Some(start.index()),
+            started: false,
+            weights: Vec::new(),
+        }
+    }
+
+    fn step(&mut self, node: usize) -> Option<usize> {
+        let Some(weight) = self.weight.as_mut() else {
+            let count = self.graph.successors(node).count();
+            if count == 0 {
+                return None;
+            }
+            return self
+                .graph
+                .successors(node)
+                .nth(self.rng.gen_range(0..count));
+        };
+
+        self.weights.clear();
+        let mut total = 0.0;
+        for (target, edge_weight) in self.graph.weighted_successors(node) {
+            let edge_weight = weight(edge_weight);
+            if edge_weight > 0.0 {
+                total += edge_weight;
+                self.weights.push((target, edge_weight));
+            }
+        }
+        let mut left = self.rng.gen::<f64>() * total;
+        for &(target, edge_weight) in self.weights.iter() {
+            if left < edge_weight {
+                return Some(target);
+            }
+            left -= edge_weight;
+        }
+        // Rounding can leave a sliver of the total past the last edge.
+        self.weights.last().map(|&(target, _)| target)
+    }
+}
+
+impl<T, E, Ty: EdgeType, R: Rng, W: FnMut(&E) -> f64> Iterator for RandomWalk<'_, T, E, Ty, R, W> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<NodeIndex> {
+        let node = self.current?;
+        if !self.started {
+            self.started = true;
+            return Some(NodeIndex::new(node));
+        }
+        self.current = self.step(node);
+        self.current.map(NodeIndex::new)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Walks `steps` random steps from `start`, picking every outgoing edge
+    /// with the same probability, and returns the nodes visited, starting
+    /// with `start`. The walk is cut short at a node with no successors.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn random_walk<R: Rng + ?Sized>(
+        &self,
+        start: NodeIndex,
+        steps: usize,
+        rng: &mut R,
+    ) -> Vec<NodeIndex> {
+        RandomWalk::new(self, start, rng).take(steps + 1).collect()
+    }
+
+    /// Like [`Graph::random_walk`], picking every outgoing edge with a
+    /// probability proportional to `weight` of its payload, as
+    /// [`RandomWalk::weighted`] does.
+    ///
+    /// Panics if `start` is out of bounds.
+    pub fn weighted_random_walk<R: Rng + ?Sized>(
+        &self,
+        start: NodeIndex,
+        steps: usize,
+        rng: &mut R,
+        weight: impl FnMut(&E) -> f64,
+    ) -> Vec<NodeIndex> {
+        RandomWalk::weighted(self, start, rng, weight)
+            .take(steps + 1)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::RandomWalk;
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn uniform_walks() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let path = DiGraph::<()>::path_graph(3);
+        assert_eq!(
+            path.random_walk(NodeIndex::new(0), 5, &mut rng),
+            vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)]
+        );
+        assert_eq!(
+            path.random_walk(NodeIndex::new(1), 0, &mut rng),
+            vec![NodeIndex::new(1)]
+        );
+
+        let cycle = UnGraph::<()>::cycle_graph(5);
+        let walk: Vec<_> = RandomWalk::new(&cycle, NodeIndex::new(0), &mut rng)
+            .take(100)
+            .collect();
+        assert_eq!(walk.len(), 100);
+        assert!(walk
+            .windows(2)
+            .all(|pair| cycle.contains_edge(pair[0], pair[1])));
+    }
+
+    #[test]
+    fn weighted_walks() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut graph = DiGraph::<(), f64>::with_capacity(4, 5);
+        let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+        for (from, to, weight) in [
+            (0, 1, 1.0),
+            (0, 2, 3.0),
+            (0, 3, 0.0),
+            (1, 0, 1.0),
+            (2, 0, 1.0),
+        ] {
+            graph.add_edge(n[from], n[to], weight).unwrap();
+        }
+
+        let mut visits = [0; 4];
+        for node in graph.weighted_random_walk(NodeIndex::new(0), 4000, &mut rng, |&w| w) {
+            visits[node.index()] += 1;
+        }
+        assert_eq!(visits[0], 2001);
+        assert_eq!(visits[3], 0);
+        assert!(visits[2] > 2 * visits[1] && visits[2] < 4 * visits[1]);
+    }
+}
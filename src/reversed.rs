@@ -0,0 +1,124 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::offsets::EdgeOffsets;
+use crate::{Edge, EdgeData, EdgeRef, EdgeReserve, EdgeType, Graph, NodeIndex};
+
+/// A view of a graph with the direction of every edge flipped, without
+/// copying it.
+pub struct Reversed<'a, T, E, Ty>(pub &'a Graph<T, E, Ty>);
+
+impl<T, E, Ty> Clone for Reversed<'_, T, E, Ty> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E, Ty> Copy for Reversed<'_, T, E, Ty> {}
+
+impl<'a, T, E, Ty: EdgeType> Reversed<'a, T, E, Ty> {
+    pub fn get_node(&self, node_idx: NodeIndex) -> Option<&'a T> {
+        self.0.get_node(node_idx)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &'a T> {
+        self.0.nodes()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = EdgeRef<'a, E>> {
+        self.0.edges().map(|edge| EdgeRef {
+            source: edge.target,
+            target: edge.source,
+            ..edge
+        })
+    }
+
+    /// Iterates over the nodes with an edge to `idx` in the underlying graph.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors(&self, idx: NodeIndex) -> impl Iterator<Item = NodeIndex> + 'a {
+        self.0.neighbors_incoming(idx)
+    }
+
+    /// Iterates over the nodes `idx` has an edge to in the underlying graph.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn neighbors_incoming(&self, idx: NodeIndex) -> impl Iterator<Item = NodeIndex> + 'a {
+        self.0.neighbors(idx)
+    }
+}
+
+impl<T: Clone, E: Clone, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Copies the graph with the direction of every edge flipped. Use
+    /// [`Graph::reversed_view`] to avoid the copy.
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![EdgeData::new(); self.nodes.len()];
+        for edge in self.edges() {
+            edges[edge.target.index()].push(Edge {
+                target: edge.source,
+                weight: edge.weight.clone(),
+            });
+        }
+
+        let reversed = Self {
+            nodes: self.nodes.clone(),
+            edge_offsets: EdgeOffsets::from_lens(edges.iter().map(Vec::len)),
+            edges,
+            edge_count: self.edge_count,
+            edge_reserve: EdgeReserve::default(),
+            in_degrees: self.edges.iter().map(Vec::len).collect(),
+            incoming: None,
+            allows_self_loops: self.allows_self_loops,
+            edge_type: PhantomData,
+        };
+        reversed.track_incoming(self.tracks_incoming())
+    }
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn reversed_view(&self) -> Reversed<'_, T, E, Ty> {
+        Reversed(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    #[test]
+    fn reversing() {
+        let mut graph = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 1).unwrap();
+        graph.add_edge(a, c, 2).unwrap();
+        graph.add_edge(c, b, 3).unwrap();
+
+        let reversed = graph.reversed();
+        assert_eq!(reversed.to_string(), "b -> a\nb -> c\nc -> a\n");
+        assert_eq!(reversed.edge_weight(b, c), Some(&3));
+        assert!(reversed.reversed() == graph);
+    }
+
+    #[test]
+    fn reversed_view() {
+        let mut graph = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(c, b, ()).unwrap();
+
+        let view = graph.reversed_view();
+        assert_eq!(view.neighbors(b).collect::<Vec<_>>(), vec![a, c]);
+        assert_eq!(view.neighbors_incoming(a).collect::<Vec<_>>(), vec![b]);
+        let edges: Vec<_> = view
+            .edges()
+            .map(|edge| (edge.source(), edge.target()))
+            .collect();
+        assert_eq!(edges, vec![(b, a), (b, c)]);
+        assert_eq!(view.get_node(c), Some(&'c'));
+    }
+}
@@ -0,0 +1,255 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+impl<T: Clone, E: Clone, Ty: EdgeType> Graph<T, E, Ty> {
+    /// Copies `k` nodes picked uniformly at random, or every node if there
+    /// are fewer, along with every edge between them. The sampled nodes keep
+    /// their relative order.
+    ///
+    /// Also returns the new index of every node, as [`Graph::subgraph`] does.
+    pub fn sample_nodes<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        rng: &mut R,
+    ) -> (Self, Vec<Option<NodeIndex>>) {
+        let len = self.nodes.len();
+        let mut nodes: Vec<_> = index::sample(rng, len, k.min(len)).into_vec();
+        nodes.sort_unstable();
+        self.sampled(nodes)
+    }
+
+    /// Picks `k` edges uniformly at random, or every edge if there are
+    /// fewer, and copies their endpoints along with every edge between them,
+    /// so the result can hold more edges than were picked. The sampled nodes
+    /// keep their relative order.
+    ///
+    /// Also returns the new index of every node, as [`Graph::subgraph`] does.
+    pub fn sample_edges<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        rng: &mut R,
+    ) -> (Self, Vec<Option<NodeIndex>>) {
+        let endpoints: Vec<_> = self
+            .edges()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect();
+        let picked = index::sample(rng, endpoints.len(), k.min(endpoints.len()));
+
+        let mut nodes: Vec<_> = picked
+            .into_iter()
+            .flat_map(|edge| {
+                let (source, target) = endpoints[edge];
+                [source, target]
+            })
+            .collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        self.sampled(nodes)
+    }
+
+    /// Samples the graph outward from `seeds` in `waves` rounds, where every
+    /// node reached in the last round brings in up to `neighbors` of its
+    /// neighbors not reached yet, picked at random. Copies every node reached
+    /// along with every edge between them, keeping their relative order.
+    ///
+    /// Edge direction is ignored, and every neighbor is as likely to be
+    /// picked however many edges lead to it. Also returns the new index of
+    /// every node, as [`Graph::subgraph`] does.
+    ///
+    /// Panics if any of the seeds is out of bounds.
+    pub fn snowball_sample<R: Rng + ?Sized>(
+        &self,
+        seeds: &[NodeIndex],
+        neighbors: usize,
+        waves: usize,
+        rng: &mut R,
+    ) -> (Self, Vec<Option<NodeIndex>>) {
+        let adjacency = self.simple_adjacency();
+        let mut reached = vec![false; adjacency.len()];
+        let mut wave = Vec::new();
+        for &seed in seeds {
+            self.check_sample_seed(seed);
+            if !core::mem::replace(&mut reached[seed.index()], true) {
+                wave.push(seed.index());
+            }
+        }
+
+        let mut candidates = Vec::new();
+        for _ in 0..waves {
+            let mut next = Vec::new();
+            for node in wave {
+                candidates.clear();
+                candidates.extend(adjacency[node].iter().filter(|&&other| !reached[other]));
+                let (picked, _) = candidates.partial_shuffle(rng, neighbors);
+                for &other in picked.iter() {
+                    if !core::mem::replace(&mut reached[other], true) {
+                        next.push(other);
+                    }
+                }
+            }
+            wave = next;
+        }
+        self.sampled(reached_nodes(&reached))
+    }
+
+    /// Samples `k` nodes, or every node if there are fewer, by forest fire:
+    /// a fire starts at a random node and spreads to a random number of its
+    /// neighbors not burned yet, then from each of those in turn, and so on.
+    /// The number of neighbors is geometrically distributed with mean
+    /// `burn / (1 - burn)`. Whenever the fire dies out before `k` nodes are
+    /// burned, a new one starts at a random node. Copies every burned node
+    /// along with every edge between them, keeping their relative order.
+    ///
+    /// Edge direction is ignored, and every neighbor is as likely to be
+    /// picked however many edges lead to it. Also returns the new index of
+    /// every node, as [`Graph::subgraph`] does.
+    ///
+    /// Panics unless `0 <= burn < 1`.
+    pub fn forest_fire_sample<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        burn: f64,
+        rng: &mut R,
+    ) -> (Self, Vec<Option<NodeIndex>>) {
+        if !(0.0..1.0).contains(&burn) {
+            panic!(
+                "burn out of range: burn is {}, but must be at least 0 and less than 1",
+                burn
+            );
+        }
+
+        let adjacency = self.simple_adjacency();
+        let k = k.min(adjacency.len());
+        let mut burned = vec![false; adjacency.len()];
+        let mut count = 0;
+        let mut queue = VecDeque::new();
+        let mut candidates = Vec::new();
+        while count < k {
+            let start = loop {
+                let start = rng.gen_range(0..adjacency.len());
+                if !burned[start] {
+                    break start;
+                }
+            };
+            burned[start] = true;
+            count += 1;
+            queue.push_back(start);
+
+            while let Some(node) = queue.pop_front() {
+                let mut spread = 0;
+                while rng.gen_bool(burn) {
+                    spread += 1;
+                }
+                candidates.clear();
+                candidates.extend(adjacency[node].iter().filter(|&&other| !burned[other]));
+                let (picked, _) = candidates.partial_shuffle(rng, spread.min(k - count));
+                for &other in picked.iter() {
+                    if !core::mem::replace(&mut burned[other], true) {
+                        count += 1;
+                        queue.push_back(other);
+                    }
+                }
+            }
+        }
+        self.sampled(reached_nodes(&burned))
+    }
+
+    fn sampled(&self, nodes: Vec<usize>) -> (Self, Vec<Option<NodeIndex>>) {
+        let nodes: Vec<_> = nodes.into_iter().map(NodeIndex::new).collect();
+        self.subgraph(&nodes)
+    }
+
+    fn check_sample_seed(&self, seed: NodeIndex) {
+        if seed.index() >= self.nodes.len() {
+            panic!(
+                "index seed out of range: seed is {}, but len is {}",
+                seed,
+                self.nodes.len(),
+            );
+        }
+    }
+}
+
+fn reached_nodes(reached: &[bool]) -> Vec<usize> {
+    (0..reached.len()).filter(|&node| reached[node]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{DiGraph, NodeIndex, UnGraph};
+
+    #[test]
+    fn uniform_samples() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let graph = UnGraph::<()>::complete_graph(10);
+
+        let (sample, mapping) = graph.sample_nodes(4, &mut rng);
+        assert_eq!((sample.node_count(), sample.edge_count()), (4, 6));
+        assert_eq!(mapping.iter().flatten().count(), 4);
+        assert_eq!(graph.sample_nodes(20, &mut rng).0.node_count(), 10);
+
+        let (sample, _) = graph.sample_edges(1, &mut rng);
+        assert_eq!((sample.node_count(), sample.edge_count()), (2, 1));
+        // Two edges span three or four nodes, and every pair among them is
+        // connected in the sample too.
+        let (sample, _) = graph.sample_edges(2, &mut rng);
+        let n = sample.node_count();
+        assert!(n == 3 || n == 4);
+        assert_eq!(sample.edge_count(), n * (n - 1) / 2);
+    }
+
+    #[test]
+    fn spreading_samples() {
+        let mut rng = StdRng::seed_from_u64(7);
+        // Edges point toward the seed, so the snowball has to follow them
+        // backwards.
+        let graph = DiGraph::<()>::from_edges([(1, 0), (2, 0), (3, 1), (4, 1), (5, 3)]).unwrap();
+
+        let (sample, mapping) = graph.snowball_sample(&[NodeIndex::new(0)], 5, 2, &mut rng);
+        assert_eq!(sample.node_count(), 5);
+        assert_eq!(sample.edge_count(), 4);
+        assert_eq!(mapping[5], None);
+        let (sample, _) = graph.snowball_sample(&[NodeIndex::new(0)], 1, 1, &mut rng);
+        assert_eq!(sample.node_count(), 2);
+
+        let path = UnGraph::<()>::path_graph(100);
+        let (sample, _) = path.forest_fire_sample(30, 0.7, &mut rng);
+        assert_eq!(sample.node_count(), 30);
+        assert!(sample.edge_count() > 0);
+        assert_eq!(
+            path.forest_fire_sample(30, 0.0, &mut rng).0.node_count(),
+            30
+        );
+    }
+
+    #[test]
+    fn antiparallel_edges_count_once() {
+        let complete = DiGraph::<()>::complete_graph(20);
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (sample, _) = complete.forest_fire_sample(15, 0.9, &mut rng);
+            assert_eq!(sample.node_count(), 15);
+        }
+
+        // Node 1 is behind two edges and node 2 behind one, but both are
+        // picked about half the time.
+        let graph = DiGraph::<()>::from_edges([(0, 1), (1, 0), (0, 2)]).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        let picked_one = (0..400)
+            .filter(|_| {
+                let (_, mapping) = graph.snowball_sample(&[NodeIndex::new(0)], 1, 1, &mut rng);
+                mapping[1].is_some()
+            })
+            .count();
+        assert!((160..240).contains(&picked_one));
+    }
+}
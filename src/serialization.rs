@@ -0,0 +1,117 @@
+//! Graphs are serialized as a list of nodes followed by a list of
+//! `(source, target, weight)` edges, for example in JSON:
+//!
+//! ```json
+//! {"nodes":["a","b"],"edges":[[0,1,2.5]]}
+//! ```
+//!
+//! The edge type is not part of the format, so the same data can be loaded
+//! as either a directed or an undirected graph. Graphs that allow self-loops
+//! also carry `"self_loops":true`.
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{EdgeType, Graph, NodeIndex};
+
+#[derive(serde::Serialize)]
+#[serde(rename = "Graph")]
+struct GraphRef<'a, T, E> {
+    nodes: &'a [T],
+    edges: Vec<(NodeIndex, NodeIndex, &'a E)>,
+    #[serde(skip_serializing_if = "is_false")]
+    self_loops: bool,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "Graph")]
+struct GraphOwned<T, E> {
+    nodes: Vec<T>,
+    edges: Vec<(NodeIndex, NodeIndex, E)>,
+    #[serde(default)]
+    self_loops: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl<T: Serialize, E: Serialize, Ty: EdgeType> Serialize for Graph<T, E, Ty> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let edges = self
+            .edges()
+            .map(|edge| (edge.source(), edge.target(), edge.weight()))
+            .collect();
+
+        GraphRef {
+            nodes: &self.nodes,
+            edges,
+            self_loops: self.allows_self_loops,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, E, Ty> Deserialize<'de> for Graph<T, E, Ty>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+    Ty: EdgeType,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let GraphOwned {
+            nodes,
+            edges,
+            self_loops,
+        } = GraphOwned::deserialize(deserializer)?;
+
+        let mut graph = Graph::default().allow_self_loops(self_loops);
+        for node in nodes {
+            graph.add_node(node);
+        }
+        for (source, target, weight) in edges {
+            graph
+                .add_edge(source, target, weight)
+                .map_err(de::Error::custom)?;
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, UnGraph};
+
+    #[test]
+    fn json_round_trip() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(a, b, 2.5).unwrap();
+        graph.add_edge(c, a, 1.0).unwrap();
+
+        let json = serde_json::to_string(&graph).unwrap();
+        assert_eq!(
+            json,
+            r#"{"nodes":["a","b","c"],"edges":[[0,1,2.5],[2,0,1.0]]}"#
+        );
+        assert!(serde_json::from_str::<Graph<String, f64>>(&json).unwrap() == graph);
+
+        let undirected: UnGraph<String, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(undirected.get_edges(a), Ok(vec![b, c]));
+    }
+
+    #[test]
+    fn rejects_invalid_edges() {
+        let json = r#"{"nodes":[1,2],"edges":[[0,2,null]]}"#;
+        assert!(serde_json::from_str::<Graph<i32>>(json).is_err());
+
+        let json = r#"{"nodes":[1],"edges":[[0,0,null]]}"#;
+        assert!(serde_json::from_str::<Graph<i32>>(json).is_err());
+        let json = r#"{"nodes":[1],"edges":[[0,0,null]],"self_loops":true}"#;
+        let graph: Graph<i32> = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&graph).unwrap(), json);
+    }
+}
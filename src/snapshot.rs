@@ -0,0 +1,335 @@
+//! A compact binary format for persisting large graphs, independent of
+//! serde.
+//!
+//! A snapshot starts with the magic bytes `VGRF` and a version byte, then a
+//! flags byte and the node count as a varint, followed by every node payload.
+//! Each node's adjacency list follows as a varint length and, for every edge,
+//! the zigzag varint difference between its target and the previous target
+//! (the source node for the first edge) and its weight. Payloads and weights
+//! are written by a [`Codec`].
+//!
+//! Like the serde format, the edge type is not stored, so the same snapshot
+//! can be loaded as either a directed or an undirected graph.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use crate::{EdgeType, Graph, GraphError, NodeIndex};
+
+const MAGIC: &[u8; 4] = b"VGRF";
+const VERSION: u8 = 1;
+const SELF_LOOPS: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SnapshotError {
+    /// The input does not start with the snapshot magic bytes.
+    NotASnapshot,
+    UnsupportedVersion(u8),
+    UnexpectedEnd,
+    /// A codec rejected its input.
+    InvalidValue(String),
+    InvalidEdge(GraphError),
+    TrailingBytes(usize),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::NotASnapshot => write!(f, "input is not a graph snapshot"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            SnapshotError::UnexpectedEnd => write!(f, "unexpected end of snapshot"),
+            SnapshotError::InvalidValue(message) => write!(f, "invalid value: {}", message),
+            SnapshotError::InvalidEdge(err) => write!(f, "invalid edge: {}", err),
+            SnapshotError::TrailingBytes(len) => {
+                write!(f, "{} unexpected bytes after snapshot", len)
+            }
+        }
+    }
+}
+
+impl core::error::Error for SnapshotError {}
+
+/// Writes values of type `X` to a snapshot and reads them back.
+pub trait Codec<X> {
+    fn encode(&self, value: &X, bytes: &mut Vec<u8>);
+
+    /// Reads a value from the front of `bytes`, leaving the rest.
+    fn decode(&self, bytes: &mut &[u8]) -> Result<X, SnapshotError>;
+}
+
+/// Writes numbers, `bool`s and `char`s as their little-endian bytes, strings
+/// as a varint length followed by UTF-8, and `()` as nothing.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Raw;
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], SnapshotError> {
+    if bytes.len() < len {
+        return Err(SnapshotError::UnexpectedEnd);
+    }
+    let (taken, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(taken)
+}
+
+macro_rules! impl_raw_codec {
+    ($($x:ty),*) => {$(
+        impl Codec<$x> for Raw {
+            fn encode(&self, value: &$x, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+
+            fn decode(&self, bytes: &mut &[u8]) -> Result<$x, SnapshotError> {
+                let taken = take(bytes, core::mem::size_of::<$x>())?;
+                Ok(<$x>::from_le_bytes(taken.try_into().unwrap()))
+            }
+        }
+    )*};
+}
+
+impl_raw_codec!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl Codec<()> for Raw {
+    fn encode(&self, _: &(), _: &mut Vec<u8>) {}
+
+    fn decode(&self, _: &mut &[u8]) -> Result<(), SnapshotError> {
+        Ok(())
+    }
+}
+
+impl Codec<bool> for Raw {
+    fn encode(&self, value: &bool, bytes: &mut Vec<u8>) {
+        bytes.push(*value as u8);
+    }
+
+    fn decode(&self, bytes: &mut &[u8]) -> Result<bool, SnapshotError> {
+        match take(bytes, 1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            byte => Err(SnapshotError::InvalidValue(format!(
+                "{} is not a bool",
+                byte
+            ))),
+        }
+    }
+}
+
+impl Codec<char> for Raw {
+    fn encode(&self, value: &char, bytes: &mut Vec<u8>) {
+        self.encode(&(*value as u32), bytes);
+    }
+
+    fn decode(&self, bytes: &mut &[u8]) -> Result<char, SnapshotError> {
+        let code: u32 = self.decode(bytes)?;
+        char::from_u32(code)
+            .ok_or_else(|| SnapshotError::InvalidValue(format!("{:#x} is not a char", code)))
+    }
+}
+
+impl Codec<String> for Raw {
+    fn encode(&self, value: &String, bytes: &mut Vec<u8>) {
+        write_varint(value.len() as u64, bytes);
+        bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn decode(&self, bytes: &mut &[u8]) -> Result<String, SnapshotError> {
+        let len = read_len(bytes)?;
+        String::from_utf8(take(bytes, len)?.to_vec())
+            .map_err(|err| SnapshotError::InvalidValue(err.to_string()))
+    }
+}
+
+fn write_varint(mut value: u64, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, SnapshotError> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = take(bytes, 1)?[0];
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+    }
+    Err(SnapshotError::InvalidValue(
+        "varint is too long".to_string(),
+    ))
+}
+
+fn read_len(bytes: &mut &[u8]) -> Result<usize, SnapshotError> {
+    let len = read_varint(bytes)?;
+    usize::try_from(len).map_err(|_| SnapshotError::InvalidValue(format!("{} is too long", len)))
+}
+
+impl<T, E, Ty: EdgeType> Graph<T, E, Ty> {
+    pub fn to_bytes(&self, nodes: &impl Codec<T>, weights: &impl Codec<E>) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION);
+        bytes.push(if self.allows_self_loops {
+            SELF_LOOPS
+        } else {
+            0
+        });
+
+        write_varint(self.nodes.len() as u64, &mut bytes);
+        for node in self.nodes.iter() {
+            nodes.encode(node, &mut bytes);
+        }
+        for (source, edge_data) in self.edges.iter().enumerate() {
+            write_varint(edge_data.len() as u64, &mut bytes);
+            let mut previous = source as i64;
+            for edge in edge_data.iter() {
+                let delta = edge.target.index() as i64 - previous;
+                write_varint(((delta << 1) ^ (delta >> 63)) as u64, &mut bytes);
+                weights.encode(&edge.weight, &mut bytes);
+                previous = edge.target.index() as i64;
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(
+        mut bytes: &[u8],
+        nodes: &impl Codec<T>,
+        weights: &impl Codec<E>,
+    ) -> Result<Self, SnapshotError> {
+        let bytes = &mut bytes;
+        if take(bytes, MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        let version = take(bytes, 1)?[0];
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let flags = take(bytes, 1)?[0];
+
+        let node_count = read_len(bytes)?;
+        // Every node takes at least a byte, for the length of its adjacency
+        // list, so a corrupt count is caught before anything is allocated.
+        if node_count > bytes.len() {
+            return Err(SnapshotError::UnexpectedEnd);
+        }
+        let mut graph =
+            Graph::with_capacity(node_count, 0).allow_self_loops(flags & SELF_LOOPS != 0);
+        for _ in 0..node_count {
+            graph.add_node(nodes.decode(bytes)?);
+        }
+        for source in 0..node_count {
+            let mut previous = source as i64;
+            for _ in 0..read_len(bytes)? {
+                let zigzag = read_varint(bytes)?;
+                let delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+                let weight = weights.decode(bytes)?;
+                // A target that overflows or is negative is out of bounds
+                // like any other.
+                let target = previous
+                    .checked_add(delta)
+                    .and_then(|target| usize::try_from(target).ok())
+                    .unwrap_or(usize::MAX);
+                graph
+                    .add_edge(NodeIndex::new(source), NodeIndex::new(target), weight)
+                    .map_err(SnapshotError::InvalidEdge)?;
+                previous = target as i64;
+            }
+        }
+
+        if !bytes.is_empty() {
+            return Err(SnapshotError::TrailingBytes(bytes.len()));
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Raw, SnapshotError};
+    use crate::{DiGraph, GraphError, NodeIndex, UnGraph};
+
+    #[test]
+    fn round_trip() {
+        let mut graph = DiGraph::new().allow_self_loops(true);
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(c, a, 2.5).unwrap();
+        graph.add_edge(a, c, -1.0).unwrap();
+        graph.add_edge(a, b, 0.5).unwrap();
+        graph.add_edge(b, b, 4.0).unwrap();
+
+        let bytes = graph.to_bytes(&Raw, &Raw);
+        assert_eq!(&bytes[..7], b"VGRF\x01\x01\x03");
+        assert!(DiGraph::from_bytes(&bytes, &Raw, &Raw) == Ok(graph));
+    }
+
+    #[test]
+    fn unit_weights_take_one_byte_per_edge() {
+        let graph = UnGraph::<u8>::path_graph(200);
+        let bytes = graph.to_bytes(&Raw, &Raw);
+
+        // Header, a two-byte node count, payloads, list lengths and deltas.
+        assert_eq!(bytes.len(), 6 + 2 + 200 + 200 + 199);
+        assert!(UnGraph::from_bytes(&bytes, &Raw, &Raw).unwrap() == graph);
+    }
+
+    #[test]
+    fn rejects_corrupt_input() {
+        let graph = DiGraph::<u32>::from_edges([(0, 1)]).unwrap();
+        let bytes = graph.to_bytes(&Raw, &Raw);
+        let load = |bytes: &[u8]| DiGraph::<u32>::from_bytes(bytes, &Raw, &Raw).err();
+
+        assert_eq!(load(b"GRAF"), Some(SnapshotError::NotASnapshot));
+        assert_eq!(
+            load(b"VGRF\x02"),
+            Some(SnapshotError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            load(b"VGRF\x01\x00\x7f"),
+            Some(SnapshotError::UnexpectedEnd)
+        );
+        assert_eq!(
+            load(&bytes[..bytes.len() - 1]),
+            Some(SnapshotError::UnexpectedEnd)
+        );
+        assert_eq!(
+            load(&[&bytes[..], &[0]].concat()),
+            Some(SnapshotError::TrailingBytes(1))
+        );
+
+        let mut pointing_out = bytes.clone();
+        let last = pointing_out.len() - 2;
+        pointing_out[last] = 4;
+        assert_eq!(
+            load(&pointing_out),
+            Some(SnapshotError::InvalidEdge(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(2),
+                len: 2
+            }))
+        );
+
+        // The only edge goes from node 1 back to node 0, a delta of -1 in a
+        // single byte, replaced with a delta of i64::MAX.
+        let backwards = DiGraph::<u32>::from_edges([(1, 0)])
+            .unwrap()
+            .to_bytes(&Raw, &Raw);
+        let overflowing = [
+            &backwards[..backwards.len() - 1],
+            &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ]
+        .concat();
+        assert_eq!(
+            load(&overflowing),
+            Some(SnapshotError::InvalidEdge(GraphError::NodeOutOfBounds {
+                index: NodeIndex::new(usize::MAX),
+                len: 2
+            }))
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+
+use crate::{Directed, Edge, EdgeData, EdgeType, GraphError, NodeIndex, Undirected};
+
+/// Identifies a node in a [`StableGraph`].
+///
+/// Removing a node leaves every other id valid. Its slot may be reused by a
+/// later node, but with a new generation, so the old id is never mistaken for
+/// the new node.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// A graph whose node ids stay valid when other nodes are removed.
+///
+/// Removed nodes leave an empty slot behind which is reused by the next
+/// [`StableGraph::add_node`]. Edge targets hold only the slot index, which is
+/// sound because removing a node also removes every edge touching it. Like
+/// [`crate::Graph`], edges from a node to itself are rejected unless allowed
+/// with [`StableGraph::allow_self_loops`].
+#[derive(PartialEq, Clone)]
+pub struct StableGraph<T, E = (), Ty = Directed> {
+    nodes: Vec<Option<T>>,
+    generations: Vec<u32>,
+    edges: Vec<EdgeData<E>>,
+    free: Vec<usize>,
+    node_count: usize,
+    edge_count: usize,
+    allows_self_loops: bool,
+    edge_type: PhantomData<Ty>,
+}
+
+impl<T, E, Ty: EdgeType> Default for StableGraph<T, E, Ty> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            generations: Vec::new(),
+            edges: Vec::new(),
+            free: Vec::new(),
+            node_count: 0,
+            edge_count: 0,
+            allows_self_loops: false,
+            edge_type: PhantomData,
+        }
+    }
+}
+
+impl<T, E> StableGraph<T, E, Directed> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, E> StableGraph<T, E, Undirected> {
+    pub fn new_undirected() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, E, Ty: EdgeType> StableGraph<T, E, Ty> {
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Self-loops are rejected by [`StableGraph::add_edge`] unless allowed
+    /// here. Forbidding them again keeps the ones already in the graph.
+    pub fn allow_self_loops(mut self, allow: bool) -> Self {
+        self.allows_self_loops = allow;
+        self
+    }
+
+    pub fn allows_self_loops(&self) -> bool {
+        self.allows_self_loops
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn add_node(&mut self, node: T) -> NodeId {
+        self.node_count += 1;
+        if let Some(index) = self.free.pop() {
+            self.nodes[index] = Some(node);
+            return self.id(index);
+        }
+
+        let index = self.nodes.len();
+        self.nodes.push(Some(node));
+        self.generations.push(0);
+        self.edges.push(EdgeData::new());
+        self.id(index)
+    }
+
+    pub fn contains_node(&self, id: NodeId) -> bool {
+        self.get_node(id).is_some()
+    }
+
+    pub fn get_node(&self, id: NodeId) -> Option<&T> {
+        if self.generations.get(id.index) != Some(&id.generation) {
+            return None;
+        }
+        self.nodes[id.index].as_ref()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &T)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| Some((self.id(index), node.as_ref()?)))
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes().map(|(id, _)| id)
+    }
+
+    /// Removes the node and every edge touching it, or returns `None` if the
+    /// id is stale.
+    pub fn remove_node(&mut self, id: NodeId) -> Option<T> {
+        if !self.contains_node(id) {
+            return None;
+        }
+
+        self.edge_count -= self.edges[id.index].len();
+        self.edges[id.index].clear();
+        for edge_data in self.edges.iter_mut() {
+            let len = edge_data.len();
+            edge_data.retain(|edge| edge.target.index() != id.index);
+            self.edge_count -= len - edge_data.len();
+        }
+
+        self.node_count -= 1;
+        self.generations[id.index] = self.generations[id.index].wrapping_add(1);
+        self.free.push(id.index);
+        self.nodes[id.index].take()
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: E) -> Result<(), GraphError> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+
+        if from == to && !self.allows_self_loops {
+            return Err(GraphError::StableSelfLoop(from));
+        }
+
+        self.edges[from.index].push(Edge {
+            target: NodeIndex::new(to.index),
+            weight,
+        });
+        self.edge_count += 1;
+        Ok(())
+    }
+
+    pub fn edge_weight(&self, from: NodeId, to: NodeId) -> Option<&E> {
+        if !self.contains_node(from) || !self.contains_node(to) {
+            return None;
+        }
+
+        let find = |from: usize, to: usize| {
+            self.edges[from]
+                .iter()
+                .find(|edge| edge.target.index() == to)
+                .map(|edge| &edge.weight)
+        };
+
+        if Ty::is_directed() {
+            find(from.index, to.index)
+        } else {
+            find(from.index, to.index).or_else(|| find(to.index, from.index))
+        }
+    }
+
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> Result<E, GraphError> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+
+        let position = |from: usize, to: usize| {
+            self.edges[from]
+                .iter()
+                .position(|edge| edge.target.index() == to)
+                .map(|pos| (from, pos))
+        };
+        let found = if Ty::is_directed() {
+            position(from.index, to.index)
+        } else {
+            position(from.index, to.index).or_else(|| position(to.index, from.index))
+        };
+
+        match found {
+            Some((source, pos)) => {
+                self.edge_count -= 1;
+                Ok(self.edges[source].remove(pos).weight)
+            }
+            None => Err(GraphError::EdgeNotFound {
+                from: NodeIndex::new(from.index),
+                to: NodeIndex::new(to.index),
+            }),
+        }
+    }
+
+    pub fn get_edges_from(&self, id: NodeId) -> Result<Vec<NodeId>, GraphError> {
+        self.check_node(id)?;
+
+        let mut edges: Vec<_> = self.edges[id.index]
+            .iter()
+            .map(|edge| self.id(edge.target.index()))
+            .collect();
+        if !Ty::is_directed() {
+            edges.extend(self.incoming_edges(id.index));
+        }
+        Ok(edges)
+    }
+
+    pub fn get_edges_to(&self, id: NodeId) -> Result<Vec<NodeId>, GraphError> {
+        if !Ty::is_directed() {
+            return self.get_edges_from(id);
+        }
+
+        self.check_node(id)?;
+
+        Ok(self.incoming_edges(id.index).collect())
+    }
+
+    fn check_node(&self, id: NodeId) -> Result<(), GraphError> {
+        if !self.contains_node(id) {
+            return Err(GraphError::MissingNode(id));
+        }
+
+        Ok(())
+    }
+
+    fn incoming_edges(&self, index: usize) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(source, edge_data)| {
+                edge_data
+                    .iter()
+                    .filter(move |edge| edge.target.index() == index)
+                    .map(move |_| self.id(source))
+            })
+    }
+
+    fn id(&self, index: usize) -> NodeId {
+        NodeId {
+            index,
+            generation: self.generations[index],
+        }
+    }
+}
+
+/// Lists the nodes that are in the graph by slot index, like the `Debug`
+/// output of [`crate::Graph`].
+impl<T: Debug, E: Debug, Ty: EdgeType> Debug for StableGraph<T, E, Ty> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (kind, arrow) = if Ty::is_directed() {
+            ("StableDiGraph", "->")
+        } else {
+            ("StableUnGraph", "--")
+        };
+
+        if self.node_count == 0 {
+            return write!(f, "{} {{}}", kind);
+        }
+        writeln!(f, "{} {{", kind)?;
+        for (idx, (node, edge_data)) in self.nodes.iter().zip(self.edges.iter()).enumerate() {
+            let Some(node) = node else {
+                continue;
+            };
+            write!(f, "    {}: {:?} {} [", idx, node, arrow)?;
+            for (position, edge) in edge_data.iter().enumerate() {
+                if position > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: {:?}", edge.target, edge.weight)?;
+            }
+            writeln!(f, "]")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StableGraph;
+    use crate::{GraphError, Undirected};
+
+    #[test]
+    fn removal_keeps_ids() {
+        let mut graph = StableGraph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, c, ()).unwrap();
+        graph.add_edge(c, a, ()).unwrap();
+
+        assert_eq!(graph.remove_node(b), Some('b'));
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.get_node(a), Some(&'a'));
+        assert_eq!(graph.get_node(c), Some(&'c'));
+        assert_eq!(graph.get_edges_from(a), Ok(vec![]));
+        assert_eq!(graph.get_edges_from(c), Ok(vec![a]));
+        assert_eq!(graph.get_edges_to(a), Ok(vec![c]));
+        assert_eq!(graph.node_ids().collect::<Vec<_>>(), vec![a, c]);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(
+            format!("{:?}", graph),
+            "StableDiGraph {\n    0: 'a' -> []\n    2: 'c' -> [0: ()]\n}"
+        );
+    }
+
+    #[test]
+    fn self_loops() {
+        let mut graph = StableGraph::<u8>::new();
+        let a = graph.add_node(1);
+        graph.remove_node(a);
+        let b = graph.add_node(2);
+        assert_eq!(graph.add_edge(b, b, ()), Err(GraphError::StableSelfLoop(b)));
+        assert_eq!(
+            graph.add_edge(b, b, ()).unwrap_err().to_string(),
+            "node cannot point to itself: index is 0, generation is 1"
+        );
+
+        let mut graph = graph.allow_self_loops(true);
+        graph.add_edge(b, b, ()).unwrap();
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.remove_edge(b, b), Ok(()));
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(
+            format!("{:?}", StableGraph::<u8, (), Undirected>::new_undirected()),
+            "StableUnGraph {}"
+        );
+    }
+
+    #[test]
+    fn reuse_is_detectable() {
+        let mut graph = StableGraph::new_undirected();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        graph.remove_node(b);
+
+        let d = graph.add_node(4);
+        assert_eq!(d.index(), b.index());
+        assert_ne!(d, b);
+        assert!(!graph.contains_node(b));
+        assert_eq!(graph.get_node(b), None);
+        assert_eq!(graph.remove_node(b), None);
+        assert!(graph.add_edge(a, b, "stale").is_err());
+
+        graph.add_edge(d, a, "fresh").unwrap();
+        assert_eq!(graph.edge_weight(a, d), Some(&"fresh"));
+        assert_eq!(graph.get_edges_from(a), Ok(vec![d]));
+        assert_eq!(graph.remove_edge(a, d), Ok("fresh"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Sharing a graph between threads while it changes.
+
+use std::sync::{Arc, RwLock};
+
+use crate::{Directed, EdgeType, Graph};
+
+/// A handle to a graph that many threads read while others change it.
+///
+/// Readers take a [`SyncGraph::snapshot`], an immutable graph they can
+/// traverse for as long as they like without blocking anyone. Writers batch
+/// their mutations in [`SyncGraph::update`], which readers see all at once
+/// in the snapshots they take afterwards. Snapshots taken before keep the
+/// graph as it was, so a write while snapshots are alive copies the graph.
+///
+/// Cloning the handle shares the same graph.
+pub struct SyncGraph<T, E = (), Ty = Directed> {
+    current: Arc<RwLock<Arc<Graph<T, E, Ty>>>>,
+}
+
+impl<T, E, Ty> Clone for SyncGraph<T, E, Ty> {
+    fn clone(&self) -> Self {
+        Self {
+            current: Arc::clone(&self.current),
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> From<Graph<T, E, Ty>> for SyncGraph<T, E, Ty> {
+    fn from(graph: Graph<T, E, Ty>) -> Self {
+        Self::new(graph)
+    }
+}
+
+impl<T, E, Ty: EdgeType> SyncGraph<T, E, Ty> {
+    pub fn new(graph: Graph<T, E, Ty>) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(graph))),
+        }
+    }
+
+    /// Returns the graph as of the last finished update.
+    pub fn snapshot(&self) -> Arc<Graph<T, E, Ty>> {
+        Arc::clone(&self.current.read().expect("graph lock poisoned"))
+    }
+
+    /// Applies a batch of mutations, returning what `update` returns. Updates
+    /// from different threads run one after the other.
+    ///
+    /// A panic in `update` poisons the graph, and every later call panics.
+    pub fn update<R>(&self, update: impl FnOnce(&mut Graph<T, E, Ty>) -> R) -> R
+    where
+        Graph<T, E, Ty>: Clone,
+    {
+        let mut current = self.current.write().expect("graph lock poisoned");
+        update(Arc::make_mut(&mut current))
+    }
+
+    /// Replaces the graph, returning the last snapshot of the old one.
+    pub fn replace(&self, graph: Graph<T, E, Ty>) -> Arc<Graph<T, E, Ty>> {
+        let mut current = self.current.write().expect("graph lock poisoned");
+        core::mem::replace(&mut current, Arc::new(graph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    use super::SyncGraph;
+    use crate::{CsrGraph, DiGraph, Graph, NodeIndex, StableGraph};
+
+    fn assert_send_sync<X: Send + Sync>() {}
+
+    #[test]
+    fn graphs_are_send_and_sync() {
+        assert_send_sync::<Graph<String, f64>>();
+        assert_send_sync::<StableGraph<String, f64>>();
+        assert_send_sync::<CsrGraph<String, f64>>();
+        assert_send_sync::<SyncGraph<String, f64>>();
+    }
+
+    #[test]
+    fn snapshots_keep_their_version() {
+        let graph = SyncGraph::new(DiGraph::<u32>::path_graph(3));
+        let before = graph.snapshot();
+
+        let added = graph.update(|graph| {
+            let added = graph.add_node(0);
+            graph.add_edge(NodeIndex::new(2), added, ()).unwrap();
+            added
+        });
+        assert_eq!(before.node_count(), 3);
+        assert_eq!(graph.snapshot().bfs(NodeIndex::new(0)).last(), Some(added));
+
+        let old = graph.replace(Graph::new());
+        assert_eq!(old.node_count(), 4);
+        assert_eq!(graph.snapshot().node_count(), 0);
+    }
+
+    #[test]
+    fn readers_traverse_while_writer_mutates() {
+        let graph = SyncGraph::new(DiGraph::<u32>::path_graph(100));
+        let barrier = Arc::new(Barrier::new(5));
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let (graph, barrier) = (graph.clone(), Arc::clone(&barrier));
+                thread::spawn(move || {
+                    barrier.wait();
+                    (0..50)
+                        .map(|_| {
+                            let snapshot = graph.snapshot();
+                            // Nodes and edges are only ever added together.
+                            let reached = snapshot.bfs(NodeIndex::new(0)).count();
+                            assert_eq!(reached, snapshot.node_count());
+                            reached
+                        })
+                        .max()
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        for _ in 0..50 {
+            graph.update(|graph| {
+                let last = NodeIndex::new(graph.node_count() - 1);
+                let added = graph.add_node(0);
+                graph.add_edge(last, added, ()).unwrap();
+            });
+        }
+
+        for reader in readers {
+            assert!(reader.join().unwrap() <= Some(150));
+        }
+        assert_eq!(graph.snapshot().node_count(), 150);
+    }
+}
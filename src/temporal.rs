@@ -0,0 +1,308 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::algo::MinScored;
+use crate::{Directed, EdgeIndex, EdgeType, Graph, GraphError, NodeIndex};
+
+#[derive(Debug, Clone)]
+struct TimedEdge<Tm, E> {
+    source: NodeIndex,
+    target: NodeIndex,
+    start: Tm,
+    /// Inclusive, and never before `start`.
+    end: Tm,
+    weight: E,
+}
+
+/// A graph whose edges only exist at a point in time or over an interval of
+/// times, such as messages or flights.
+///
+/// The time type `Tm` can be anything ordered, like a plain integer or a
+/// timestamp. [`TemporalGraph::snapshot_at`] turns the edges present at one
+/// time into a regular [`Graph`], and the path queries only follow edges in
+/// the order their times allow. Like [`Graph`], edges from a node to itself
+/// are rejected.
+#[derive(Debug, Clone)]
+pub struct TemporalGraph<T, Tm, E = (), Ty = Directed> {
+    nodes: Vec<T>,
+    edges: Vec<TimedEdge<Tm, E>>,
+    edge_type: PhantomData<Ty>,
+}
+
+impl<T, Tm, E, Ty: EdgeType> Default for TemporalGraph<T, Tm, E, Ty> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            edge_type: PhantomData,
+        }
+    }
+}
+
+impl<T, Tm: Ord + Copy, E, Ty: EdgeType> TemporalGraph<T, Tm, E, Ty> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, weight: T) -> NodeIndex {
+        self.nodes.push(weight);
+        NodeIndex::new(self.nodes.len() - 1)
+    }
+
+    /// Adds an edge that exists only at the time `at`.
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        at: Tm,
+        weight: E,
+    ) -> Result<EdgeIndex, GraphError> {
+        self.add_edge_during(from, to, at, at, weight)
+    }
+
+    /// Adds an edge that exists from `start` to `end`, both included.
+    ///
+    /// Panics if `end` is before `start`.
+    pub fn add_edge_during(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        start: Tm,
+        end: Tm,
+        weight: E,
+    ) -> Result<EdgeIndex, GraphError> {
+        assert!(start <= end, "edge interval ends before it starts");
+        for idx in [from, to] {
+            if idx.index() >= self.nodes.len() {
+                return Err(GraphError::NodeOutOfBounds {
+                    index: idx,
+                    len: self.nodes.len(),
+                });
+            }
+        }
+        if from == to {
+            return Err(GraphError::SelfLoop(from));
+        }
+
+        self.edges.push(TimedEdge {
+            source: from,
+            target: to,
+            start,
+            end,
+            weight,
+        });
+        Ok(EdgeIndex::new(self.edges.len() - 1))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn get_node(&self, idx: NodeIndex) -> Option<&T> {
+        self.nodes.get(idx.index())
+    }
+
+    pub fn get_node_mut(&mut self, idx: NodeIndex) -> Option<&mut T> {
+        self.nodes.get_mut(idx.index())
+    }
+
+    pub fn edge_weight(&self, idx: EdgeIndex) -> Option<&E> {
+        self.edges.get(idx.index()).map(|edge| &edge.weight)
+    }
+
+    /// The endpoints of an edge, as `(source, target)`.
+    pub fn edge_endpoints(&self, idx: EdgeIndex) -> Option<(NodeIndex, NodeIndex)> {
+        self.edges
+            .get(idx.index())
+            .map(|edge| (edge.source, edge.target))
+    }
+
+    /// The first and last time an edge exists, which are the same for an
+    /// edge added with [`TemporalGraph::add_edge`].
+    pub fn edge_interval(&self, idx: EdgeIndex) -> Option<(Tm, Tm)> {
+        self.edges
+            .get(idx.index())
+            .map(|edge| (edge.start, edge.end))
+    }
+
+    /// The edges that exist at time `at`, in the order they were added.
+    pub fn edges_at(&self, at: Tm) -> impl Iterator<Item = EdgeIndex> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(move |(_, edge)| edge.start <= at && at <= edge.end)
+            .map(|(idx, _)| EdgeIndex::new(idx))
+    }
+
+    /// Finds the earliest time every node can be reached from `from`,
+    /// leaving at `departure`, or `None` for the nodes that can't be.
+    ///
+    /// A path can only take an edge at a time it exists and no earlier than
+    /// it arrived at the edge's source, and waiting at a node is allowed.
+    /// Taking an edge takes no time, so a path may take several edges at the
+    /// same time.
+    ///
+    /// Panics if `from` is out of bounds.
+    pub fn earliest_arrivals(&self, from: NodeIndex, departure: Tm) -> Vec<Option<Tm>> {
+        self.earliest_arrival_tree(from, departure).0
+    }
+
+    /// Finds a time-respecting path from `from` to `to` that arrives as
+    /// early as possible, leaving at `departure`, and returns the arrival
+    /// time and the nodes along the path. See
+    /// [`TemporalGraph::earliest_arrivals`] for which paths count.
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn earliest_arrival_path(
+        &self,
+        from: NodeIndex,
+        to: NodeIndex,
+        departure: Tm,
+    ) -> Option<(Tm, Vec<NodeIndex>)> {
+        self.check_node(to);
+        let (arrivals, predecessors) = self.earliest_arrival_tree(from, departure);
+        let arrival = arrivals[to.index()]?;
+
+        let mut path = vec![to];
+        let mut current = to.index();
+        while let Some(previous) = predecessors[current] {
+            path.push(NodeIndex::new(previous));
+            current = previous;
+        }
+        path.reverse();
+        Some((arrival, path))
+    }
+
+    /// Dijkstra's algorithm over arrival times, which works because leaving
+    /// a node later never leads anywhere sooner.
+    fn earliest_arrival_tree(
+        &self,
+        from: NodeIndex,
+        departure: Tm,
+    ) -> (Vec<Option<Tm>>, Vec<Option<usize>>) {
+        self.check_node(from);
+        let mut outgoing = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges.iter() {
+            outgoing[edge.source.index()].push((edge.target.index(), edge));
+            if !Ty::is_directed() {
+                outgoing[edge.target.index()].push((edge.source.index(), edge));
+            }
+        }
+
+        let mut arrivals = vec![None; self.nodes.len()];
+        let mut predecessors = vec![None; self.nodes.len()];
+        let mut done = vec![false; self.nodes.len()];
+        let mut heap = BinaryHeap::new();
+        arrivals[from.index()] = Some(departure);
+        heap.push(MinScored(departure, from.index()));
+
+        while let Some(MinScored(time, node)) = heap.pop() {
+            if core::mem::replace(&mut done[node], true) {
+                continue;
+            }
+            for &(next, edge) in outgoing[node].iter() {
+                if edge.end < time {
+                    continue;
+                }
+                let arrival = time.max(edge.start);
+                if arrivals[next].is_none_or(|known| arrival < known) {
+                    arrivals[next] = Some(arrival);
+                    predecessors[next] = Some(node);
+                    heap.push(MinScored(arrival, next));
+                }
+            }
+        }
+        (arrivals, predecessors)
+    }
+
+    fn check_node(&self, idx: NodeIndex) {
+        if idx.index() >= self.nodes.len() {
+            panic!(
+                "index idx out of range: idx is {}, but len is {}",
+                idx,
+                self.nodes.len(),
+            );
+        }
+    }
+}
+
+impl<T: Clone, Tm: Ord + Copy, E: Clone, Ty: EdgeType> TemporalGraph<T, Tm, E, Ty> {
+    /// Builds the graph as it is at time `at`, with every node and the edges
+    /// that exist at that time, in the order they were added. Node indices
+    /// carry over.
+    pub fn snapshot_at(&self, at: Tm) -> Graph<T, E, Ty> {
+        let mut graph = Graph::with_capacity(self.nodes.len(), 0);
+        for weight in self.nodes.iter() {
+            graph.add_node(weight.clone());
+        }
+        for idx in self.edges_at(at) {
+            let edge = &self.edges[idx.index()];
+            graph
+                .add_edge(edge.source, edge.target, edge.weight.clone())
+                .expect("both endpoints are in the snapshot");
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemporalGraph;
+    use crate::{GraphError, NodeIndex, Undirected};
+
+    #[test]
+    fn flights() {
+        let mut graph = TemporalGraph::<&str, u32>::new();
+        let n: Vec<_> = ["ams", "ber", "cdg", "dub"]
+            .into_iter()
+            .map(|city| graph.add_node(city))
+            .collect();
+        graph.add_edge(n[0], n[1], 1, ()).unwrap();
+        // Gone by the time anyone from `ams` gets to `ber`.
+        graph.add_edge(n[1], n[3], 0, ()).unwrap();
+        graph.add_edge_during(n[1], n[2], 2, 5, ()).unwrap();
+        graph.add_edge(n[2], n[3], 3, ()).unwrap();
+        graph.add_edge(n[0], n[3], 9, ()).unwrap();
+        assert_eq!(
+            graph.add_edge(n[2], n[2], 4, ()),
+            Err(GraphError::SelfLoop(n[2]))
+        );
+
+        assert_eq!(graph.snapshot_at(3).to_string(), "ber -> cdg\ncdg -> dub\n");
+        assert_eq!(graph.snapshot_at(7).edge_count(), 0);
+        assert_eq!(
+            graph.earliest_arrivals(n[0], 0),
+            vec![Some(0), Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            graph.earliest_arrival_path(n[0], n[3], 0),
+            Some((3, vec![n[0], n[1], n[2], n[3]]))
+        );
+        assert_eq!(
+            graph.earliest_arrival_path(n[0], n[3], 4),
+            Some((9, vec![n[0], n[3]]))
+        );
+        assert_eq!(graph.earliest_arrival_path(n[3], n[0], 0), None);
+    }
+
+    #[test]
+    fn undirected_paths_go_both_ways() {
+        let mut graph = TemporalGraph::<u8, i64, (), Undirected>::new();
+        let (a, b, c) = (graph.add_node(0), graph.add_node(1), graph.add_node(2));
+        graph.add_edge(b, a, 10, ()).unwrap();
+        graph.add_edge(c, b, 10, ()).unwrap();
+        assert_eq!(
+            graph.earliest_arrival_path(a, c, -5),
+            Some((10, vec![a, b, c]))
+        );
+        assert_eq!(graph.earliest_arrivals(c, 11), vec![None, None, Some(11)]);
+        assert_eq!(graph.snapshot_at(10).neighbors(b).count(), 2);
+        assert_eq!(graph.get_node(NodeIndex::new(2)), Some(&2));
+    }
+}
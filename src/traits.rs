@@ -0,0 +1,143 @@
+//! Traits over the graph storages, so an algorithm can be written once and
+//! run on a [`Graph`], a [`StableGraph`] or a [`CsrGraph`].
+
+use core::hash::Hash;
+
+use crate::{CsrGraph, EdgeType, Graph, NodeId, NodeIndex, StableGraph};
+
+/// The node id type of a graph and the nodes it holds.
+pub trait GraphBase {
+    type NodeId: Copy + Eq + Hash;
+
+    /// Iterates over the ids of every node in the graph.
+    fn node_ids(&self) -> impl Iterator<Item = Self::NodeId> + '_;
+}
+
+pub trait NodeCount: GraphBase {
+    fn node_count(&self) -> usize;
+}
+
+pub trait Neighbors: GraphBase {
+    /// Iterates over the nodes `node` has an edge to. In undirected graphs
+    /// this includes every node connected to `node`.
+    ///
+    /// Panics if `node` is not in the graph.
+    fn neighbors(&self, node: Self::NodeId) -> impl Iterator<Item = Self::NodeId> + '_;
+}
+
+impl<G: GraphBase> GraphBase for &G {
+    type NodeId = G::NodeId;
+
+    fn node_ids(&self) -> impl Iterator<Item = G::NodeId> + '_ {
+        (**self).node_ids()
+    }
+}
+
+impl<G: NodeCount> NodeCount for &G {
+    fn node_count(&self) -> usize {
+        (**self).node_count()
+    }
+}
+
+impl<G: Neighbors> Neighbors for &G {
+    fn neighbors(&self, node: G::NodeId) -> impl Iterator<Item = G::NodeId> + '_ {
+        (**self).neighbors(node)
+    }
+}
+
+impl<T, E, Ty: EdgeType> GraphBase for Graph<T, E, Ty> {
+    type NodeId = NodeIndex;
+
+    fn node_ids(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        (0..self.nodes.len()).map(NodeIndex::new)
+    }
+}
+
+impl<T, E, Ty: EdgeType> NodeCount for Graph<T, E, Ty> {
+    fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl<T, E, Ty: EdgeType> Neighbors for Graph<T, E, Ty> {
+    fn neighbors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        Graph::neighbors(self, node)
+    }
+}
+
+impl<T, E, Ty: EdgeType> GraphBase for StableGraph<T, E, Ty> {
+    type NodeId = NodeId;
+
+    fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        StableGraph::node_ids(self)
+    }
+}
+
+impl<T, E, Ty: EdgeType> NodeCount for StableGraph<T, E, Ty> {
+    fn node_count(&self) -> usize {
+        StableGraph::node_count(self)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Neighbors for StableGraph<T, E, Ty> {
+    fn neighbors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.get_edges_from(node)
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_iter()
+    }
+}
+
+impl<T, E, Ty: EdgeType> GraphBase for CsrGraph<T, E, Ty> {
+    type NodeId = NodeIndex;
+
+    fn node_ids(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        (0..CsrGraph::node_count(self)).map(NodeIndex::new)
+    }
+}
+
+impl<T, E, Ty: EdgeType> NodeCount for CsrGraph<T, E, Ty> {
+    fn node_count(&self) -> usize {
+        CsrGraph::node_count(self)
+    }
+}
+
+impl<T, E, Ty: EdgeType> Neighbors for CsrGraph<T, E, Ty> {
+    fn neighbors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        CsrGraph::neighbors(self, node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Neighbors, NodeCount};
+    use crate::{Graph, StableGraph, UnGraph};
+
+    /// Counts the nodes with no way to reach them from any other node.
+    fn unreachable<G: Neighbors + NodeCount>(graph: G) -> usize {
+        let mut reached = HashSet::new();
+        for node in graph.node_ids() {
+            reached.extend(graph.neighbors(node).filter(|&next| next != node));
+        }
+        graph.node_count() - reached.len()
+    }
+
+    #[test]
+    fn algorithms_run_on_every_storage() {
+        let graph = Graph::<u8>::from_edges([(0, 1), (1, 2), (3, 1)]).unwrap();
+        assert_eq!(unreachable(&graph), 2);
+        assert_eq!(unreachable(graph.clone().into_csr()), 2);
+        assert_eq!(unreachable(UnGraph::<u8>::path_graph(4).into_csr()), 0);
+
+        let mut stable = StableGraph::new();
+        let a = stable.add_node('a');
+        let b = stable.add_node('b');
+        let c = stable.add_node('c');
+        stable.add_edge(a, c, ()).unwrap();
+        stable.add_edge(b, c, ()).unwrap();
+        stable.remove_node(a);
+        assert_eq!(unreachable(&stable), 1);
+        assert_eq!(stable.neighbors(b).collect::<Vec<_>>(), vec![c]);
+    }
+}
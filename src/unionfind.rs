@@ -0,0 +1,100 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A disjoint set forest over the elements `0..n`, with union by rank and
+/// path compression.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+}
+
+impl UnionFind {
+    /// Puts each of the elements `0..n` in a set of its own.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+        }
+    }
+
+    /// Returns the number of elements, not the number of sets.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the representative of the set containing `x`.
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            x = self.parents[x];
+        }
+        x
+    }
+
+    /// Like [`UnionFind::find`], but shortens the path to the representative
+    /// on the way, so later lookups are faster.
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find_mut(&mut self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            self.parents[x] = self.parents[self.parents[x]];
+            x = self.parents[x];
+        }
+        x
+    }
+
+    /// Tells whether `x` and `y` are in the same set.
+    ///
+    /// Panics if `x` or `y` is out of bounds.
+    pub fn equiv(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Merges the sets containing `x` and `y`. Returns false if they were
+    /// already the same set.
+    ///
+    /// Panics if `x` or `y` is out of bounds.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let x = self.find_mut(x);
+        let y = self.find_mut(y);
+        if x == y {
+            return false;
+        }
+
+        match self.ranks[x].cmp(&self.ranks[y]) {
+            core::cmp::Ordering::Less => self.parents[x] = y,
+            core::cmp::Ordering::Greater => self.parents[y] = x,
+            core::cmp::Ordering::Equal => {
+                self.parents[y] = x;
+                self.ranks[x] += 1;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn merging_sets() {
+        let mut sets = UnionFind::new(5);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(3, 4));
+        assert!(sets.union(1, 4));
+        assert!(!sets.union(0, 3));
+
+        assert!(sets.equiv(0, 4));
+        assert_eq!(sets.find_mut(3), sets.find(1));
+        assert!(!sets.equiv(2, 0));
+        assert_eq!(sets.len(), 5);
+        assert!(UnionFind::new(0).is_empty());
+    }
+}
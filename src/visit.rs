@@ -0,0 +1,496 @@
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+use crate::{Directed, EdgeType, Graph, NodeIndex};
+
+/// A traversal whose state is kept apart from the graph it walks. The graph
+/// is passed to every step, so it is free to be borrowed mutably in between,
+/// for example to update node payloads as they are visited.
+///
+/// Walkers keep track of nodes by index, so removing nodes during a walk
+/// makes it visit the wrong ones, and skip the ones it had found past the new
+/// end of the graph. Nodes added during a walk are visited once an edge to
+/// them is found.
+pub trait Walker<G> {
+    type Item;
+
+    fn walk_next(&mut self, graph: G) -> Option<Self::Item>;
+
+    /// Turns the walker into an iterator that holds on to `graph`.
+    fn iter(self, graph: G) -> WalkerIter<Self, G>
+    where
+        Self: Sized,
+        G: Copy,
+    {
+        WalkerIter {
+            walker: self,
+            graph,
+        }
+    }
+}
+
+pub struct WalkerIter<W, G> {
+    walker: W,
+    graph: G,
+}
+
+impl<W: Walker<G>, G: Copy> Iterator for WalkerIter<W, G> {
+    type Item = W::Item;
+
+    fn next(&mut self) -> Option<W::Item> {
+        self.walker.walk_next(self.graph)
+    }
+}
+
+fn check_start<T, E, Ty>(graph: &Graph<T, E, Ty>, start: NodeIndex) -> usize {
+    let len = graph.nodes.len();
+    let start = start.index();
+    if start >= len {
+        panic!(
+            "index start out of range: start is {}, but len is {}",
+            start, len,
+        );
+    }
+    start
+}
+
+/// The state of a breadth-first traversal, see [`Walker`].
+#[derive(Debug, Clone)]
+pub struct BfsWalker {
+    queue: VecDeque<usize>,
+    discovered: Vec<bool>,
+}
+
+impl BfsWalker {
+    /// Panics if `start` is out of bounds.
+    pub fn new<T, E, Ty>(graph: &Graph<T, E, Ty>, start: NodeIndex) -> Self {
+        let start = check_start(graph, start);
+        let mut discovered = vec![false; graph.nodes.len()];
+        discovered[start] = true;
+        Self {
+            queue: VecDeque::from([start]),
+            discovered,
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Walker<&Graph<T, E, Ty>> for BfsWalker {
+    type Item = NodeIndex;
+
+    fn walk_next(&mut self, graph: &Graph<T, E, Ty>) -> Option<NodeIndex> {
+        let len = graph.nodes.len();
+        let node = loop {
+            let node = self.queue.pop_front()?;
+            if node < len {
+                break node;
+            }
+        };
+        self.discovered.resize(len, false);
+        for next in graph.successors(node) {
+            if !self.discovered[next] {
+                self.discovered[next] = true;
+                self.queue.push_back(next);
+            }
+        }
+        Some(NodeIndex::new(node))
+    }
+}
+
+/// The state of a depth-first traversal, see [`Walker`].
+#[derive(Debug, Clone)]
+pub struct DfsWalker {
+    stack: Vec<usize>,
+    discovered: Vec<bool>,
+}
+
+impl DfsWalker {
+    /// Panics if `start` is out of bounds.
+    pub fn new<T, E, Ty>(graph: &Graph<T, E, Ty>, start: NodeIndex) -> Self {
+        let start = check_start(graph, start);
+        Self {
+            stack: vec![start],
+            discovered: vec![false; graph.nodes.len()],
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Walker<&Graph<T, E, Ty>> for DfsWalker {
+    type Item = NodeIndex;
+
+    fn walk_next(&mut self, graph: &Graph<T, E, Ty>) -> Option<NodeIndex> {
+        self.discovered.resize(graph.nodes.len(), false);
+        while let Some(node) = self.stack.pop() {
+            if node >= self.discovered.len() || self.discovered[node] {
+                continue;
+            }
+            self.discovered[node] = true;
+
+            // Pushed in reverse so the first successor is visited first.
+            let len = self.stack.len();
+            let discovered = &self.discovered;
+            self.stack
+                .extend(graph.successors(node).filter(|&next| !discovered[next]));
+            self.stack[len..].reverse();
+            return Some(NodeIndex::new(node));
+        }
+
+        None
+    }
+}
+
+/// The state of a walk in topological order, see [`Walker`].
+///
+/// In-degrees are counted when the walker is created, so edges added or
+/// removed during the walk are not taken into account: an added edge can let
+/// its target be visited before the rest of its predecessors, and nodes added
+/// during the walk are never visited. Nodes on a cycle, and nodes reachable
+/// only through one, are never visited either.
+#[derive(Debug, Clone)]
+pub struct TopoWalker {
+    in_degrees: Vec<usize>,
+    queue: VecDeque<usize>,
+}
+
+impl TopoWalker {
+    pub fn new<T, E>(graph: &Graph<T, E, Directed>) -> Self {
+        let in_degrees = graph.in_degrees.clone();
+        let queue = (0..in_degrees.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .collect();
+        Self { in_degrees, queue }
+    }
+}
+
+impl<T, E> Walker<&Graph<T, E, Directed>> for TopoWalker {
+    type Item = NodeIndex;
+
+    fn walk_next(&mut self, graph: &Graph<T, E, Directed>) -> Option<NodeIndex> {
+        let len = graph.nodes.len();
+        let node = loop {
+            let node = self.queue.pop_front()?;
+            if node < len {
+                break node;
+            }
+        };
+        // Nodes added since the walk started count as already released, so
+        // they are skipped along with the targets of edges added to nodes
+        // that were.
+        self.in_degrees.resize(len, 0);
+        for edge in graph.edges[node].iter() {
+            let target = edge.target.index();
+            if let Some(in_degree) = self.in_degrees[target].checked_sub(1) {
+                self.in_degrees[target] = in_degree;
+                if in_degree == 0 {
+                    self.queue.push_back(target);
+                }
+            }
+        }
+        Some(NodeIndex::new(node))
+    }
+}
+
+pub struct Bfs<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    walker: BfsWalker,
+}
+
+impl<'a, T, E, Ty: EdgeType> Bfs<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>, start: NodeIndex) -> Self {
+        Self {
+            graph,
+            walker: BfsWalker::new(graph, start),
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Iterator for Bfs<'_, T, E, Ty> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<NodeIndex> {
+        self.walker.walk_next(self.graph)
+    }
+}
+
+pub struct Dfs<'a, T, E, Ty> {
+    graph: &'a Graph<T, E, Ty>,
+    walker: DfsWalker,
+}
+
+impl<'a, T, E, Ty: EdgeType> Dfs<'a, T, E, Ty> {
+    pub fn new(graph: &'a Graph<T, E, Ty>, start: NodeIndex) -> Self {
+        Self {
+            graph,
+            walker: DfsWalker::new(graph, start),
+        }
+    }
+}
+
+impl<T, E, Ty: EdgeType> Iterator for Dfs<'_, T, E, Ty> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<NodeIndex> {
+        self.walker.walk_next(self.graph)
+    }
+}
+
+/// What [`depth_first_search`] reports to its visitor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DfsEvent {
+    /// The node is reached for the first time, before any of its successors.
+    Discover(NodeIndex),
+    /// The edge leads to the node discovered next.
+    TreeEdge(NodeIndex, NodeIndex),
+    /// The edge leads back to a node whose successors are still being
+    /// visited, closing a cycle. In undirected graphs the edge back to the
+    /// parent is reported as well.
+    BackEdge(NodeIndex, NodeIndex),
+    /// The edge leads to a node that is already finished.
+    CrossForwardEdge(NodeIndex, NodeIndex),
+    /// Every successor of the node has been visited.
+    Finish(NodeIndex),
+}
+
+/// Runs a depth-first search from every start node not reached by an earlier
+/// one, reporting each step to `visitor`. The search stops early when the
+/// visitor returns [`ControlFlow::Break`], with the value it broke with.
+///
+/// Panics if a start node is out of bounds.
+pub fn depth_first_search<T, E, Ty: EdgeType, B>(
+    graph: &Graph<T, E, Ty>,
+    starts: impl IntoIterator<Item = NodeIndex>,
+    mut visitor: impl FnMut(DfsEvent) -> ControlFlow<B>,
+) -> ControlFlow<B> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Undiscovered,
+        Open,
+        Finished,
+    }
+
+    let n = NodeIndex::new;
+    let mut states = vec![State::Undiscovered; graph.nodes.len()];
+    for start in starts {
+        let start = check_start(graph, start);
+        if states[start] != State::Undiscovered {
+            continue;
+        }
+
+        states[start] = State::Open;
+        visitor(DfsEvent::Discover(n(start)))?;
+        let mut stack = vec![(start, graph.successors(start))];
+        while let Some((node, successors)) = stack.last_mut() {
+            let node = *node;
+            let Some(next) = successors.next() else {
+                stack.pop();
+                states[node] = State::Finished;
+                visitor(DfsEvent::Finish(n(node)))?;
+                continue;
+            };
+
+            match states[next] {
+                State::Undiscovered => {
+                    states[next] = State::Open;
+                    visitor(DfsEvent::TreeEdge(n(node), n(next)))?;
+                    visitor(DfsEvent::Discover(n(next)))?;
+                    stack.push((next, graph.successors(next)));
+                }
+                State::Open => visitor(DfsEvent::BackEdge(n(node), n(next)))?,
+                State::Finished => visitor(DfsEvent::CrossForwardEdge(n(node), n(next)))?,
+            }
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::ControlFlow;
+
+    use super::{depth_first_search, BfsWalker, Dfs, DfsEvent, DfsWalker, TopoWalker, Walker};
+    use crate::{Graph, NodeIndex, UnGraph};
+
+    #[test]
+    fn bfs_order() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[2], n[4], ()).unwrap();
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        graph.add_edge(n[4], n[1], ()).unwrap();
+
+        assert_eq!(
+            graph.bfs(n[0]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            graph.bfs(n[4]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![4, 1, 3, 0, 2]
+        );
+        assert_eq!(
+            graph.bfs(n[5]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![5]
+        );
+    }
+
+    #[test]
+    fn dfs_order() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[1], n[3], ()).unwrap();
+        graph.add_edge(n[2], n[4], ()).unwrap();
+        graph.add_edge(n[3], n[0], ()).unwrap();
+        graph.add_edge(n[4], n[1], ()).unwrap();
+
+        assert_eq!(
+            graph.dfs(n[0]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![0, 1, 3, 2, 4]
+        );
+        assert_eq!(
+            graph.dfs(n[2]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![2, 4, 1, 3, 0]
+        );
+
+        let mut dfs = Dfs::new(&graph, n[5]);
+        assert_eq!(dfs.next(), Some(n[5]));
+        assert_eq!(dfs.next(), None);
+    }
+
+    #[test]
+    fn bfs_undirected() {
+        let mut graph = UnGraph::new_undirected();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[2], n[1], ()).unwrap();
+
+        assert_eq!(
+            graph.bfs(n[2]).map(NodeIndex::index).collect::<Vec<_>>(),
+            vec![2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn walking_while_mutating() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+
+        let mut depth = 0;
+        let mut bfs = BfsWalker::new(&graph, n[0]);
+        while let Some(node) = bfs.walk_next(&graph) {
+            graph[node] = depth;
+            depth += 10;
+            if node == n[0] {
+                let added = graph.add_node(0);
+                graph.add_edge(n[2], added, ()).unwrap();
+            }
+        }
+        assert_eq!(
+            graph.nodes().copied().collect::<Vec<_>>(),
+            vec![0, 10, 20, 3, 30]
+        );
+
+        let dfs: Vec<_> = DfsWalker::new(&graph, n[1]).iter(&graph).collect();
+        assert_eq!(dfs, vec![n[1], n[2], NodeIndex::new(4)]);
+    }
+
+    #[test]
+    fn topological_walk() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[2], n[0], ()).unwrap();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[3], n[4], ()).unwrap();
+        graph.add_edge(n[4], n[3], ()).unwrap();
+
+        let order: Vec<_> = TopoWalker::new(&graph).iter(&graph).collect();
+        assert_eq!(order, vec![n[2], n[0], n[1]]);
+    }
+
+    #[test]
+    fn topological_walk_while_mutating() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[2], ()).unwrap();
+
+        let mut topo = TopoWalker::new(&graph);
+        let mut order = Vec::new();
+        while let Some(node) = topo.walk_next(&graph) {
+            order.push(node);
+            if node == n[1] {
+                // An edge back to a visited node, and a new node with an edge
+                // to it.
+                graph.add_edge(n[2], n[0], ()).unwrap();
+                let added = graph.add_node(3);
+                graph.add_edge(n[2], added, ()).unwrap();
+            }
+        }
+        assert_eq!(order, vec![n[0], n[1], n[2]]);
+    }
+
+    #[test]
+    fn walking_after_removal() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[0], n[3], ()).unwrap();
+
+        let mut bfs = BfsWalker::new(&graph, n[0]);
+        let mut dfs = DfsWalker::new(&graph, n[0]);
+        assert_eq!(bfs.walk_next(&graph), Some(n[0]));
+        assert_eq!(dfs.walk_next(&graph), Some(n[0]));
+        graph.remove_node(n[2]);
+        assert_eq!(bfs.walk_next(&graph), Some(n[1]));
+        assert_eq!(bfs.walk_next(&graph), None);
+        assert_eq!(dfs.walk_next(&graph), Some(n[1]));
+        assert_eq!(dfs.walk_next(&graph), None);
+    }
+
+    #[test]
+    fn dfs_events() {
+        let mut graph = Graph::new();
+        let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+        graph.add_edge(n[0], n[1], ()).unwrap();
+        graph.add_edge(n[1], n[0], ()).unwrap();
+        graph.add_edge(n[0], n[2], ()).unwrap();
+        graph.add_edge(n[3], n[2], ()).unwrap();
+
+        let mut events = Vec::new();
+        let result = depth_first_search(&graph, [n[0], n[3]], |event| {
+            events.push(event);
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!(result, ControlFlow::Continue(()));
+        assert_eq!(
+            events,
+            vec![
+                DfsEvent::Discover(n[0]),
+                DfsEvent::TreeEdge(n[0], n[1]),
+                DfsEvent::Discover(n[1]),
+                DfsEvent::BackEdge(n[1], n[0]),
+                DfsEvent::Finish(n[1]),
+                DfsEvent::TreeEdge(n[0], n[2]),
+                DfsEvent::Discover(n[2]),
+                DfsEvent::Finish(n[2]),
+                DfsEvent::Finish(n[0]),
+                DfsEvent::Discover(n[3]),
+                DfsEvent::CrossForwardEdge(n[3], n[2]),
+                DfsEvent::Finish(n[3]),
+            ]
+        );
+
+        let back_edge = depth_first_search(&graph, [n[0]], |event| match event {
+            DfsEvent::BackEdge(from, to) => ControlFlow::Break((from, to)),
+            _ => ControlFlow::Continue(()),
+        });
+        assert_eq!(back_edge, ControlFlow::Break((n[1], n[0])));
+    }
+}
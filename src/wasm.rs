@@ -0,0 +1,152 @@
+//! A JavaScript-friendly graph for use in the browser through
+//! `wasm-bindgen`.
+//!
+//! Nodes are labeled with strings and edges weighted with numbers, the types
+//! JavaScript has on hand. Nodes are identified by their index, and methods
+//! given an index that is out of bounds throw instead of panicking.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{DiGraph, Graph, GraphError, NodeIndex, UnGraph};
+
+enum Inner {
+    Directed(DiGraph<String, f64>),
+    Undirected(UnGraph<String, f64>),
+}
+
+macro_rules! with_graph {
+    ($inner:expr, $graph:ident => $body:expr) => {
+        match $inner {
+            Inner::Directed($graph) => $body,
+            Inner::Undirected($graph) => $body,
+        }
+    };
+}
+
+#[wasm_bindgen]
+pub struct JsGraph {
+    inner: Inner,
+}
+
+fn to_js(err: impl core::fmt::Display) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+fn indices(path: impl IntoIterator<Item = NodeIndex>) -> Vec<u32> {
+    path.into_iter().map(|idx| idx.index() as u32).collect()
+}
+
+#[wasm_bindgen]
+impl JsGraph {
+    #[wasm_bindgen(constructor)]
+    pub fn new(directed: bool) -> JsGraph {
+        let inner = if directed {
+            Inner::Directed(Graph::new())
+        } else {
+            Inner::Undirected(Graph::new_undirected())
+        };
+        JsGraph { inner }
+    }
+
+    #[wasm_bindgen(js_name = isDirected)]
+    pub fn is_directed(&self) -> bool {
+        matches!(self.inner, Inner::Directed(_))
+    }
+
+    #[wasm_bindgen(js_name = nodeCount)]
+    pub fn node_count(&self) -> usize {
+        with_graph!(&self.inner, graph => graph.node_count())
+    }
+
+    #[wasm_bindgen(js_name = edgeCount)]
+    pub fn edge_count(&self) -> usize {
+        with_graph!(&self.inner, graph => graph.edge_count())
+    }
+
+    /// Returns the index of the new node.
+    #[wasm_bindgen(js_name = addNode)]
+    pub fn add_node(&mut self, label: String) -> u32 {
+        with_graph!(&mut self.inner, graph => graph.add_node(label).index() as u32)
+    }
+
+    #[wasm_bindgen(js_name = nodeLabel)]
+    pub fn node_label(&self, node: u32) -> Option<String> {
+        with_graph!(&self.inner, graph => graph.get_node(NodeIndex::new(node as usize)).cloned())
+    }
+
+    #[wasm_bindgen(js_name = addEdge)]
+    pub fn add_edge(&mut self, from: u32, to: u32, weight: f64) -> Result<(), JsError> {
+        let (from, to) = (NodeIndex::new(from as usize), NodeIndex::new(to as usize));
+        with_graph!(&mut self.inner, graph => graph.add_edge(from, to, weight)).map_err(to_js)
+    }
+
+    /// Returns the indices of the nodes reachable from `start`, in
+    /// breadth-first order.
+    pub fn bfs(&self, start: u32) -> Result<Vec<u32>, JsError> {
+        let start = self.check_node(start)?;
+        Ok(with_graph!(&self.inner, graph => indices(graph.bfs(start))))
+    }
+
+    /// Returns the indices of the nodes on the cheapest path from `source` to
+    /// `target`, or `undefined` if there is none. Weights must not be
+    /// negative.
+    #[wasm_bindgen(js_name = shortestPath)]
+    pub fn shortest_path(&self, source: u32, target: u32) -> Result<Option<Vec<u32>>, JsError> {
+        let (source, target) = (self.check_node(source)?, self.check_node(target)?);
+        let path = with_graph!(&self.inner, graph => graph.shortest_path(source, target));
+        Ok(path.map(|(_, path)| indices(path)))
+    }
+
+    /// Writes the graph in the node-link JSON format of
+    /// [`Graph::to_json_node_link`], which D3 reads directly.
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> Result<String, JsError> {
+        with_graph!(&self.inner, graph => graph.to_json_node_link()).map_err(to_js)
+    }
+
+    fn check_node(&self, node: u32) -> Result<NodeIndex, JsError> {
+        let len = self.node_count();
+        let index = NodeIndex::new(node as usize);
+        if index.index() >= len {
+            return Err(to_js(GraphError::NodeOutOfBounds { index, len }));
+        }
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsGraph;
+
+    #[test]
+    fn traversals() {
+        let mut graph = JsGraph::new(true);
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        let c = graph.add_node("c".to_string());
+        graph.add_edge(a, b, 1.0).unwrap();
+        graph.add_edge(b, c, 1.5).unwrap();
+        graph.add_edge(a, c, 3.0).unwrap();
+
+        assert!(graph.is_directed());
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.node_label(b).as_deref(), Some("b"));
+        assert_eq!(graph.bfs(b).unwrap(), vec![b, c]);
+        assert_eq!(graph.shortest_path(a, c).unwrap(), Some(vec![a, b, c]));
+        assert_eq!(graph.shortest_path(c, a).unwrap(), None);
+    }
+
+    #[test]
+    fn undirected_json() {
+        let mut graph = JsGraph::new(false);
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        graph.add_edge(b, a, 2.0).unwrap();
+
+        assert_eq!(graph.bfs(a).unwrap(), vec![a, b]);
+        assert_eq!(
+            graph.to_json().unwrap(),
+            r#"{"nodes":[{"id":0,"data":"a"},{"id":1,"data":"b"}],"links":[{"source":1,"target":0,"weight":2.0}]}"#
+        );
+    }
+}